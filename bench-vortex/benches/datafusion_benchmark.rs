@@ -164,10 +164,13 @@ fn bench_vortex<M: Measurement>(
     compress: bool,
 ) {
     let vortex_dataset = toy_dataset_vortex(compress);
-    let vortex_table = Arc::new(VortexMemTable::new(
-        vortex_dataset,
-        VortexMemTableOptions::default().with_pushdown(enable_pushdown),
-    ));
+    let vortex_table = Arc::new(
+        VortexMemTable::new(
+            vortex_dataset,
+            VortexMemTableOptions::default().with_pushdown(enable_pushdown),
+        )
+        .unwrap(),
+    );
 
     measure_provider(&mut group, session, vortex_table);
 }
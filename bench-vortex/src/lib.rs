@@ -20,7 +20,7 @@ use vortex::arrow::FromArrowType;
 use vortex::compress::CompressionStrategy;
 use vortex::encoding::EncodingRef;
 use vortex::{Array, Context, IntoArray};
-use vortex_alp::ALPEncoding;
+use vortex_alp::{ALPEncoding, ALPRDEncoding};
 use vortex_datetime_parts::DateTimePartsEncoding;
 use vortex_dict::DictEncoding;
 use vortex_dtype::DType;
@@ -53,6 +53,7 @@ pub mod vortex_utils;
 lazy_static! {
     pub static ref CTX: Arc<Context> = Arc::new(Context::default().with_encodings([
         &ALPEncoding as EncodingRef,
+        &ALPRDEncoding,
         &DictEncoding,
         &BitPackedEncoding,
         &FoREncoding,
@@ -0,0 +1,236 @@
+use std::fmt::Debug;
+
+use serde::{Deserialize, Serialize};
+use vortex::array::{PrimitiveArray, Sparse, SparseArray};
+use vortex::encoding::ids;
+use vortex::stats::ArrayStatisticsCompute;
+use vortex::validity::{ArrayValidity, LogicalValidity};
+use vortex::variants::{ArrayVariants, PrimitiveArrayTrait};
+use vortex::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use vortex::{
+    impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, Canonical, IntoArrayVariant,
+    IntoCanonical,
+};
+use vortex_dtype::{DType, Nullability, PType};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::alp_rd::{alp_rd_decode, ALPRDFloat, MAX_DICT_SIZE};
+use crate::match_each_alp_float_ptype;
+
+impl_encoding!("vortex.alprd", ids::ALP_RD, ALPRD);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ALPRDMetadata {
+    right_bit_width: u8,
+    dict: Vec<u16>,
+    has_exceptions: bool,
+}
+
+static EXCEPTIONS_DTYPE: DType = DType::Primitive(PType::U16, Nullability::Nullable);
+
+impl ALPRDArray {
+    /// Build an ALP-RD array of floats with the given `dtype`.
+    ///
+    /// `left_parts` holds u16 codes into `dict` and carries the validity of the array.
+    /// `right_parts` holds the low `right_bit_width` bits of each value as an unsigned integer
+    /// of the same width as the float. `exceptions`, if present, is a sparse u16 array of the
+    /// left parts that are missing from the dictionary.
+    pub fn try_new(
+        dtype: DType,
+        left_parts: Array,
+        dict: Vec<u16>,
+        right_parts: Array,
+        right_bit_width: u8,
+        exceptions: Option<Array>,
+    ) -> VortexResult<Self> {
+        let right_ptype = match dtype {
+            DType::Primitive(PType::F32, _) => PType::U32,
+            DType::Primitive(PType::F64, _) => PType::U64,
+            ref d => vortex_bail!(MismatchedTypes: "f32 or f64", d),
+        };
+
+        if left_parts.dtype() != &DType::Primitive(PType::U16, dtype.nullability()) {
+            vortex_bail!(MismatchedTypes: "u16 left parts", left_parts.dtype());
+        }
+        if right_parts.dtype() != &DType::Primitive(right_ptype, Nullability::NonNullable) {
+            vortex_bail!(MismatchedTypes: right_ptype, right_parts.dtype());
+        }
+        if right_parts.len() != left_parts.len() {
+            vortex_bail!(
+                "Left and right parts must have the same length, got {} and {}",
+                left_parts.len(),
+                right_parts.len()
+            );
+        }
+        if dict.is_empty() || dict.len() > MAX_DICT_SIZE {
+            vortex_bail!(
+                "ALP-RD dictionary must have between 1 and {} entries, got {}",
+                MAX_DICT_SIZE,
+                dict.len()
+            );
+        }
+        if right_bit_width as usize >= right_ptype.bit_width() {
+            vortex_bail!(
+                "Right bit width {} must be less than {}",
+                right_bit_width,
+                right_ptype.bit_width()
+            );
+        }
+
+        let length = left_parts.len();
+        let mut children = vec![left_parts, right_parts];
+        if let Some(exceptions) = exceptions {
+            if exceptions.dtype() != &EXCEPTIONS_DTYPE || exceptions.len() != length {
+                vortex_bail!(
+                    "Exceptions must be a nullable u16 array of length {}, got {} of length {}",
+                    length,
+                    exceptions.dtype(),
+                    exceptions.len()
+                );
+            }
+            children.push(exceptions);
+        }
+
+        Self::try_from_parts(
+            dtype,
+            length,
+            ALPRDMetadata {
+                right_bit_width,
+                dict,
+                has_exceptions: children.len() > 2,
+            },
+            children.into(),
+            Default::default(),
+        )
+    }
+
+    pub fn left_parts(&self) -> Array {
+        self.array()
+            .child(
+                0,
+                &DType::Primitive(PType::U16, self.dtype().nullability()),
+                self.len(),
+            )
+            .expect("Missing left parts")
+    }
+
+    pub fn right_parts(&self) -> Array {
+        let ptype = match self.ptype() {
+            PType::F32 => PType::U32,
+            _ => PType::U64,
+        };
+        self.array()
+            .child(
+                1,
+                &DType::Primitive(ptype, Nullability::NonNullable),
+                self.len(),
+            )
+            .expect("Missing right parts")
+    }
+
+    pub fn exceptions(&self) -> Option<Array> {
+        self.metadata().has_exceptions.then(|| {
+            self.array()
+                .child(2, &EXCEPTIONS_DTYPE, self.len())
+                .unwrap_or_else(|| panic!("Missing exceptions with present metadata flag"))
+        })
+    }
+
+    #[inline]
+    pub fn dict(&self) -> &[u16] {
+        &self.metadata().dict
+    }
+
+    #[inline]
+    pub fn right_bit_width(&self) -> u8 {
+        self.metadata().right_bit_width
+    }
+
+    #[inline]
+    pub fn ptype(&self) -> PType {
+        self.dtype().try_into().unwrap()
+    }
+}
+
+impl ArrayTrait for ALPRDArray {}
+
+impl ArrayVariants for ALPRDArray {
+    fn as_primitive_array(&self) -> Option<&dyn PrimitiveArrayTrait> {
+        Some(self)
+    }
+}
+
+impl PrimitiveArrayTrait for ALPRDArray {}
+
+impl ArrayValidity for ALPRDArray {
+    fn is_valid(&self, index: usize) -> bool {
+        self.left_parts().with_dyn(|a| a.is_valid(index))
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.left_parts().with_dyn(|a| a.logical_validity())
+    }
+}
+
+impl IntoCanonical for ALPRDArray {
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        let codes = self.left_parts().into_primitive()?;
+        let dict = self.dict();
+        let mut left_parts: Vec<u16> = codes
+            .maybe_null_slice::<u16>()
+            .iter()
+            .map(|&code| dict[code as usize])
+            .collect();
+
+        match self.exceptions() {
+            Some(exceptions) if exceptions.encoding().id() == Sparse::ID => {
+                let exceptions = SparseArray::try_from(exceptions)?;
+                let values = exceptions.values().into_primitive()?;
+                for (index, &left) in exceptions
+                    .resolved_indices()
+                    .into_iter()
+                    .zip(values.maybe_null_slice::<u16>())
+                {
+                    left_parts[index] = left;
+                }
+            }
+            // Exceptions compressed into another encoding are decoded, their valid values are the
+            // exceptions.
+            Some(exceptions) => {
+                let exceptions = exceptions.into_primitive()?;
+                for (index, &left) in exceptions.maybe_null_slice::<u16>().iter().enumerate() {
+                    if exceptions.is_valid(index) {
+                        left_parts[index] = left;
+                    }
+                }
+            }
+            None => {}
+        }
+
+        let right_parts = self.right_parts().into_primitive()?;
+        let decoded = match_each_alp_float_ptype!(self.ptype(), |$T| {
+            PrimitiveArray::from_vec(
+                alp_rd_decode::<$T>(
+                    &left_parts,
+                    right_parts.maybe_null_slice::<<$T as ALPRDFloat>::UINT>(),
+                    self.right_bit_width(),
+                ),
+                codes.validity(),
+            )
+        });
+        Ok(Canonical::Primitive(decoded))
+    }
+}
+
+impl AcceptArrayVisitor for ALPRDArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("left_parts", &self.left_parts())?;
+        visitor.visit_child("right_parts", &self.right_parts())?;
+        if let Some(exceptions) = self.exceptions().as_ref() {
+            visitor.visit_child("exceptions", exceptions)?;
+        }
+        Ok(())
+    }
+}
+
+impl ArrayStatisticsCompute for ALPRDArray {}
@@ -0,0 +1,81 @@
+use vortex::compute::unary::{scalar_at_unchecked, ScalarAtFn};
+use vortex::compute::{slice, take, ArrayCompute, SliceFn, TakeFn};
+use vortex::{Array, ArrayDType, IntoArray};
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::alp_rd::{ALPRDArray, ALPRDFloat};
+use crate::match_each_alp_float_ptype;
+
+impl ArrayCompute for ALPRDArray {
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn for ALPRDArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        Ok(self.scalar_at_unchecked(index))
+    }
+
+    fn scalar_at_unchecked(&self, index: usize) -> Scalar {
+        let code = scalar_at_unchecked(&self.left_parts(), index);
+        if code.is_null() {
+            return Scalar::null(self.dtype().clone());
+        }
+
+        // The left part is an exception if it is present in the exceptions array.
+        let left = match self
+            .exceptions()
+            .filter(|e| e.with_dyn(|a| a.is_valid(index)))
+        {
+            Some(exceptions) => u16::try_from(&scalar_at_unchecked(&exceptions, index)).unwrap(),
+            None => self.dict()[u16::try_from(&code).unwrap() as usize],
+        };
+        let right = scalar_at_unchecked(&self.right_parts(), index);
+
+        match_each_alp_float_ptype!(self.ptype(), |$T| {
+            let right = <$T as ALPRDFloat>::UINT::try_from(&right).unwrap();
+            let bits = (<$T as ALPRDFloat>::UINT::from(left) << self.right_bit_width()) | right;
+            Scalar::primitive(<$T as ALPRDFloat>::from_uint(bits), self.dtype().nullability())
+        })
+    }
+}
+
+impl TakeFn for ALPRDArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        Ok(Self::try_new(
+            self.dtype().clone(),
+            take(&self.left_parts(), indices)?,
+            self.dict().to_vec(),
+            take(&self.right_parts(), indices)?,
+            self.right_bit_width(),
+            self.exceptions().map(|e| take(&e, indices)).transpose()?,
+        )?
+        .into_array())
+    }
+}
+
+impl SliceFn for ALPRDArray {
+    fn slice(&self, start: usize, end: usize) -> VortexResult<Array> {
+        Ok(Self::try_new(
+            self.dtype().clone(),
+            slice(&self.left_parts(), start, end)?,
+            self.dict().to_vec(),
+            slice(&self.right_parts(), start, end)?,
+            self.right_bit_width(),
+            self.exceptions()
+                .map(|e| slice(&e, start, end))
+                .transpose()?,
+        )?
+        .into_array())
+    }
+}
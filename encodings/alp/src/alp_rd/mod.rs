@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+
+pub use array::*;
+use itertools::Itertools;
+use num_traits::{AsPrimitive, Float, One, PrimInt};
+use vortex::array::{PrimitiveArray, SparseArray};
+use vortex::validity::Validity;
+use vortex::{ArrayDType, IntoArray};
+use vortex_dtype::{DType, NativePType, Nullability, PType};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::match_each_alp_float_ptype;
+
+mod array;
+mod compute;
+
+/// The maximum number of distinct left parts stored in the dictionary.
+pub const MAX_DICT_SIZE: usize = 8;
+
+/// The widest left part considered when splitting values.
+const MAX_LEFT_BIT_WIDTH: u8 = 16;
+
+const SAMPLE_SIZE: usize = 1024;
+
+/// A float that ALP-RD splits into left and right parts of its bit representation.
+pub trait ALPRDFloat: Float + NativePType {
+    /// The unsigned integer with the same width as the float.
+    type UINT: PrimInt + NativePType + From<u16> + AsPrimitive<u16>;
+
+    const BITS: u8;
+
+    fn to_uint(self) -> Self::UINT;
+
+    fn from_uint(bits: Self::UINT) -> Self;
+}
+
+impl ALPRDFloat for f32 {
+    type UINT = u32;
+    const BITS: u8 = 32;
+
+    fn to_uint(self) -> u32 {
+        self.to_bits()
+    }
+
+    fn from_uint(bits: u32) -> Self {
+        f32::from_bits(bits)
+    }
+}
+
+impl ALPRDFloat for f64 {
+    type UINT = u64;
+    const BITS: u8 = 64;
+
+    fn to_uint(self) -> u64 {
+        self.to_bits()
+    }
+
+    fn from_uint(bits: u64) -> Self {
+        f64::from_bits(bits)
+    }
+}
+
+/// The split of floats into left and right parts, and the dictionary of common left parts.
+///
+/// Floats that are not decimals, such as sensor readings, rarely vary in their sign, exponent
+/// and leading mantissa bits. ALP-RD stores those bits as codes into a small dictionary, and
+/// keeps the remaining right bits as they are. Left parts missing from the dictionary are
+/// stored as exceptions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RDEncoder {
+    right_bit_width: u8,
+    dict: Vec<u16>,
+}
+
+impl RDEncoder {
+    /// Choose the split that minimizes the estimated size of a sample of `values`.
+    pub fn new<T: ALPRDFloat>(values: &[T]) -> Self {
+        let sample = if values.len() > SAMPLE_SIZE {
+            values
+                .iter()
+                .step_by(values.len() / SAMPLE_SIZE)
+                .copied()
+                .collect_vec()
+        } else {
+            values.to_vec()
+        };
+
+        let mut best = Self {
+            right_bit_width: T::BITS - 1,
+            dict: vec![0],
+        };
+        let mut best_size = usize::MAX;
+        for left_bit_width in 1..=MAX_LEFT_BIT_WIDTH {
+            let right_bit_width = T::BITS - left_bit_width;
+            let mut counts = HashMap::<u16, usize>::new();
+            for &value in &sample {
+                *counts.entry(left_part(value, right_bit_width)).or_default() += 1;
+            }
+            let dict = counts
+                .iter()
+                .sorted_by_key(|&(&left, &count)| (usize::MAX - count, left))
+                .take(MAX_DICT_SIZE)
+                .map(|(&left, _)| left)
+                .collect_vec();
+
+            let exceptions = sample.len() - dict.iter().map(|left| counts[left]).sum::<usize>();
+            let code_bit_width = dict.len().next_power_of_two().trailing_zeros() as usize;
+            // Each exception stores a u16 left part and a u64 position.
+            let size =
+                sample.len() * (right_bit_width as usize + code_bit_width) + exceptions * (16 + 64);
+            if size < best_size && !dict.is_empty() {
+                best_size = size;
+                best = Self {
+                    right_bit_width,
+                    dict,
+                };
+            }
+        }
+        best
+    }
+
+    /// Build an encoder from a split chosen earlier.
+    pub fn from_parts(right_bit_width: u8, dict: Vec<u16>) -> Self {
+        Self {
+            right_bit_width,
+            dict,
+        }
+    }
+
+    #[inline]
+    pub fn right_bit_width(&self) -> u8 {
+        self.right_bit_width
+    }
+
+    #[inline]
+    pub fn dict(&self) -> &[u16] {
+        &self.dict
+    }
+
+    /// Split the values of `array` into dictionary codes, right parts and exceptions.
+    pub fn encode(&self, array: &PrimitiveArray) -> VortexResult<ALPRDArray> {
+        match_each_alp_float_ptype!(array.ptype(), |$T| {
+            self.encode_typed::<$T>(array)
+        })
+    }
+
+    fn encode_typed<T: ALPRDFloat>(&self, array: &PrimitiveArray) -> VortexResult<ALPRDArray> {
+        if self.right_bit_width >= T::BITS || self.right_bit_width < T::BITS - MAX_LEFT_BIT_WIDTH {
+            vortex_bail!(
+                "ALP-RD right bit width {} is invalid for {}",
+                self.right_bit_width,
+                array.ptype()
+            );
+        }
+
+        let values = array.maybe_null_slice::<T>();
+        let right_mask = (T::UINT::one() << self.right_bit_width as usize) - T::UINT::one();
+        let mut codes = Vec::with_capacity(values.len());
+        let mut right_parts = Vec::with_capacity(values.len());
+        let mut exc_pos = Vec::new();
+        let mut exc_values = Vec::new();
+        for (i, &value) in values.iter().enumerate() {
+            right_parts.push(value.to_uint() & right_mask);
+            let left = left_part(value, self.right_bit_width);
+            match self.dict.iter().position(|&l| l == left) {
+                Some(code) => codes.push(code as u16),
+                None => {
+                    codes.push(0);
+                    exc_pos.push(i as u64);
+                    exc_values.push(left);
+                }
+            }
+        }
+
+        let exceptions = (!exc_pos.is_empty())
+            .then(|| {
+                SparseArray::try_new(
+                    PrimitiveArray::from(exc_pos).into_array(),
+                    PrimitiveArray::from_vec(exc_values, Validity::AllValid).into_array(),
+                    values.len(),
+                    Scalar::null(DType::Primitive(PType::U16, Nullability::Nullable)),
+                )
+                .map(|a| a.into_array())
+            })
+            .transpose()?;
+
+        ALPRDArray::try_new(
+            array.dtype().clone(),
+            PrimitiveArray::from_vec(codes, array.validity()).into_array(),
+            self.dict.clone(),
+            PrimitiveArray::from(right_parts).into_array(),
+            self.right_bit_width,
+            exceptions,
+        )
+    }
+}
+
+/// Encode a float array with ALP-RD, choosing the split from a sample of its values.
+pub fn alp_rd_encode(array: &PrimitiveArray) -> VortexResult<ALPRDArray> {
+    let encoder = match array.ptype() {
+        PType::F32 => RDEncoder::new(array.maybe_null_slice::<f32>()),
+        PType::F64 => RDEncoder::new(array.maybe_null_slice::<f64>()),
+        _ => vortex_bail!("ALP-RD can only encode f32 and f64"),
+    };
+    encoder.encode(array)
+}
+
+fn left_part<T: ALPRDFloat>(value: T, right_bit_width: u8) -> u16 {
+    // The right bit width leaves at most `MAX_LEFT_BIT_WIDTH` bits on the left.
+    (value.to_uint() >> right_bit_width as usize).as_()
+}
+
+/// Join `left_parts` with `right_parts` back into floats.
+fn alp_rd_decode<T: ALPRDFloat>(
+    left_parts: &[u16],
+    right_parts: &[T::UINT],
+    right_bit_width: u8,
+) -> Vec<T> {
+    left_parts
+        .iter()
+        .zip(right_parts)
+        .map(|(&left, &right)| {
+            T::from_uint((T::UINT::from(left) << right_bit_width as usize) | right)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::compute::unary::scalar_at;
+    use vortex::compute::{slice, take};
+    use vortex::{IntoArrayVariant, IntoCanonical};
+
+    use super::*;
+
+    fn readings(len: usize) -> Vec<f64> {
+        (0..len)
+            .map(|i| 20.0 + (i as f64 * 0.731).sin() * 3.0 / 7.0)
+            .collect()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let values = readings(2048);
+        let encoded = alp_rd_encode(&PrimitiveArray::from(values.clone())).unwrap();
+        assert!(encoded.exceptions().is_none());
+        assert!(encoded.dict().len() <= MAX_DICT_SIZE);
+
+        let decoded = encoded.into_canonical().unwrap().into_primitive().unwrap();
+        assert_eq!(decoded.maybe_null_slice::<f64>(), values.as_slice());
+    }
+
+    #[test]
+    fn test_exceptions() {
+        let mut values = readings(1024).into_iter().map(|v| v as f32).collect_vec();
+        values[17] = -1.0e30;
+        values[900] = f32::NAN;
+        let encoded = alp_rd_encode(&PrimitiveArray::from(values.clone())).unwrap();
+        assert!(encoded.exceptions().is_some());
+
+        let decoded = encoded
+            .clone()
+            .into_canonical()
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        let same_bits = |a: &[f32], b: &[f32]| {
+            a.iter().map(|v| v.to_bits()).collect_vec()
+                == b.iter().map(|v| v.to_bits()).collect_vec()
+        };
+        assert!(same_bits(decoded.maybe_null_slice::<f32>(), &values));
+
+        assert_eq!(
+            scalar_at(encoded.array(), 17).unwrap(),
+            Scalar::from(-1.0e30f32)
+        );
+        let sliced = slice(encoded.array(), 10, 20)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert!(same_bits(sliced.maybe_null_slice::<f32>(), &values[10..20]));
+        let taken = take(
+            encoded.array(),
+            &PrimitiveArray::from(vec![900u32, 17, 3]).into_array(),
+        )
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+        assert!(same_bits(
+            taken.maybe_null_slice::<f32>(),
+            &[values[900], values[17], values[3]]
+        ));
+    }
+
+    #[test]
+    fn test_dense_exceptions() {
+        let mut values = readings(1024).into_iter().map(|v| v as f32).collect_vec();
+        values[17] = -1.0e30;
+        let encoded = alp_rd_encode(&PrimitiveArray::from(values.clone())).unwrap();
+
+        // Exceptions recompressed by the sampling compressor need not stay sparse.
+        let dense = ALPRDArray::try_new(
+            encoded.dtype().clone(),
+            encoded.left_parts(),
+            encoded.dict().to_vec(),
+            encoded.right_parts(),
+            encoded.right_bit_width(),
+            Some(
+                encoded
+                    .exceptions()
+                    .unwrap()
+                    .into_canonical()
+                    .unwrap()
+                    .into(),
+            ),
+        )
+        .unwrap();
+        let decoded = dense.into_canonical().unwrap().into_primitive().unwrap();
+        assert_eq!(decoded.maybe_null_slice::<f32>(), values.as_slice());
+    }
+
+    #[test]
+    fn test_nullable() {
+        let values = vec![Some(1.0f64 / 3.0), None, Some(2.0 / 3.0)];
+        let encoded = alp_rd_encode(&PrimitiveArray::from_nullable_vec(values)).unwrap();
+        assert!(encoded.dtype().is_nullable());
+        assert!(scalar_at(encoded.array(), 1).unwrap().is_null());
+        assert_eq!(
+            scalar_at(encoded.array(), 2).unwrap(),
+            Scalar::primitive(2.0f64 / 3.0, Nullability::Nullable)
+        );
+
+        let decoded = encoded.into_canonical().unwrap().into_primitive().unwrap();
+        assert_eq!(decoded.maybe_null_slice::<f64>()[0], 1.0 / 3.0);
+        assert!(!decoded.validity().is_valid(1));
+    }
+}
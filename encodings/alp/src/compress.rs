@@ -1,6 +1,6 @@
 use itertools::Itertools;
 use vortex::array::{PrimitiveArray, Sparse, SparseArray};
-use vortex::validity::Validity;
+use vortex::validity::{ArrayValidity, Validity};
 use vortex::{Array, ArrayDType, ArrayDef, IntoArray, IntoArrayVariant};
 use vortex_dtype::{NativePType, PType};
 use vortex_error::{vortex_bail, VortexResult};
@@ -86,11 +86,18 @@ fn patch_decoded(array: PrimitiveArray, patches: &Array) -> VortexResult<Primiti
                     typed_patches.values().into_primitive()?.maybe_null_slice::<$T>())
             })
         }
-        _ => vortex_bail!(
-            "Can't patch ALP array with {}; only {} is supported",
-            patches,
-            Sparse::ID
-        ),
+        // Patches compressed into another encoding are decoded, their valid values are the patches.
+        _ => {
+            let patches = patches.clone().into_primitive()?;
+            let positions = (0..patches.len())
+                .filter(|&i| patches.is_valid(i))
+                .collect::<Vec<_>>();
+            match_each_alp_float_ptype!(array.ptype(), |$T| {
+                let values = patches.maybe_null_slice::<$T>();
+                let values = positions.iter().map(|&i| values[i]).collect::<Vec<_>>();
+                array.patch(&positions, &values)
+            })
+        }
     }
 }
 
@@ -108,6 +115,8 @@ fn decompress_primitive<T: NativePType + ALPFloat>(
 mod tests {
     use core::f64;
 
+    use vortex::array::BoolArray;
+    use vortex::compute::filter;
     use vortex::compute::unary::scalar_at;
     use vortex::AsArray;
 
@@ -190,4 +199,33 @@ mod tests {
 
         let _decoded = decompress(encoded).unwrap();
     }
+
+    #[test]
+    #[allow(clippy::approx_constant)] // ALP doesn't like E
+    fn test_filter_patched() {
+        let values = vec![
+            1.234f64,
+            2.718,
+            std::f64::consts::PI,
+            4.0,
+            std::f64::consts::E,
+        ];
+        let encoded = alp_encode(&PrimitiveArray::from(values)).unwrap();
+        assert!(encoded.patches().is_some());
+
+        let filtered = ALPArray::try_from(
+            filter(
+                encoded.as_array_ref(),
+                BoolArray::from(vec![false, true, true, false, true]).array(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(filtered.patches().unwrap().encoding().id(), Sparse::ID);
+        let decoded = decompress(filtered).unwrap();
+        assert_eq!(
+            decoded.maybe_null_slice::<f64>(),
+            &[2.718, std::f64::consts::PI, std::f64::consts::E]
+        );
+    }
 }
@@ -1,12 +1,23 @@
+use vortex::array::{BoolArray, ConstantArray, SparseArray};
 use vortex::compute::unary::{scalar_at_unchecked, ScalarAtFn};
-use vortex::compute::{slice, take, ArrayCompute, SliceFn, TakeFn};
-use vortex::{Array, IntoArray};
+use vortex::compute::{
+    compare, filter, slice, take, ArrayCompute, CompareFn, FilterFn, Operator, SliceFn, TakeFn,
+};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::{match_each_alp_float_ptype, ALPArray, ALPFloat};
 
 impl ArrayCompute for ALPArray {
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -48,6 +59,83 @@ impl ScalarAtFn for ALPArray {
     }
 }
 
+impl CompareFn for ALPArray {
+    fn compare(&self, other: &Array, operator: Operator) -> VortexResult<Array> {
+        if let Ok(constant) = ConstantArray::try_from(other) {
+            if let Some(encoded) = self.encode_scalar(constant.scalar())? {
+                return compare_encoded(self, &encoded, constant.scalar(), operator);
+            }
+        }
+
+        let decoded = Array::from(self.clone().into_canonical()?);
+        compare(&decoded, other, operator)
+    }
+}
+
+impl ALPArray {
+    /// Encode `value` with the exponents of this array, returning `None` if it does not survive
+    /// the round trip and so would have been stored as a patch.
+    fn encode_scalar(&self, value: &Scalar) -> VortexResult<Option<Scalar>> {
+        if value.is_null() {
+            return Ok(None);
+        }
+
+        let value = value.cast(self.dtype())?;
+        match_each_alp_float_ptype!(self.ptype(), |$T| {
+            Ok(<$T as ALPFloat>::encode_single(<$T>::try_from(&value)?, &self.exponents())
+                .ok()
+                .map(|encoded| Scalar::primitive(encoded, self.dtype().nullability())))
+        })
+    }
+}
+
+/// Compare the encoded values of `array` to the encoded constant, then overwrite the result at
+/// each patched position with the comparison of the patch value itself.
+///
+/// Rounding to the encoded integer is monotonic, and values that are not patched decode exactly,
+/// so the order of encoded values matches the order of the floats they decode to.
+fn compare_encoded(
+    array: &ALPArray,
+    encoded: &Scalar,
+    value: &Scalar,
+    operator: Operator,
+) -> VortexResult<Array> {
+    let matches = compare(
+        &array.encoded(),
+        ConstantArray::new(encoded.clone(), array.len()).array(),
+        operator,
+    )?;
+    let Some(patches) = array.patches() else {
+        return Ok(matches);
+    };
+
+    let Ok(patches) = SparseArray::try_from(&patches) else {
+        // Patches compressed into another encoding can't be applied by position, so compare the
+        // decoded values instead.
+        let decoded = Array::from(array.clone().into_canonical()?);
+        return compare(
+            &decoded,
+            ConstantArray::new(value.clone(), array.len()).array(),
+            operator,
+        );
+    };
+    let patch_values = patches.values();
+    let patch_matches = compare(
+        &patch_values,
+        ConstantArray::new(value.clone(), patch_values.len()).array(),
+        operator,
+    )?
+    .into_bool()?
+    .boolean_buffer();
+
+    let matches = matches.into_bool()?;
+    let mut bools = matches.boolean_buffer().iter().collect::<Vec<_>>();
+    for (position, index) in patches.resolved_indices().into_iter().enumerate() {
+        bools[index] = patch_matches.value(position);
+    }
+    Ok(BoolArray::from_vec(bools, matches.validity()).into_array())
+}
+
 impl TakeFn for ALPArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
         // TODO(ngates): wrap up indices in an array that caches decompression?
@@ -60,6 +148,17 @@ impl TakeFn for ALPArray {
     }
 }
 
+impl FilterFn for ALPArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        Ok(Self::try_new(
+            filter(&self.encoded(), predicate)?,
+            self.exponents(),
+            self.patches().map(|p| filter(&p, predicate)).transpose()?,
+        )?
+        .into_array())
+    }
+}
+
 impl SliceFn for ALPArray {
     fn slice(&self, start: usize, end: usize) -> VortexResult<Array> {
         Ok(Self::try_new(
@@ -70,3 +169,88 @@ impl SliceFn for ALPArray {
         .into_array())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex::array::{ConstantArray, PrimitiveArray, Sparse};
+    use vortex::compute::{compare, Operator};
+    use vortex::{ArrayDef, IntoArray, IntoArrayVariant};
+
+    use crate::{alp_encode, ALPArray};
+
+    #[test]
+    #[allow(clippy::approx_constant)] // ALP doesn't like E
+    fn compare_constant() {
+        let values = vec![
+            1.234f64,
+            2.718,
+            std::f64::consts::PI,
+            4.0,
+            std::f64::consts::E,
+        ];
+        let encoded = alp_encode(&PrimitiveArray::from(values)).unwrap();
+        assert!(encoded.patches().is_some());
+
+        let compare_to = |value: f64, operator: Operator| {
+            compare(
+                encoded.array(),
+                ConstantArray::new(value, encoded.len()).array(),
+                operator,
+            )
+            .unwrap()
+            .into_bool()
+            .unwrap()
+            .boolean_buffer()
+            .iter()
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            compare_to(2.718, Operator::Eq),
+            vec![false, true, false, false, false]
+        );
+        assert_eq!(
+            compare_to(3.0, Operator::Lt),
+            vec![true, true, false, false, true]
+        );
+        assert_eq!(
+            compare_to(2.718, Operator::Gte),
+            vec![false, true, true, true, true]
+        );
+        // Not representable with the exponents of the array, so compared after decoding.
+        assert_eq!(compare_to(1.0 / 3.0, Operator::Gt), vec![true; 5]);
+    }
+
+    #[test]
+    fn compare_with_compressed_patches() {
+        let values = vec![1.234f64, 2.5, std::f64::consts::PI, 4.0];
+        let encoded = alp_encode(&PrimitiveArray::from(values.clone())).unwrap();
+        let patches = encoded.patches().unwrap();
+        assert_eq!(patches.encoding().id(), Sparse::ID);
+
+        // Patches in any encoding other than sparse, as a compressor may leave them.
+        let encoded = ALPArray::try_new(
+            encoded.encoded(),
+            encoded.exponents(),
+            Some(patches.into_primitive().unwrap().into_array()),
+        )
+        .unwrap();
+
+        let matches = compare(
+            encoded.array(),
+            ConstantArray::new(3.0f64, encoded.len()).array(),
+            Operator::Gt,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, false, true, true]
+        );
+        assert_eq!(
+            encoded.into_primitive().unwrap().maybe_null_slice::<f64>(),
+            values.as_slice()
+        );
+    }
+}
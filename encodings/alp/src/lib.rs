@@ -1,8 +1,10 @@
 pub use alp::*;
+pub use alp_rd::*;
 pub use array::*;
 pub use compress::*;
 
 mod alp;
+mod alp_rd;
 mod array;
 mod compress;
 mod compute;
@@ -1,16 +1,22 @@
-use vortex::array::{PrimitiveArray, TemporalArray};
+use vortex::array::{ConstantArray, PrimitiveArray, TemporalArray};
 use vortex::compute::unary::{scalar_at, ScalarAtFn};
-use vortex::compute::{slice, take, ArrayCompute, SliceFn, TakeFn};
+use vortex::compute::{
+    and, compare, or, slice, take, ArrayCompute, CompareFn, Operator, SliceFn, TakeFn,
+};
 use vortex::validity::ArrayValidity;
-use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
 use vortex_datetime_dtype::{TemporalMetadata, TimeUnit};
 use vortex_dtype::DType;
 use vortex_error::{vortex_bail, VortexResult};
-use vortex_scalar::Scalar;
+use vortex_scalar::{ExtScalar, Scalar};
 
 use crate::DateTimePartsArray;
 
 impl ArrayCompute for DateTimePartsArray {
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -83,6 +89,106 @@ impl ScalarAtFn for DateTimePartsArray {
     }
 }
 
+impl CompareFn for DateTimePartsArray {
+    fn compare(&self, other: &Array, operator: Operator) -> VortexResult<Array> {
+        if let Ok(constant) = ConstantArray::try_from(other) {
+            if let Some(parts) = self.split_scalar(constant.scalar())? {
+                return compare_parts(self, parts, operator);
+            }
+        }
+
+        let decoded = Array::from(self.clone().into_canonical()?);
+        compare(&decoded, other, operator)
+    }
+}
+
+impl DateTimePartsArray {
+    /// Split a timestamp into days, seconds and subseconds the same way the array was compressed,
+    /// cast to the dtypes of the children. Returns `None` if a part does not fit its child.
+    fn split_scalar(&self, value: &Scalar) -> VortexResult<Option<[Scalar; 3]>> {
+        let Some(timestamp) = ExtScalar::try_from(value)?.value().as_pvalue()? else {
+            return Ok(None);
+        };
+        let timestamp = i64::try_from(timestamp)?;
+
+        let DType::Extension(ext, _) = self.dtype() else {
+            vortex_bail!(ComputeError: "expected dtype to be DType::Extension variant")
+        };
+        let divisor = match TemporalMetadata::try_from(ext)?.time_unit() {
+            TimeUnit::Ns => 1_000_000_000,
+            TimeUnit::Us => 1_000_000,
+            TimeUnit::Ms => 1_000,
+            TimeUnit::S => 1,
+            TimeUnit::D => vortex_bail!(InvalidArgument: "cannot decode into TimeUnit::D"),
+        };
+
+        let parts = [
+            (timestamp / (86_400 * divisor), self.days()),
+            ((timestamp % (86_400 * divisor)) / divisor, self.seconds()),
+            ((timestamp % (86_400 * divisor)) % divisor, self.subsecond()),
+        ];
+        let [Ok(days), Ok(seconds), Ok(subseconds)] =
+            parts.map(|(part, child)| Scalar::from(part).cast(child.dtype()))
+        else {
+            return Ok(None);
+        };
+        Ok(Some([days, seconds, subseconds]))
+    }
+}
+
+/// Compare each part of the array to the matching part of the constant.
+///
+/// Timestamps order the same as their (days, seconds, subseconds) parts compared
+/// lexicographically, so ordering operators compare the leading parts strictly and fall through
+/// to the next part where they are equal.
+fn compare_parts(
+    array: &DateTimePartsArray,
+    [days, seconds, subseconds]: [Scalar; 3],
+    operator: Operator,
+) -> VortexResult<Array> {
+    let len = array.len();
+    let compare_child = |child: Array, value: Scalar, operator: Operator| {
+        compare(&child, ConstantArray::new(value, len).array(), operator)
+    };
+
+    match operator {
+        Operator::Eq => and(
+            &and(
+                &compare_child(array.days(), days, operator)?,
+                &compare_child(array.seconds(), seconds, operator)?,
+            )?,
+            &compare_child(array.subsecond(), subseconds, operator)?,
+        ),
+        Operator::NotEq => or(
+            &or(
+                &compare_child(array.days(), days, operator)?,
+                &compare_child(array.seconds(), seconds, operator)?,
+            )?,
+            &compare_child(array.subsecond(), subseconds, operator)?,
+        ),
+        Operator::Gt | Operator::Gte | Operator::Lt | Operator::Lte => {
+            let strict = match operator {
+                Operator::Gt | Operator::Gte => Operator::Gt,
+                _ => Operator::Lt,
+            };
+            let seconds_match = or(
+                &compare_child(array.seconds(), seconds.clone(), strict)?,
+                &and(
+                    &compare_child(array.seconds(), seconds, Operator::Eq)?,
+                    &compare_child(array.subsecond(), subseconds, operator)?,
+                )?,
+            )?;
+            or(
+                &compare_child(array.days(), days.clone(), strict)?,
+                &and(
+                    &compare_child(array.days(), days, Operator::Eq)?,
+                    &seconds_match,
+                )?,
+            )
+        }
+    }
+}
+
 /// Decode an [Array] into a [TemporalArray].
 ///
 /// Enforces that the passed array is actually a [DateTimePartsArray] with proper metadata.
@@ -124,10 +230,12 @@ pub fn decode_to_temporal(array: &DateTimePartsArray) -> VortexResult<TemporalAr
 
 #[cfg(test)]
 mod test {
-    use vortex::array::{PrimitiveArray, TemporalArray};
+    use vortex::array::{ConstantArray, PrimitiveArray, TemporalArray};
+    use vortex::compute::{compare, Operator};
     use vortex::{IntoArray, IntoArrayVariant};
     use vortex_datetime_dtype::TimeUnit;
     use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::Scalar;
 
     use crate::compute::decode_to_temporal;
     use crate::{compress_temporal, DateTimePartsArray};
@@ -166,4 +274,57 @@ mod test {
             raw_values.as_slice()
         );
     }
+
+    #[test]
+    fn compare_constant() {
+        let raw_values = vec![
+            -86_400_001i64,
+            -1,
+            86_400_000,
+            86_400_000 + 1_000,
+            86_400_000 + 1_001,
+            2 * 86_400_000,
+        ];
+        let temporal_array = TemporalArray::new_timestamp(
+            PrimitiveArray::from(raw_values.clone()).into_array(),
+            TimeUnit::Ms,
+            None,
+        );
+        let (days, seconds, subseconds) = compress_temporal(temporal_array.clone()).unwrap();
+        let date_times = DateTimePartsArray::try_new(
+            DType::Extension(temporal_array.ext_dtype().clone(), Nullability::NonNullable),
+            days,
+            seconds,
+            subseconds,
+        )
+        .unwrap();
+
+        for value in [-86_400_001i64, -86_399_999, 0, 86_401_000, 86_401_001, 1] {
+            let constant = ConstantArray::new(
+                Scalar::extension(temporal_array.ext_dtype().clone(), Scalar::from(value)),
+                date_times.len(),
+            );
+            for operator in [
+                Operator::Eq,
+                Operator::NotEq,
+                Operator::Gt,
+                Operator::Gte,
+                Operator::Lt,
+                Operator::Lte,
+            ] {
+                let matches = compare(date_times.array(), constant.array(), operator)
+                    .unwrap()
+                    .into_bool()
+                    .unwrap()
+                    .boolean_buffer()
+                    .iter()
+                    .collect::<Vec<_>>();
+                let expected = raw_values
+                    .iter()
+                    .map(|&v| operator.to_fn::<i64>()(v, value))
+                    .collect::<Vec<_>>();
+                assert_eq!(matches, expected, "{} {}", operator, value);
+            }
+        }
+    }
 }
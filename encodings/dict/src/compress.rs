@@ -5,10 +5,18 @@ use hashbrown::hash_map::{Entry, RawEntryMut};
 use hashbrown::HashMap;
 use num_traits::AsPrimitive;
 use vortex::accessor::ArrayAccessor;
-use vortex::array::{PrimitiveArray, VarBinArray};
-use vortex::validity::Validity;
-use vortex::{ArrayDType, IntoArray};
+use vortex::array::{
+    Bool, BoolArray, Primitive, PrimitiveArray, Struct, StructArray, VarBin, VarBinArray,
+    VarBinView, VarBinViewArray,
+};
+use vortex::compute::take;
+use vortex::validity::{ArrayValidity, Validity};
+use vortex::variants::StructArrayTrait;
+use vortex::{Array, ArrayDType, ArrayDef, IntoArray, IntoCanonical};
 use vortex_dtype::{match_each_native_ptype, DType, NativePType, ToBytes};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::DictArray;
 
 #[derive(Debug)]
 struct Value<T>(T);
@@ -27,6 +35,54 @@ impl<T: ToBytes> PartialEq<Self> for Value<T> {
 
 impl<T: ToBytes> Eq for Value<T> {}
 
+/// Dictionary encode a primitive, varbin, varbinview, bool or struct array.
+/// Null values in the original array are encoded in the dictionary.
+pub fn dict_encode(array: &Array) -> VortexResult<DictArray> {
+    let (codes, values) = dict_encode_parts(array)?;
+    DictArray::try_new(codes.into_array(), values)
+}
+
+fn dict_encode_parts(array: &Array) -> VortexResult<(PrimitiveArray, Array)> {
+    Ok(match array.encoding().id() {
+        Primitive::ID => {
+            let (codes, values) = dict_encode_primitive(&PrimitiveArray::try_from(array)?);
+            (codes, values.into_array())
+        }
+        VarBin::ID => {
+            let (codes, values) = dict_encode_varbin(&VarBinArray::try_from(array)?);
+            (codes, values.into_array())
+        }
+        VarBinView::ID => {
+            let (codes, values) = dict_encode_varbinview(&VarBinViewArray::try_from(array)?);
+            (codes, values.into_array())
+        }
+        Bool::ID => {
+            let (codes, values) = dict_encode_bool(&BoolArray::try_from(array)?);
+            (codes, values.into_array())
+        }
+        Struct::ID => {
+            let (codes, values) = dict_encode_struct(&StructArray::try_from(array)?)?;
+            (codes, values.into_array())
+        }
+        _ => vortex_bail!(
+            "Can't dictionary encode {} array",
+            array.encoding().id().as_ref()
+        ),
+    })
+}
+
+/// The validity of dictionary values, where the first value stands for null if nullable.
+fn values_validity(nullable: bool, len: usize) -> Validity {
+    if nullable {
+        let mut validity = vec![true; len];
+        validity[0] = false;
+
+        validity.into()
+    } else {
+        Validity::NonNullable
+    }
+}
+
 pub fn dict_encode_primitive(array: &PrimitiveArray) -> (PrimitiveArray, PrimitiveArray) {
     match_each_native_ptype!(array.ptype(), |$P| {
         dict_encode_typed_primitive::<$P>(array)
@@ -67,14 +123,7 @@ pub fn dict_encode_typed_primitive<T: NativePType>(
     })
     .unwrap();
 
-    let values_validity = if array.dtype().is_nullable() {
-        let mut validity = vec![true; values.len()];
-        validity[0] = false;
-
-        validity.into()
-    } else {
-        Validity::NonNullable
-    };
+    let values_validity = values_validity(array.dtype().is_nullable(), values.len());
 
     (
         PrimitiveArray::from(codes),
@@ -82,6 +131,111 @@ pub fn dict_encode_typed_primitive<T: NativePType>(
     )
 }
 
+/// Dictionary encode a boolean array.
+/// Null values in the original array are encoded in the dictionary.
+pub fn dict_encode_bool(array: &BoolArray) -> (PrimitiveArray, BoolArray) {
+    let mut lookup_dict: [Option<u64>; 2] = [None, None];
+    let mut codes: Vec<u64> = Vec::with_capacity(array.len());
+    let mut values: Vec<bool> = Vec::new();
+
+    if array.dtype().is_nullable() {
+        values.push(false);
+    }
+
+    array
+        .with_iterator(|iter| {
+            for ov in iter {
+                match ov {
+                    None => codes.push(0),
+                    Some(&v) => codes.push(*lookup_dict[v as usize].get_or_insert_with(|| {
+                        values.push(v);
+                        values.len() as u64 - 1
+                    })),
+                }
+            }
+        })
+        .unwrap();
+
+    let values_validity = values_validity(array.dtype().is_nullable(), values.len());
+
+    (
+        PrimitiveArray::from(codes),
+        BoolArray::from_vec(values, values_validity),
+    )
+}
+
+/// Dictionary encode the rows of a struct array.
+/// Null rows in the original array are encoded in the dictionary.
+pub fn dict_encode_struct(array: &StructArray) -> VortexResult<(PrimitiveArray, StructArray)> {
+    // An empty array has no row to take the null value from, so its dictionary is empty too.
+    if array.is_empty() {
+        let validity = if array.dtype().is_nullable() {
+            Validity::AllValid
+        } else {
+            Validity::NonNullable
+        };
+        let values = StructArray::try_new(
+            array.names().clone(),
+            array.children().collect(),
+            0,
+            validity,
+        )?;
+        return Ok((PrimitiveArray::from(Vec::<u64>::new()), values));
+    }
+
+    // Rows are equal when the codes of each of their fields are equal.
+    let field_codes = array
+        .children()
+        .map(|field| Ok(dict_encode_parts(&Array::from(field.into_canonical()?))?.0))
+        .collect::<VortexResult<Vec<_>>>()?;
+    let nulls = array.logical_validity().to_null_buffer()?;
+
+    let mut lookup_dict: HashMap<Vec<u64>, u64> = HashMap::new();
+    let mut codes: Vec<u64> = Vec::with_capacity(array.len());
+    // The row of the array that each dictionary value is taken from.
+    let mut value_rows: Vec<u64> = Vec::new();
+
+    let nullable = array.dtype().is_nullable();
+    if nullable {
+        value_rows.push(0);
+    }
+
+    for row in 0..array.len() {
+        if nulls.as_ref().is_some_and(|n| n.is_null(row)) {
+            codes.push(0);
+            continue;
+        }
+        let key = field_codes
+            .iter()
+            .map(|c| c.maybe_null_slice::<u64>()[row])
+            .collect();
+        let code = match lookup_dict.entry(key) {
+            Entry::Occupied(o) => *o.get(),
+            Entry::Vacant(vac) => {
+                let next_code = value_rows.len() as u64;
+                vac.insert(next_code);
+                value_rows.push(row as u64);
+                next_code
+            }
+        };
+        codes.push(code);
+    }
+
+    let value_rows = PrimitiveArray::from(value_rows).into_array();
+    let fields = array
+        .children()
+        .map(|field| take(&field, &value_rows))
+        .collect::<VortexResult<Vec<_>>>()?;
+    let values = StructArray::try_new(
+        array.names().clone(),
+        fields,
+        value_rows.len(),
+        values_validity(nullable, value_rows.len()),
+    )?;
+
+    Ok((PrimitiveArray::from(codes), values))
+}
+
 /// Dictionary encode varbin array. Specializes for primitive byte arrays to avoid double copying
 pub fn dict_encode_varbin(array: &VarBinArray) -> (PrimitiveArray, VarBinArray) {
     array
@@ -89,6 +243,13 @@ pub fn dict_encode_varbin(array: &VarBinArray) -> (PrimitiveArray, VarBinArray)
         .unwrap()
 }
 
+/// Dictionary encode varbinview array into a varbin dictionary.
+pub fn dict_encode_varbinview(array: &VarBinViewArray) -> (PrimitiveArray, VarBinArray) {
+    array
+        .with_iterator(|iter| dict_encode_typed_varbin(array.dtype().clone(), iter))
+        .unwrap()
+}
+
 fn lookup_bytes<'a, T: NativePType + AsPrimitive<usize>>(
     offsets: &'a [T],
     bytes: &'a [u8],
@@ -147,15 +308,7 @@ where
         }
     }
 
-    let values_validity = if dtype.is_nullable() {
-        let mut validity = Vec::with_capacity(offsets.len() - 1);
-        validity.push(false);
-        validity.extend(vec![true; offsets.len() - 2]);
-
-        validity.into()
-    } else {
-        Validity::NonNullable
-    };
+    let values_validity = values_validity(dtype.is_nullable(), offsets.len() - 1);
 
     (
         PrimitiveArray::from(codes),
@@ -174,14 +327,19 @@ mod test {
     use std::str;
 
     use vortex::accessor::ArrayAccessor;
-    use vortex::array::{PrimitiveArray, VarBinArray};
+    use vortex::array::{BoolArray, PrimitiveArray, StructArray, VarBinArray, VarBinViewArray};
     use vortex::compute::unary::scalar_at;
-    use vortex::ToArray;
+    use vortex::validity::Validity;
+    use vortex::variants::StructArrayTrait;
+    use vortex::{ArrayDType, IntoArray, IntoArrayVariant, ToArray};
     use vortex_dtype::Nullability::Nullable;
     use vortex_dtype::{DType, PType};
     use vortex_scalar::Scalar;
 
-    use crate::compress::{dict_encode_typed_primitive, dict_encode_varbin};
+    use crate::compress::{
+        dict_encode, dict_encode_bool, dict_encode_struct, dict_encode_typed_primitive,
+        dict_encode_varbin, dict_encode_varbinview,
+    };
 
     #[test]
     fn encode_primitive() {
@@ -283,4 +441,85 @@ mod test {
             &[0u64, 0, 1, 1, 0, 1, 0, 1]
         );
     }
+
+    #[test]
+    fn encode_varbinview() {
+        let arr = VarBinViewArray::from_iter_nullable_str([
+            Some("a string longer than twelve bytes"),
+            None,
+            Some("short"),
+            Some("a string longer than twelve bytes"),
+        ]);
+        let (codes, values) = dict_encode_varbinview(&arr);
+        assert_eq!(codes.maybe_null_slice::<u64>(), &[1, 0, 2, 1]);
+        values
+            .with_iterator(|iter| {
+                assert_eq!(
+                    iter.map(|b| b.map(|v| unsafe { str::from_utf8_unchecked(v) }))
+                        .collect::<Vec<_>>(),
+                    vec![
+                        None,
+                        Some("a string longer than twelve bytes"),
+                        Some("short")
+                    ]
+                );
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn encode_bool_nulls() {
+        let arr = BoolArray::from_iter([Some(true), None, Some(true), Some(false), None]);
+        let (codes, values) = dict_encode_bool(&arr);
+        assert_eq!(codes.maybe_null_slice::<u64>(), &[1, 0, 1, 2, 0]);
+        assert_eq!(
+            values
+                .with_iterator(|iter| iter.map(|b| b.copied()).collect::<Vec<_>>())
+                .unwrap(),
+            vec![None, Some(true), Some(false)]
+        );
+    }
+
+    #[test]
+    fn encode_struct() {
+        let arr = StructArray::try_new(
+            ["a".into(), "b".into()].into(),
+            vec![
+                PrimitiveArray::from(vec![1, 1, 2, 1, 7]).into_array(),
+                VarBinArray::from(vec!["x", "y", "x", "x", "z"]).into_array(),
+            ],
+            5,
+            Validity::from(vec![true, true, true, true, false]),
+        )
+        .unwrap();
+        let (codes, values) = dict_encode_struct(&arr).unwrap();
+        assert_eq!(codes.maybe_null_slice::<u64>(), &[1, 2, 3, 1, 0]);
+        assert_eq!(values.len(), 4);
+        assert!(!values.validity().is_valid(0));
+        assert_eq!(
+            values
+                .field(0)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>()[1..],
+            [1, 1, 2]
+        );
+    }
+
+    #[test]
+    fn encode_empty_nullable_struct() {
+        let arr = StructArray::try_new(
+            ["a".into()].into(),
+            vec![PrimitiveArray::from(Vec::<i32>::new()).into_array()],
+            0,
+            Validity::AllValid,
+        )
+        .unwrap();
+        let (codes, values) = dict_encode_struct(&arr).unwrap();
+        assert!(codes.is_empty());
+        assert!(values.is_empty());
+        assert_eq!(values.dtype(), arr.dtype());
+        assert_eq!(dict_encode(arr.array()).unwrap().dtype(), arr.dtype());
+    }
 }
@@ -1,12 +1,18 @@
+use vortex::array::{Primitive, PrimitiveArray};
 use vortex::compute::unary::{scalar_at, scalar_at_unchecked, ScalarAtFn};
-use vortex::compute::{slice, take, ArrayCompute, SliceFn, TakeFn};
-use vortex::{Array, IntoArray};
+use vortex::compute::{filter, slice, take, ArrayCompute, FilterFn, SliceFn, TakeFn};
+use vortex::{Array, ArrayDType, ArrayDef, IntoArray, IntoArrayVariant};
+use vortex_dtype::match_each_unsigned_integer_ptype;
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::DictArray;
 
 impl ArrayCompute for DictArray {
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -42,24 +48,80 @@ impl TakeFn for DictArray {
         //   codes: 0 0 1
         //   dict: a b c d e f g h
         let codes = take(&self.codes(), indices)?;
-        Self::try_new(codes, self.values()).map(|a| a.into_array())
+        with_used_values(codes, self.values()).map(|a| a.into_array())
+    }
+}
+
+impl FilterFn for DictArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        let codes = filter(&self.codes(), predicate)?;
+        with_used_values(codes, self.values()).map(|a| a.into_array())
     }
 }
 
 impl SliceFn for DictArray {
-    // TODO(robert): Add function to trim the dictionary
     fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
-        Self::try_new(slice(&self.codes(), start, stop)?, self.values()).map(|a| a.into_array())
+        with_used_values(slice(&self.codes(), start, stop)?, self.values()).map(|a| a.into_array())
     }
 }
 
+/// Build a dictionary array over `codes`, trimming the `values` they don't reference when that is
+/// cheap or worthwhile.
+///
+/// Trimming scans all the codes, so it is only done when they are already canonical, or when
+/// there are more values than codes, in which case some values are certainly unused.
+fn with_used_values(codes: Array, values: Array) -> VortexResult<DictArray> {
+    if codes.encoding().id() == Primitive::ID || values.len() > codes.len() {
+        trim_values(codes, values)
+    } else {
+        DictArray::try_new(codes, values)
+    }
+}
+
+/// Build a dictionary array that keeps only the `values` referenced by `codes`.
+///
+/// Kept values stay in their original order, so a sorted dictionary remains sorted, and the
+/// null value of a nullable dictionary remains at code 0.
+fn trim_values(codes: Array, values: Array) -> VortexResult<DictArray> {
+    let codes = codes.into_primitive()?;
+    match_each_unsigned_integer_ptype!(codes.ptype(), |$C| {
+        let mut used = vec![false; values.len()];
+        if values.dtype().is_nullable() && !used.is_empty() {
+            used[0] = true;
+        }
+        for &code in codes.maybe_null_slice::<$C>() {
+            used[code as usize] = true;
+        }
+        if used.iter().all(|&u| u) {
+            return DictArray::try_new(codes.into_array(), values);
+        }
+
+        let mut remap = vec![0 as $C; values.len()];
+        let mut kept = Vec::new();
+        for (code, _) in used.iter().enumerate().filter(|(_, &u)| u) {
+            remap[code] = kept.len() as $C;
+            kept.push(code as u64);
+        }
+        let trimmed_codes: Vec<$C> = codes
+            .maybe_null_slice::<$C>()
+            .iter()
+            .map(|&code| remap[code as usize])
+            .collect();
+        DictArray::try_new(
+            PrimitiveArray::from_vec(trimmed_codes, codes.validity()).into_array(),
+            take(&values, &PrimitiveArray::from(kept).into_array())?,
+        )
+    })
+}
+
 #[cfg(test)]
 mod test {
-    use vortex::array::{PrimitiveArray, VarBinArray};
+    use vortex::array::{BoolArray, ConstantArray, PrimitiveArray, VarBinArray};
+    use vortex::compute::{filter, slice, take};
     use vortex::{IntoArray, IntoArrayVariant, ToArray};
     use vortex_dtype::{DType, Nullability};
 
-    use crate::{dict_encode_typed_primitive, dict_encode_varbin, DictArray};
+    use crate::{dict_encode, dict_encode_typed_primitive, dict_encode_varbin, DictArray};
 
     #[test]
     fn flatten_nullable_primitive() {
@@ -95,4 +157,104 @@ mod test {
             reference.bytes().into_primitive().unwrap().buffer()
         );
     }
+
+    #[test]
+    fn slice_trims_values() {
+        let reference = PrimitiveArray::from_nullable_vec(vec![
+            Some(5),
+            None,
+            Some(1),
+            Some(5),
+            Some(3),
+            Some(1),
+        ]);
+        let dict = dict_encode(reference.array()).unwrap();
+        assert_eq!(dict.values().len(), 4);
+
+        let sliced = DictArray::try_from(slice(dict.array(), 2, 4).unwrap()).unwrap();
+        assert_eq!(
+            sliced
+                .values()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            &[0, 5, 1]
+        );
+        assert_eq!(
+            sliced.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[1, 5]
+        );
+
+        let taken = DictArray::try_from(
+            take(
+                dict.array(),
+                &PrimitiveArray::from(vec![1u32, 4]).into_array(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(taken.values().len(), 2);
+        let taken = taken.into_primitive().unwrap();
+        assert!(!taken.validity().is_valid(0));
+        assert_eq!(taken.maybe_null_slice::<i32>()[1], 3);
+    }
+
+    #[test]
+    fn trims_only_canonical_or_large_dictionaries() {
+        let values = PrimitiveArray::from(vec![10i32, 20, 30, 40]).into_array();
+        let dict =
+            DictArray::try_new(ConstantArray::new(1u8, 10).into_array(), values.clone()).unwrap();
+
+        // Constant codes would need decoding to find the unused values.
+        let sliced = DictArray::try_from(slice(dict.array(), 0, 8).unwrap()).unwrap();
+        assert_eq!(sliced.values().len(), 4);
+
+        // Fewer codes than values leaves some values unused.
+        let sliced = DictArray::try_from(slice(dict.array(), 0, 2).unwrap()).unwrap();
+        assert_eq!(sliced.values().len(), 1);
+        assert_eq!(
+            sliced.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[20, 20]
+        );
+
+        // Canonical codes are cheap to scan.
+        let dict = DictArray::try_new(
+            PrimitiveArray::from(vec![0u8, 1, 1, 0, 3, 3, 0, 1]).into_array(),
+            values,
+        )
+        .unwrap();
+        let filtered = DictArray::try_from(
+            filter(
+                dict.array(),
+                BoolArray::from(vec![true, false, false, true, false, false, true, false]).array(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(filtered.values().len(), 1);
+    }
+
+    #[test]
+    fn filter_codes() {
+        let reference = VarBinArray::from(vec!["a", "b", "a", "c", "b"]);
+        let dict = dict_encode(reference.array()).unwrap();
+        let filtered = DictArray::try_from(
+            filter(
+                dict.array(),
+                BoolArray::from(vec![false, true, true, false, true]).array(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(filtered.len(), 3);
+        assert_eq!(filtered.values().len(), 2);
+        assert_eq!(
+            filtered
+                .codes()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[1, 0, 1]
+        );
+    }
 }
@@ -25,6 +25,12 @@ impl ArrayStatisticsCompute for DictArray {
         if let Some(null_count) = self.codes().statistics().compute(Stat::NullCount) {
             stats.insert(Stat::NullCount, null_count);
         }
+        // The array has no NaN if the dictionary has none, but may repeat any NaN of it.
+        if let Some(nan_count) = self.values().statistics().compute(Stat::NanCount) {
+            if usize::try_from(&nan_count).ok() == Some(0) {
+                stats.insert(Stat::NanCount, nan_count);
+            }
+        }
 
         // if dictionary is sorted
         if self
@@ -0,0 +1,166 @@
+use std::cmp::min;
+use std::mem::size_of;
+
+use fastlanes::BitPacking;
+use vortex::array::{BoolArray, PrimitiveArray};
+use vortex::compute::{filter, FilterFn};
+use vortex::validity::filter_validity;
+use vortex::{Array, IntoArray, IntoArrayVariant};
+use vortex_dtype::{match_each_unsigned_integer_ptype, NativePType};
+use vortex_error::VortexResult;
+
+use crate::BitPackedArray;
+
+impl FilterFn for BitPackedArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        let validity = filter_validity(self.validity(), predicate)?;
+        let patches = self
+            .patches()
+            .map(|patches| filter(&patches, predicate))
+            .transpose()?;
+        let predicate = predicate.clone().into_bool()?;
+        let length = predicate.boolean_buffer().count_set_bits();
+
+        let packed = match_each_unsigned_integer_ptype!(self.ptype(), |$T| {
+            PrimitiveArray::from(filter_packed::<$T>(self, &predicate)?)
+        });
+        BitPackedArray::try_new(
+            packed.into_array(),
+            validity,
+            patches,
+            self.bit_width(),
+            length,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+/// Pack the selected values of `array` into new 1024-value chunks. Chunks without selected values
+/// are skipped, fully selected chunks that stay aligned are copied as they are, and only the
+/// remaining chunks are unpacked.
+fn filter_packed<T: NativePType + BitPacking>(
+    array: &BitPackedArray,
+    predicate: &BoolArray,
+) -> VortexResult<Vec<T>> {
+    let bit_width = array.bit_width();
+    if bit_width == 0 {
+        return Ok(Vec::new());
+    }
+
+    let predicate = predicate.boolean_buffer();
+    let offset = array.offset();
+    let packed = array.packed().into_primitive()?;
+    let packed = packed.maybe_null_slice::<T>();
+    let chunk_size = 128 * bit_width / size_of::<T>();
+
+    let mut output = Vec::with_capacity(packed.len());
+    let mut pending = Vec::with_capacity(1024);
+    let mut unpacked = [T::zero(); 1024];
+    for chunk in 0..(array.len() + offset + 1023) / 1024 {
+        // The values of the array held in this chunk, which starts `offset` values early.
+        let start = (chunk * 1024).saturating_sub(offset);
+        let end = min((chunk + 1) * 1024 - offset, array.len());
+        let selected = predicate.slice(start, end - start);
+        let count = selected.count_set_bits();
+        if count == 0 {
+            continue;
+        }
+
+        let packed_chunk = &packed[chunk * chunk_size..][..chunk_size];
+        if count == 1024 && pending.is_empty() {
+            output.extend_from_slice(packed_chunk);
+            continue;
+        }
+
+        unsafe { BitPacking::unchecked_unpack(bit_width, packed_chunk, &mut unpacked) };
+        let chunk_offset = start + offset - chunk * 1024;
+        for index in selected.set_indices() {
+            pending.push(unpacked[chunk_offset + index]);
+            if pending.len() == 1024 {
+                pack_chunk(bit_width, &pending, &mut output, chunk_size);
+                pending.clear();
+            }
+        }
+    }
+
+    if !pending.is_empty() {
+        pending.resize(1024, T::zero());
+        pack_chunk(bit_width, &pending, &mut output, chunk_size);
+    }
+    Ok(output)
+}
+
+fn pack_chunk<T: NativePType + BitPacking>(
+    bit_width: usize,
+    values: &[T],
+    output: &mut Vec<T>,
+    chunk_size: usize,
+) {
+    let output_len = output.len();
+    output.resize(output_len + chunk_size, T::zero());
+    unsafe { BitPacking::unchecked_pack(bit_width, values, &mut output[output_len..]) };
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::{BoolArray, PrimitiveArray};
+    use vortex::compute::{filter, slice};
+    use vortex::validity::ArrayValidity;
+    use vortex::{Array, ArrayDef, IntoArrayVariant};
+
+    use crate::{bitpack_encode, BitPacked, BitPackedArray};
+
+    fn filter_and_check(array: &Array, values: &[Option<u32>], predicate: Vec<bool>) -> Array {
+        let filtered = filter(array, BoolArray::from(predicate.clone()).array()).unwrap();
+        assert_eq!(filtered.encoding().id(), BitPacked::ID);
+
+        let decoded = filtered.clone().into_primitive().unwrap();
+        let expected = values
+            .iter()
+            .zip(predicate)
+            .filter_map(|(&v, keep)| keep.then_some(v))
+            .collect::<Vec<_>>();
+        assert_eq!(decoded.len(), expected.len());
+        for (i, v) in expected.into_iter().enumerate() {
+            assert_eq!(decoded.is_valid(i), v.is_some());
+            if let Some(v) = v {
+                assert_eq!(decoded.maybe_null_slice::<u32>()[i], v);
+            }
+        }
+        filtered
+    }
+
+    #[test]
+    fn filter_bitpacked() {
+        let values: Vec<u32> = (0..3000).map(|i| i % 63).chain([1 << 20]).collect();
+        let packed = bitpack_encode(PrimitiveArray::from(values.clone()), 6).unwrap();
+
+        // Part of the first chunk, all of the second and part of the third with a patch.
+        let predicate = (0..values.len())
+            .map(|i| (1024..2048).contains(&i) || i % 7 == 0 || i == 3000)
+            .collect();
+        let values = values.into_iter().map(Some).collect::<Vec<_>>();
+        let filtered = filter_and_check(packed.array(), &values, predicate);
+        let filtered = BitPackedArray::try_from(filtered).unwrap();
+        assert_eq!(filtered.packed().len(), 2 * 128 * 6 / 4);
+
+        // Chunks without selected values are dropped, fully selected ones are copied.
+        let predicate = (0..values.len())
+            .map(|i| (1024..2048).contains(&i))
+            .collect();
+        let filtered = filter_and_check(packed.array(), &values, predicate);
+        let filtered = BitPackedArray::try_from(filtered).unwrap();
+        assert_eq!(filtered.packed().len(), 128 * 6 / 4);
+    }
+
+    #[test]
+    fn filter_sliced_nullable() {
+        let values: Vec<Option<u32>> = (0..4000).map(|i| (i % 5 != 0).then_some(i % 100)).collect();
+        let packed = bitpack_encode(PrimitiveArray::from_nullable_vec(values.clone()), 7).unwrap();
+        let sliced = slice(packed.array(), 1000, 3500).unwrap();
+        assert_eq!(BitPackedArray::try_from(&sliced).unwrap().offset(), 1000);
+
+        let predicate = (0..2500).map(|i| i % 3 != 0 || i > 2000).collect();
+        filter_and_check(&sliced, &values[1000..3500], predicate);
+    }
+}
@@ -1,14 +1,19 @@
 use vortex::compute::unary::ScalarAtFn;
-use vortex::compute::{ArrayCompute, SearchSortedFn, SliceFn, TakeFn};
+use vortex::compute::{ArrayCompute, FilterFn, SearchSortedFn, SliceFn, TakeFn};
 
 use crate::BitPackedArray;
 
+mod filter;
 mod scalar_at;
 mod search_sorted;
 mod slice;
 mod take;
 
 impl ArrayCompute for BitPackedArray {
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
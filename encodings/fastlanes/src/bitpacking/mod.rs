@@ -1,6 +1,7 @@
 use ::serde::{Deserialize, Serialize};
 pub use compress::*;
 use vortex::array::{Primitive, PrimitiveArray};
+use vortex::encoding::ids;
 use vortex::stats::{ArrayStatisticsCompute, StatsSet};
 use vortex::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
 use vortex::variants::{ArrayVariants, PrimitiveArrayTrait};
@@ -12,7 +13,7 @@ use vortex_error::{vortex_bail, vortex_err, VortexResult};
 mod compress;
 mod compute;
 
-impl_encoding!("fastlanes.bitpacked", 14u16, BitPacked);
+impl_encoding!("fastlanes.bitpacked", ids::FL_BITPACKED, BitPacked);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BitPackedMetadata {
@@ -99,11 +99,13 @@ where
 pub fn delta_decompress(array: DeltaArray) -> VortexResult<PrimitiveArray> {
     let bases = array.bases().into_primitive()?;
     let deltas = array.deltas().into_primitive()?;
+    let start = array.offset();
+    let end = start + array.len();
     let decoded = match_each_unsigned_integer_ptype!(deltas.ptype(), |$T| {
-        PrimitiveArray::from_vec(
-            decompress_primitive::<$T>(bases.maybe_null_slice(), deltas.maybe_null_slice()),
-            array.validity()
-        )
+        let mut decoded = decompress_primitive::<$T>(bases.maybe_null_slice(), deltas.maybe_null_slice());
+        decoded.truncate(end);
+        decoded.drain(..start);
+        PrimitiveArray::from_vec(decoded, array.validity())
     });
     Ok(decoded)
 }
@@ -1,5 +1,284 @@
-use vortex::compute::ArrayCompute;
+use std::cmp::min;
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+
+use vortex::array::PrimitiveArray;
+use vortex::compute::unary::{scalar_at, scalar_at_unchecked, ScalarAtFn};
+use vortex::compute::{
+    search_sorted, slice, ArrayCompute, SearchResult, SearchSortedFn, SearchSortedSide, SliceFn,
+    TakeFn,
+};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::{match_each_integer_ptype, match_each_unsigned_integer_ptype};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
 
 use crate::DeltaArray;
 
-impl ArrayCompute for DeltaArray {}
+impl ArrayCompute for DeltaArray {
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn search_sorted(&self) -> Option<&dyn SearchSortedFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn for DeltaArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        // Decoding a single value requires decoding the chunk that contains it.
+        let decoded = SliceFn::slice(self, index, index + 1)?.into_primitive()?;
+        scalar_at(decoded.array(), 0)
+    }
+
+    fn scalar_at_unchecked(&self, index: usize) -> Scalar {
+        <Self as ScalarAtFn>::scalar_at(self, index).unwrap()
+    }
+}
+
+impl SliceFn for DeltaArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        let validity = self.validity().slice(start, stop)?;
+        if start == stop {
+            return DeltaArray::try_new(
+                slice(&self.bases(), 0, 0)?,
+                slice(&self.deltas(), 0, 0)?,
+                validity,
+            )
+            .map(|a| a.into_array());
+        }
+
+        // Keep every chunk that overlaps the slice. Full chunks have `lanes` bases each, and the
+        // trailing remainder has a single base.
+        let lanes = self.lanes();
+        let deltas = self.deltas();
+        let full_chunks = deltas.len() / 1024;
+        let start_chunk = (self.offset() + start) / 1024;
+        let stop_chunk = (self.offset() + stop + 1023) / 1024;
+
+        let bases_stop = if stop_chunk > full_chunks {
+            full_chunks * lanes + 1
+        } else {
+            stop_chunk * lanes
+        };
+
+        DeltaArray::try_new_from_offset(
+            slice(&self.bases(), start_chunk * lanes, bases_stop)?,
+            slice(
+                &deltas,
+                start_chunk * 1024,
+                min(stop_chunk * 1024, deltas.len()),
+            )?,
+            validity,
+            (self.offset() + start) % 1024,
+            stop - start,
+        )
+        .map(|a| a.into_array())
+    }
+}
+
+impl TakeFn for DeltaArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let indices = indices.clone().into_primitive()?;
+        let indices: Vec<usize> = match_each_integer_ptype!(indices.ptype(), |$I| {
+            indices.maybe_null_slice::<$I>().iter().map(|&i| i as usize).collect()
+        });
+
+        // Decode each chunk touched by the indices once.
+        let mut chunks = BTreeMap::new();
+        for &index in &indices {
+            if index >= self.len() {
+                vortex_bail!(OutOfBounds: index, 0, self.len());
+            }
+            if let Entry::Vacant(entry) = chunks.entry(self.chunk_of(index)) {
+                let (start, stop) = self.chunk_bounds(*entry.key());
+                entry.insert((start, SliceFn::slice(self, start, stop)?.into_primitive()?));
+            }
+        }
+
+        let validity = self.validity().take(
+            &PrimitiveArray::from(indices.iter().map(|&i| i as u64).collect::<Vec<_>>())
+                .into_array(),
+        )?;
+        let ptype = self.dtype().try_into()?;
+        let taken = match_each_unsigned_integer_ptype!(ptype, |$T| {
+            let values: Vec<$T> = indices
+                .iter()
+                .map(|&index| {
+                    let (start, decoded) = &chunks[&self.chunk_of(index)];
+                    decoded.maybe_null_slice::<$T>()[index - start]
+                })
+                .collect();
+            PrimitiveArray::from_vec(values, validity)
+        });
+        Ok(taken.into_array())
+    }
+}
+
+impl SearchSortedFn for DeltaArray {
+    fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult> {
+        if self.is_empty() {
+            return Ok(SearchResult::NotFound(0));
+        }
+
+        // Find the chunks whose first value is before the target, reading only the first value of
+        // each chunk, then search the last of those chunks and the one following it.
+        let nchunks = self.chunk_of(self.len() - 1) + 1;
+        let before = partition_point(nchunks, |chunk| {
+            let first = scalar_at_unchecked(self.array(), self.chunk_bounds(chunk).0);
+            match side {
+                SearchSortedSide::Left => &first < value,
+                SearchSortedSide::Right => &first <= value,
+            }
+        });
+
+        let start = self.chunk_bounds(before.saturating_sub(1)).0;
+        let stop = self.chunk_bounds(min(before, nchunks - 1)).1;
+        let decoded = SliceFn::slice(self, start, stop)?.into_primitive()?;
+        Ok(search_sorted(decoded.array(), value.clone(), side)?.map(|i| i + start))
+    }
+}
+
+impl DeltaArray {
+    /// The index of the chunk containing the value at `index`.
+    fn chunk_of(&self, index: usize) -> usize {
+        (self.offset() + index) / 1024
+    }
+
+    /// The range of indices of this array covered by `chunk`.
+    fn chunk_bounds(&self, chunk: usize) -> (usize, usize) {
+        let start = (chunk * 1024).saturating_sub(self.offset());
+        let stop = min((chunk + 1) * 1024 - self.offset(), self.len());
+        (start, stop)
+    }
+}
+
+/// The number of leading values in `0..len` for which `pred` holds, assuming it holds for a
+/// prefix of the range.
+fn partition_point(len: usize, mut pred: impl FnMut(usize) -> bool) -> usize {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::compute::unary::scalar_at;
+    use vortex::compute::{search_sorted, slice, take, SearchResult, SearchSortedSide};
+    use vortex::validity::Validity;
+    use vortex::{ArrayDef, IntoArrayVariant};
+
+    use super::*;
+    use crate::{delta_compress, Delta};
+
+    fn delta(values: Vec<u32>) -> Array {
+        let (bases, deltas) = delta_compress(&PrimitiveArray::from(values)).unwrap();
+        DeltaArray::try_new(
+            bases.into_array(),
+            deltas.into_array(),
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    #[test]
+    fn test_scalar_at() {
+        let array = delta((0u32..3000).map(|i| i * 2).collect());
+        for index in [0, 1, 1023, 1024, 2047, 2048, 2999] {
+            assert_eq!(
+                scalar_at(&array, index).unwrap(),
+                ((index * 2) as u32).into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_slice() {
+        let array = delta((0u32..3000).collect());
+
+        let sliced = slice(&array, 1000, 2100).unwrap();
+        assert_eq!(sliced.encoding().id(), Delta::ID);
+        assert_eq!(
+            sliced
+                .clone()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u32>(),
+            (1000u32..2100).collect::<Vec<_>>()
+        );
+
+        // Slicing a slice accounts for the offset into the first chunk.
+        let nested = slice(&sliced, 30, 1050).unwrap();
+        assert_eq!(
+            nested.into_primitive().unwrap().maybe_null_slice::<u32>(),
+            (1030u32..2050).collect::<Vec<_>>()
+        );
+
+        assert_eq!(slice(&array, 1024, 1024).unwrap().len(), 0);
+        assert_eq!(
+            slice(&array, 2990, 3000)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u32>(),
+            (2990u32..3000).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_take() {
+        let array = delta((0u32..3000).map(|i| i * 3).collect());
+        let taken = take(
+            &array,
+            &PrimitiveArray::from(vec![2999u32, 0, 1500, 1024]).into_array(),
+        )
+        .unwrap();
+        assert_eq!(
+            taken.into_primitive().unwrap().maybe_null_slice::<u32>(),
+            &[8997, 0, 4500, 3072]
+        );
+    }
+
+    #[test]
+    fn test_search_sorted() {
+        let array = delta((0u32..3000).map(|i| i / 2 * 2).collect());
+
+        assert_eq!(
+            search_sorted(&array, 2048u32, SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(2048)
+        );
+        assert_eq!(
+            search_sorted(&array, 2048u32, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(2050)
+        );
+        assert_eq!(
+            search_sorted(&array, 1023u32, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(1024)
+        );
+        assert_eq!(
+            search_sorted(&array, 5000u32, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(3000)
+        );
+        assert_eq!(
+            search_sorted(&array, 0u32, SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(0)
+        );
+    }
+}
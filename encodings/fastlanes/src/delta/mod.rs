@@ -2,6 +2,7 @@ use std::fmt::Debug;
 
 pub use compress::*;
 use serde::{Deserialize, Serialize};
+use vortex::encoding::ids;
 use vortex::stats::{ArrayStatisticsCompute, StatsSet};
 use vortex::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
 use vortex::variants::{ArrayVariants, PrimitiveArrayTrait};
@@ -13,16 +14,33 @@ use vortex_error::{vortex_bail, VortexResult};
 mod compress;
 mod compute;
 
-impl_encoding!("fastlanes.delta", 16u16, Delta);
+impl_encoding!("fastlanes.delta", ids::FL_DELTA, Delta);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeltaMetadata {
     validity: ValidityMetadata,
-    len: usize,
+    deltas_len: usize,
+    offset: usize,
 }
 
 impl DeltaArray {
     pub fn try_new(bases: Array, deltas: Array, validity: Validity) -> VortexResult<Self> {
+        let len = deltas.len();
+        Self::try_new_from_offset(bases, deltas, validity, 0, len)
+    }
+
+    /// Build a DeltaArray of `len` values, starting `offset` values into the first chunk of
+    /// `deltas`.
+    ///
+    /// `bases` and `deltas` always cover whole chunks of 1024 values, plus the trailing remainder,
+    /// so slices of a DeltaArray keep the chunks they overlap and record where they start.
+    pub fn try_new_from_offset(
+        bases: Array,
+        deltas: Array,
+        validity: Validity,
+        offset: usize,
+        len: usize,
+    ) -> VortexResult<Self> {
         if bases.dtype() != deltas.dtype() {
             vortex_bail!(
                 "DeltaArray: bases and deltas must have the same dtype, got {:?} and {:?}",
@@ -31,11 +49,24 @@ impl DeltaArray {
             );
         }
 
+        if offset >= 1024 {
+            vortex_bail!("DeltaArray: offset must be less than 1024, got {}", offset);
+        }
+
+        if offset + len > deltas.len() {
+            vortex_bail!(
+                "DeltaArray: offset ({}) + len ({}) exceeds deltas.len() ({})",
+                offset,
+                len,
+                deltas.len()
+            );
+        }
+
         let dtype = bases.dtype().clone();
-        let len = deltas.len();
         let metadata = DeltaMetadata {
             validity: validity.to_metadata(len)?,
-            len,
+            deltas_len: deltas.len(),
+            offset,
         };
 
         let mut children = vec![bases, deltas];
@@ -46,10 +77,10 @@ impl DeltaArray {
         let delta = Self::try_from_parts(dtype, len, metadata, children.into(), StatsSet::new())?;
         if delta.bases().len() != delta.bases_len() {
             vortex_bail!(
-                "DeltaArray: bases.len() ({}) != expected_bases_len ({}), based on len ({}) and lane count ({})",
+                "DeltaArray: bases.len() ({}) != expected_bases_len ({}), based on deltas.len() ({}) and lane count ({})",
                 delta.bases().len(),
                 delta.bases_len(),
-                len,
+                delta.deltas_len(),
                 delta.lanes()
             );
         }
@@ -67,12 +98,23 @@ impl DeltaArray {
     #[inline]
     pub fn deltas(&self) -> Array {
         self.array()
-            .child(1, self.dtype(), self.len())
+            .child(1, self.dtype(), self.deltas_len())
             .expect("Missing deltas")
     }
 
+    /// The number of values into the first chunk of the deltas at which this array starts.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.metadata().offset
+    }
+
+    #[inline]
+    fn deltas_len(&self) -> usize {
+        self.metadata().deltas_len
+    }
+
     #[inline]
-    fn lanes(&self) -> usize {
+    pub(crate) fn lanes(&self) -> usize {
         let ptype = self.dtype().try_into().unwrap();
         match_each_unsigned_integer_ptype!(ptype, |$T| {
             <$T as fastlanes::FastLanes>::LANES
@@ -86,8 +128,8 @@ impl DeltaArray {
     }
 
     fn bases_len(&self) -> usize {
-        let num_chunks = self.len() / 1024;
-        let remainder_base_size = if self.len() % 1024 > 0 { 1 } else { 0 };
+        let num_chunks = self.deltas_len() / 1024;
+        let remainder_base_size = if self.deltas_len() % 1024 > 0 { 1 } else { 0 };
         num_chunks * self.lanes() + remainder_base_size
     }
 }
@@ -121,7 +163,8 @@ impl ArrayValidity for DeltaArray {
 impl AcceptArrayVisitor for DeltaArray {
     fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
         visitor.visit_child("bases", &self.bases())?;
-        visitor.visit_child("deltas", &self.deltas())
+        visitor.visit_child("deltas", &self.deltas())?;
+        visitor.visit_validity(&self.validity())
     }
 }
 
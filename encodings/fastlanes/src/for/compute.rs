@@ -1,9 +1,10 @@
+use vortex::array::ConstantArray;
 use vortex::compute::unary::{scalar_at_unchecked, ScalarAtFn};
 use vortex::compute::{
-    search_sorted, slice, take, ArrayCompute, SearchResult, SearchSortedFn, SearchSortedSide,
-    SliceFn, TakeFn,
+    compare, filter, search_sorted, slice, take, ArrayCompute, CompareFn, FilterFn, Operator,
+    SearchResult, SearchSortedFn, SearchSortedSide, SliceFn, TakeFn,
 };
-use vortex::{Array, ArrayDType, IntoArray};
+use vortex::{Array, ArrayDType, IntoArray, IntoCanonical};
 use vortex_dtype::match_each_integer_ptype;
 use vortex_error::VortexResult;
 use vortex_scalar::{PrimitiveScalar, Scalar, ScalarValue};
@@ -11,6 +12,14 @@ use vortex_scalar::{PrimitiveScalar, Scalar, ScalarValue};
 use crate::FoRArray;
 
 impl ArrayCompute for FoRArray {
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -28,6 +37,61 @@ impl ArrayCompute for FoRArray {
     }
 }
 
+impl CompareFn for FoRArray {
+    fn compare(&self, other: &Array, operator: Operator) -> VortexResult<Array> {
+        if let Ok(constant) = ConstantArray::try_from(other) {
+            if let Some(encoded) = self.encode_scalar(constant.scalar())? {
+                return compare(
+                    &self.encoded(),
+                    ConstantArray::new(encoded, self.len()).array(),
+                    operator,
+                );
+            }
+        }
+
+        let decoded = Array::from(self.clone().into_canonical()?);
+        compare(&decoded, other, operator)
+    }
+}
+
+impl FoRArray {
+    /// Translate `value` into the unsigned domain of the encoded child, returning `None` if no
+    /// encoded value decodes to it.
+    ///
+    /// Encoded values are offsets from the reference, so ordering is preserved for any value that
+    /// is not below the reference and has at least `shift` trailing zeros.
+    fn encode_scalar(&self, value: &Scalar) -> VortexResult<Option<Scalar>> {
+        if value.is_null() {
+            return Ok(None);
+        }
+
+        match_each_integer_ptype!(self.ptype(), |$P| {
+            let reference: $P = self.reference().try_into()?;
+            let value: $P = value.cast(self.dtype())?.try_into()?;
+            if value < reference || value.trailing_zeros() < self.shift() as u32 {
+                return Ok(None);
+            }
+
+            let encoded = (value >> self.shift()).wrapping_sub(reference >> self.shift());
+            Ok(Some(
+                Scalar::primitive(encoded, self.dtype().nullability())
+                    .reinterpret_cast(self.ptype().to_unsigned()),
+            ))
+        })
+    }
+}
+
+impl FilterFn for FoRArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        Self::try_new(
+            filter(&self.encoded(), predicate)?,
+            self.reference().clone(),
+            self.shift(),
+        )
+        .map(|a| a.into_array())
+    }
+}
+
 impl TakeFn for FoRArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
         Self::try_new(
@@ -94,11 +158,14 @@ impl SearchSortedFn for FoRArray {
 
 #[cfg(test)]
 mod test {
-    use vortex::array::PrimitiveArray;
+    use vortex::array::{BoolArray, ConstantArray, PrimitiveArray};
     use vortex::compute::unary::scalar_at;
-    use vortex::compute::{search_sorted, SearchResult, SearchSortedSide};
+    use vortex::compute::{
+        compare, filter, search_sorted, Operator, SearchResult, SearchSortedSide,
+    };
+    use vortex::{ArrayDef, IntoArrayVariant};
 
-    use crate::for_compress;
+    use crate::{for_compress, FoR};
 
     #[test]
     fn for_scalar_at() {
@@ -124,4 +191,56 @@ mod test {
             SearchResult::NotFound(0)
         );
     }
+
+    #[test]
+    fn for_filter() {
+        let for_arr = for_compress(&PrimitiveArray::from(vec![1100, 1500, 1900, 2300])).unwrap();
+        let filtered = filter(
+            &for_arr,
+            BoolArray::from(vec![true, false, false, true]).array(),
+        )
+        .unwrap();
+        assert_eq!(filtered.encoding().id(), FoR::ID);
+        assert_eq!(
+            filtered.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[1100, 2300]
+        );
+    }
+
+    #[test]
+    fn for_compare() {
+        let for_arr = for_compress(&PrimitiveArray::from(vec![1100, 1500, 1900, 2300])).unwrap();
+        let compare_to = |value: i32, operator: Operator| {
+            compare(
+                &for_arr,
+                ConstantArray::new(value, for_arr.len()).array(),
+                operator,
+            )
+            .unwrap()
+            .into_bool()
+            .unwrap()
+            .boolean_buffer()
+            .iter()
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            compare_to(1500, Operator::Eq),
+            vec![false, true, false, false]
+        );
+        assert_eq!(
+            compare_to(1900, Operator::Lt),
+            vec![true, true, false, false]
+        );
+        assert_eq!(
+            compare_to(1900, Operator::Gte),
+            vec![false, false, true, true]
+        );
+        // Below the reference, and not a multiple of the shift, so compared after decoding.
+        assert_eq!(compare_to(1000, Operator::Gt), vec![true; 4]);
+        assert_eq!(
+            compare_to(1501, Operator::Lte),
+            vec![true, true, false, false]
+        );
+    }
 }
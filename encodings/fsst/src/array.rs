@@ -1,16 +1,17 @@
 use std::sync::Arc;
 
-use fsst::{Decompressor, Symbol, MAX_CODE};
+use fsst::{Compressor, Decompressor, Symbol, MAX_CODE};
 use serde::{Deserialize, Serialize};
-use vortex::stats::{ArrayStatisticsCompute, StatsSet};
+use vortex::encoding::ids;
+use vortex::stats::{ArrayStatisticsCompute, Stat, StatsSet};
 use vortex::validity::{ArrayValidity, LogicalValidity};
 use vortex::variants::{ArrayVariants, BinaryArrayTrait, Utf8ArrayTrait};
-use vortex::visitor::AcceptArrayVisitor;
+use vortex::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use vortex::{impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, IntoCanonical};
 use vortex_dtype::{DType, Nullability, PType};
 use vortex_error::{vortex_bail, VortexResult};
 
-impl_encoding!("vortex.fsst", 24u16, FSST);
+impl_encoding!("vortex.fsst", ids::FSST, FSST);
 
 static SYMBOLS_DTYPE: DType = DType::Primitive(PType::U64, Nullability::NonNullable);
 
@@ -97,15 +98,55 @@ impl FSSTArray {
         // Build a new decompressor that uses these symbols.
         Decompressor::new(symbols)
     }
+
+    /// Rebuild a [`Compressor`][fsst::Compressor] from the symbol table of this array.
+    ///
+    /// Symbols are re-inserted in code order, which reproduces the lookup tables of the
+    /// compressor that was trained for this array. New values compressed with it therefore produce
+    /// the same codes as equal values stored in this array.
+    pub(crate) fn compressor(&self) -> Compressor {
+        let mut compressor = Compressor::default();
+        // The first 256 entries of the symbol table are the escaped bytes.
+        for symbol in self.symbol_table().into_iter().skip(256) {
+            compressor.insert(symbol);
+        }
+        compressor
+    }
+
+    /// Copy the symbol table of this array, indexed by code.
+    pub(crate) fn symbol_table(&self) -> Vec<Symbol> {
+        let symbols_array = self
+            .symbols()
+            .into_canonical()
+            .unwrap()
+            .into_primitive()
+            .expect("Symbols must be a Primitive Array");
+
+        symbols_array
+            .maybe_null_slice::<u64>()
+            .iter()
+            .map(|symbol| Symbol::from_slice(&symbol.to_le_bytes()))
+            .collect()
+    }
 }
 
 impl AcceptArrayVisitor for FSSTArray {
-    fn accept(&self, _visitor: &mut dyn vortex::visitor::ArrayVisitor) -> VortexResult<()> {
-        todo!("implement this")
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("symbols", &self.symbols())?;
+        visitor.visit_child("codes", &self.codes())
     }
 }
 
-impl ArrayStatisticsCompute for FSSTArray {}
+impl ArrayStatisticsCompute for FSSTArray {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        // Statistics are computed over the decoded strings, as the order of the codes does not
+        // follow the order of the values they encode.
+        self.clone()
+            .into_canonical()?
+            .into_varbin()?
+            .compute_statistics(stat)
+    }
+}
 
 impl ArrayValidity for FSSTArray {
     fn is_valid(&self, index: usize) -> bool {
@@ -0,0 +1,78 @@
+// Compress a set of values into an Array.
+
+use fsst::{Compressor, Symbol};
+use vortex::accessor::ArrayAccessor;
+use vortex::array::builder::VarBinBuilder;
+use vortex::array::{PrimitiveArray, VarBin, VarBinArray, VarBinView, VarBinViewArray};
+use vortex::validity::Validity;
+use vortex::{Array, ArrayDType, ArrayDef, IntoArray};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::FSSTArray;
+
+/// Compress an array of strings or binary values using the symbol table of `compressor`.
+///
+/// # Errors
+///
+/// Returns an error if `strings` is not a `VarBin` or `VarBinView` array.
+pub fn fsst_compress(strings: &Array, compressor: &Compressor) -> VortexResult<FSSTArray> {
+    let len = strings.len();
+    let dtype = strings.dtype().clone();
+
+    let codes = match strings.encoding().id() {
+        VarBin::ID => VarBinArray::try_from(strings)?
+            .with_iterator(|iter| compress_iter(iter, len, dtype.clone(), compressor))?,
+        VarBinView::ID => VarBinViewArray::try_from(strings)?
+            .with_iterator(|iter| compress_iter(iter, len, dtype.clone(), compressor))?,
+        _ => vortex_bail!(
+            "fsst_compress can only compress VarBin or VarBinView, got {}",
+            strings.encoding().id()
+        ),
+    };
+
+    // SAFETY: Symbol and u64 have the same size, enforced by the compiler.
+    let symbols = unsafe { std::mem::transmute::<&[Symbol], &[u64]>(compressor.symbol_table()) };
+    let symbols = PrimitiveArray::from_vec(symbols.to_vec(), Validity::NonNullable);
+
+    FSSTArray::try_new(dtype, symbols.into_array(), codes.into_array())
+}
+
+/// Train a [`Compressor`] on the values of an array of strings or binary values.
+///
+/// # Errors
+///
+/// Returns an error if `array` is not a `VarBin` or `VarBinView` array.
+pub fn fsst_train_compressor(array: &Array) -> VortexResult<Compressor> {
+    match array.encoding().id() {
+        VarBin::ID => VarBinArray::try_from(array)?.with_iterator(train_compressor),
+        VarBinView::ID => VarBinViewArray::try_from(array)?.with_iterator(train_compressor),
+        _ => vortex_bail!(
+            "fsst_train_compressor can only train on VarBin or VarBinView, got {}",
+            array.encoding().id()
+        ),
+    }
+}
+
+fn train_compressor(iter: &mut dyn Iterator<Item = Option<&[u8]>>) -> Compressor {
+    // The training corpus is the concatenation of all non-null values.
+    let corpus = iter.flatten().flatten().copied().collect::<Vec<u8>>();
+    Compressor::train(corpus)
+}
+
+fn compress_iter(
+    iter: &mut dyn Iterator<Item = Option<&[u8]>>,
+    len: usize,
+    dtype: DType,
+    compressor: &Compressor,
+) -> VarBinArray {
+    let mut builder = VarBinBuilder::<i32>::with_capacity(len);
+    for value in iter {
+        match value {
+            Some(value) => builder.push_value(compressor.compress(value)),
+            None => builder.push_null(),
+        }
+    }
+
+    builder.finish(DType::Binary(dtype.nullability()))
+}
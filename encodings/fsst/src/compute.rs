@@ -1,14 +1,27 @@
-use vortex::array::varbin_scalar;
+use vortex::accessor::ArrayAccessor;
+use vortex::array::{varbin_scalar, BoolArray, ConstantArray};
 use vortex::compute::unary::{scalar_at_unchecked, ScalarAtFn};
-use vortex::compute::{filter, slice, take, ArrayCompute, FilterFn, SliceFn, TakeFn};
-use vortex::{Array, ArrayDType, IntoArray};
+use vortex::compute::{
+    compare, filter, slice, take, ArrayCompute, CompareFn, FilterFn, LikeFn, Operator, SliceFn,
+    TakeFn,
+};
+use vortex::{Array, ArrayDType, IntoArray, IntoCanonical};
 use vortex_buffer::Buffer;
+use vortex_dtype::{DType, Nullability};
 use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::FSSTArray;
 
 impl ArrayCompute for FSSTArray {
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn like(&self) -> Option<&dyn LikeFn> {
+        Some(self)
+    }
+
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
@@ -80,3 +93,52 @@ impl FilterFn for FSSTArray {
         Ok(Self::try_new(self.dtype().clone(), self.symbols(), filtered_codes)?.into_array())
     }
 }
+
+impl CompareFn for FSSTArray {
+    fn compare(&self, other: &Array, operator: Operator) -> VortexResult<Array> {
+        match (ConstantArray::try_from(other), operator) {
+            (Ok(constant), Operator::Eq | Operator::NotEq) => {
+                compare_constant(self, constant.scalar(), operator)
+            }
+            // Ordering is not preserved by the codes, so fall back to comparing decoded values.
+            _ => {
+                let decoded = self.clone().into_canonical()?.into_varbin()?;
+                compare(decoded.array(), other, operator)
+            }
+        }
+    }
+}
+
+/// Compare the values of `array` to a constant without decompressing them.
+///
+/// Compressing the constant with the symbol table of the array yields the same codes as any
+/// equal value in the array, and different codes from any other value.
+fn compare_constant(array: &FSSTArray, value: &Scalar, operator: Operator) -> VortexResult<Array> {
+    let Some(value) = scalar_bytes(value)? else {
+        return Ok(ConstantArray::new(
+            Scalar::null(DType::Bool(Nullability::Nullable)),
+            array.len(),
+        )
+        .into_array());
+    };
+
+    let encoded = array.compressor().compress(value.as_slice());
+    codes_eq(array, &encoded, operator == Operator::NotEq)
+}
+
+/// Compare the codes of every value of `array` to `encoded`, inverting the result if `negated`.
+pub(crate) fn codes_eq(array: &FSSTArray, encoded: &[u8], negated: bool) -> VortexResult<Array> {
+    let codes = array.codes().into_canonical()?.into_varbin()?;
+    let matches = codes.with_iterator(|iter| {
+        iter.map(|codes| codes.map(|codes| (codes == encoded) != negated))
+            .collect::<BoolArray>()
+    })?;
+    Ok(matches.into_array())
+}
+
+fn scalar_bytes(scalar: &Scalar) -> VortexResult<Option<Buffer>> {
+    match scalar.dtype() {
+        DType::Utf8(_) => Ok(scalar.value().as_buffer_string()?.map(Buffer::from)),
+        _ => scalar.value().as_buffer(),
+    }
+}
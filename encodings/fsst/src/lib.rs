@@ -10,6 +10,9 @@
 
 mod array;
 mod canonical;
+mod compress;
 mod compute;
+mod like;
 
 pub use array::*;
+pub use compress::*;
@@ -0,0 +1,201 @@
+use fsst::{Symbol, ESCAPE_CODE};
+use vortex::accessor::ArrayAccessor;
+use vortex::array::BoolArray;
+use vortex::compute::LikeFn;
+use vortex::{Array, ArrayDType, IntoArray, IntoCanonical};
+use vortex_dtype::DType;
+use vortex_error::VortexResult;
+
+use crate::compute::codes_eq;
+use crate::FSSTArray;
+
+/// Evaluates `LIKE` over the FSST codes where possible.
+///
+/// Patterns without wildcards compare codes directly, and prefix patterns such as `abc%` only
+/// decode as many codes as needed to decide each value. Other patterns decompress each value in
+/// turn, without materializing the whole array.
+impl LikeFn for FSSTArray {
+    fn like(&self, pattern: &str) -> VortexResult<Array> {
+        let tokens = parse_pattern(pattern);
+        let utf8 = matches!(self.dtype(), DType::Utf8(_));
+
+        match tokens.as_slice() {
+            [] => codes_eq(self, &[], false),
+            [Token::Literal(literal)] => {
+                codes_eq(self, &self.compressor().compress(literal), false)
+            }
+            [Token::Literal(prefix), Token::AnyString] => {
+                let symbols = self.symbol_table();
+                map_codes(self, |codes| starts_with(&symbols, codes, prefix))
+            }
+            _ => {
+                let decompressor = self.decompressor();
+                map_codes(self, |codes| {
+                    matches_tokens(&tokens, &decompressor.decompress(codes), utf8)
+                })
+            }
+        }
+    }
+}
+
+fn map_codes(array: &FSSTArray, f: impl Fn(&[u8]) -> bool) -> VortexResult<Array> {
+    let codes = array.codes().into_canonical()?.into_varbin()?;
+    let matches =
+        codes.with_iterator(|iter| iter.map(|codes| codes.map(&f)).collect::<BoolArray>())?;
+    Ok(matches.into_array())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Literal(Vec<u8>),
+    AnyChar,
+    AnyString,
+}
+
+fn parse_pattern(pattern: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut literal = Vec::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        let wildcard = match c {
+            '%' => Token::AnyString,
+            '_' => Token::AnyChar,
+            _ => {
+                // A trailing escape matches itself.
+                let c = if c == '\\' {
+                    chars.next().unwrap_or(c)
+                } else {
+                    c
+                };
+                literal.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                continue;
+            }
+        };
+        if !literal.is_empty() {
+            tokens.push(Token::Literal(std::mem::take(&mut literal)));
+        }
+        // Consecutive `%` are equivalent to a single one.
+        if !(wildcard == Token::AnyString && tokens.last() == Some(&Token::AnyString)) {
+            tokens.push(wildcard);
+        }
+    }
+    if !literal.is_empty() {
+        tokens.push(Token::Literal(literal));
+    }
+    tokens
+}
+
+/// Decode `codes` until the value is known to start, or not start, with `prefix`.
+fn starts_with(symbols: &[Symbol], codes: &[u8], prefix: &[u8]) -> bool {
+    let mut matched = 0;
+    let mut pos = 0;
+    while matched < prefix.len() {
+        let Some(&code) = codes.get(pos) else {
+            return false;
+        };
+        let bytes = if code == ESCAPE_CODE {
+            pos += 2;
+            std::slice::from_ref(&codes[pos - 1])
+        } else {
+            pos += 1;
+            symbols[256 + code as usize].as_slice()
+        };
+
+        let len = bytes.len().min(prefix.len() - matched);
+        if bytes[..len] != prefix[matched..matched + len] {
+            return false;
+        }
+        matched += len;
+    }
+    true
+}
+
+/// Match a decoded value against the pattern tokens, backtracking to the last `%` on mismatch.
+fn matches_tokens(tokens: &[Token], value: &[u8], utf8: bool) -> bool {
+    let char_len = |pos: usize| {
+        if utf8 {
+            value[pos..]
+                .iter()
+                .skip(1)
+                .take_while(|&&b| b & 0b1100_0000 == 0b1000_0000)
+                .count()
+                + 1
+        } else {
+            1
+        }
+    };
+
+    let mut token = 0;
+    let mut pos = 0;
+    // The token after the last `%` and the position in the value it was tried at.
+    let mut backtrack: Option<(usize, usize)> = None;
+    loop {
+        let advanced = match tokens.get(token) {
+            None if pos == value.len() => return true,
+            None => None,
+            Some(Token::AnyString) => {
+                token += 1;
+                backtrack = Some((token, pos));
+                continue;
+            }
+            Some(Token::AnyChar) => (pos < value.len()).then(|| pos + char_len(pos)),
+            Some(Token::Literal(literal)) => value[pos..]
+                .starts_with(literal)
+                .then(|| pos + literal.len()),
+        };
+
+        match (advanced, backtrack) {
+            (Some(next), _) => {
+                token += 1;
+                pos = next;
+            }
+            (None, Some((retry, start))) if start < value.len() => {
+                let start = start + char_len(start);
+                backtrack = Some((retry, start));
+                token = retry;
+                pos = start;
+            }
+            (None, _) => return false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn like(pattern: &str, value: &str) -> bool {
+        matches_tokens(&parse_pattern(pattern), value.as_bytes(), true)
+    }
+
+    #[test]
+    fn test_parse_pattern() {
+        assert_eq!(
+            parse_pattern("a%%b_\\%"),
+            vec![
+                Token::Literal(b"a".to_vec()),
+                Token::AnyString,
+                Token::Literal(b"b".to_vec()),
+                Token::AnyChar,
+                Token::Literal(b"%".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_matches_tokens() {
+        assert!(like("%", ""));
+        assert!(like("abc", "abc"));
+        assert!(!like("abc", "abcd"));
+        assert!(like("a%c", "abbbc"));
+        assert!(like("%b%", "abc"));
+        assert!(!like("%d%", "abc"));
+        assert!(like("a_c", "abc"));
+        assert!(like("a_c", "aéc"));
+        assert!(!like("a_c", "ac"));
+        assert!(like("%ab", "aab"));
+        assert!(like("%a%b%c", "xaxbxc"));
+        assert!(like("100\\%", "100%"));
+        assert!(!like("100\\%", "1000"));
+    }
+}
@@ -3,14 +3,16 @@
 use arrow_array::builder::BinaryBuilder;
 use fsst::{Compressor, Symbol};
 use rstest::{fixture, rstest};
-use vortex::array::{BoolArray, PrimitiveArray};
+use vortex::array::{BoolArray, ConstantArray, PrimitiveArray, VarBinArray};
 use vortex::arrow::FromArrowArray;
 use vortex::compute::unary::scalar_at;
-use vortex::compute::{filter, slice, take};
+use vortex::compute::{compare, filter, like, slice, take, Operator};
+use vortex::stats::{ArrayStatistics, Stat};
 use vortex::validity::Validity;
-use vortex::{Array, ArrayDType, ArrayDef, IntoArray};
+use vortex::{Array, ArrayDType, ArrayDef, IntoArray, IntoArrayVariant};
 use vortex_dtype::{DType, Nullability, PType};
-use vortex_fsst::{FSSTArray, FSST};
+use vortex_fsst::{fsst_compress, fsst_train_compressor, FSSTArray, FSST};
+use vortex_scalar::Scalar;
 
 macro_rules! assert_nth_scalar {
     ($arr:expr, $n:expr, $expected:expr) => {
@@ -141,3 +143,94 @@ fn test_filter(fsst_array: Array) {
         "They said it existed and that whoever dared to exceed it was mercilessly struck down"
     );
 }
+
+#[rstest]
+#[cfg_attr(miri, ignore)]
+fn test_stats(fsst_array: Array) {
+    let stats = fsst_array.statistics();
+    assert_eq!(
+        stats.compute(Stat::Min).unwrap(),
+        Scalar::from("Nothing in present history can contradict them")
+    );
+    assert_eq!(
+        stats.compute(Stat::Max).unwrap(),
+        Scalar::from(
+            "They said it existed and that whoever dared to exceed it was mercilessly struck down"
+        )
+    );
+    assert_eq!(stats.compute_null_count().unwrap(), 0);
+    assert!(!stats.compute_is_sorted().unwrap());
+}
+
+fn to_bools(array: Array) -> Vec<Option<bool>> {
+    let bools = array.into_bool().unwrap();
+    (0..bools.len())
+        .map(|i| {
+            scalar_at(bools.array(), i)
+                .unwrap()
+                .value()
+                .as_bool()
+                .unwrap()
+        })
+        .collect()
+}
+
+#[rstest]
+#[cfg_attr(miri, ignore)]
+fn test_compare_constant(fsst_array: Array) {
+    let constant =
+        ConstantArray::new("Nothing in present history can contradict them", 3).into_array();
+
+    let equal = compare(&fsst_array, &constant, Operator::Eq).unwrap();
+    assert_eq!(to_bools(equal), vec![Some(false), Some(false), Some(true)]);
+
+    let not_equal = compare(&fsst_array, &constant, Operator::NotEq).unwrap();
+    assert_eq!(
+        to_bools(not_equal),
+        vec![Some(true), Some(true), Some(false)]
+    );
+
+    let greater = compare(&fsst_array, &constant, Operator::Gt).unwrap();
+    assert_eq!(to_bools(greater), vec![Some(true), Some(true), Some(false)]);
+}
+
+#[cfg_attr(miri, ignore)]
+#[test]
+fn test_like() {
+    let strings = VarBinArray::from_iter(
+        [
+            Some("https://example.com/items/1"),
+            None,
+            Some("https://example.com/users/2"),
+            Some("http://example.org/items/3"),
+            Some("https://example.com/items/1"),
+        ],
+        DType::Utf8(Nullability::Nullable),
+    )
+    .into_array();
+    let compressor = fsst_train_compressor(&strings).unwrap();
+    let fsst = fsst_compress(&strings, &compressor).unwrap().into_array();
+
+    for (pattern, expected) in [
+        (
+            "https://example.com/items/1",
+            vec![Some(true), None, Some(false), Some(false), Some(true)],
+        ),
+        (
+            "https://%",
+            vec![Some(true), None, Some(true), Some(false), Some(true)],
+        ),
+        (
+            "%/items/_",
+            vec![Some(true), None, Some(false), Some(true), Some(true)],
+        ),
+        (
+            "%.org%",
+            vec![Some(false), None, Some(false), Some(true), Some(false)],
+        ),
+    ] {
+        assert_eq!(to_bools(like(&fsst, pattern).unwrap()), expected);
+        // The kernel over codes agrees with matching the decompressed strings.
+        assert_eq!(to_bools(like(&strings, pattern).unwrap()), expected);
+    }
+}
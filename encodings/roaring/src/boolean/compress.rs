@@ -17,5 +17,5 @@ pub fn roaring_bool_encode(bool_array: BoolArray) -> VortexResult<RoaringBoolArr
     bitmap.run_optimize();
     bitmap.shrink_to_fit();
 
-    RoaringBoolArray::try_new(bitmap, bool_array.len())
+    RoaringBoolArray::try_new_with_validity(bitmap, bool_array.len(), bool_array.validity())
 }
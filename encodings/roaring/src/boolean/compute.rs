@@ -1,13 +1,23 @@
 use croaring::Bitmap;
 use vortex::compute::unary::ScalarAtFn;
-use vortex::compute::{ArrayCompute, SliceFn};
-use vortex::{Array, IntoArray};
+use vortex::compute::{AndFn, ArrayCompute, OrFn, SliceFn};
+use vortex::validity::{ArrayValidity, LogicalValidity, Validity};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::Nullability;
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::RoaringBoolArray;
 
 impl ArrayCompute for RoaringBoolArray {
+    fn and(&self) -> Option<&dyn AndFn> {
+        Some(self)
+    }
+
+    fn or(&self) -> Option<&dyn OrFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -23,7 +33,14 @@ impl ScalarAtFn for RoaringBoolArray {
     }
 
     fn scalar_at_unchecked(&self, index: usize) -> Scalar {
-        self.bitmap().contains(index as u32).into()
+        if self.is_valid(index) {
+            Scalar::bool(
+                self.bitmap().contains(index as u32),
+                self.dtype().nullability(),
+            )
+        } else {
+            Scalar::null(self.dtype().clone())
+        }
     }
 }
 
@@ -32,6 +49,55 @@ impl SliceFn for RoaringBoolArray {
         let slice_bitmap = Bitmap::from_range(start as u32..stop as u32);
         let bitmap = self.bitmap().and(&slice_bitmap).add_offset(-(start as i64));
 
-        Self::try_new(bitmap, stop - start).map(|a| a.into_array())
+        Self::try_new_with_validity(bitmap, stop - start, self.validity().slice(start, stop)?)
+            .map(|a| a.into_array())
+    }
+}
+
+impl AndFn for RoaringBoolArray {
+    fn and(&self, array: &Array) -> VortexResult<Array> {
+        match without_nulls(self, array) {
+            Some((other, nullability)) => {
+                combine(self.bitmap().and(other.bitmap()), self.len(), nullability)
+            }
+            None => AndFn::and(&self.clone().into_bool()?, array),
+        }
     }
 }
+
+impl OrFn for RoaringBoolArray {
+    fn or(&self, array: &Array) -> VortexResult<Array> {
+        match without_nulls(self, array) {
+            Some((other, nullability)) => {
+                combine(self.bitmap().or(other.bitmap()), self.len(), nullability)
+            }
+            None => OrFn::or(&self.clone().into_bool()?, array),
+        }
+    }
+}
+
+/// Returns `array` as a [`RoaringBoolArray`] along with the nullability of the result if neither
+/// side contains nulls, in which case the operation reduces to a set operation on the bitmaps.
+fn without_nulls(lhs: &RoaringBoolArray, array: &Array) -> Option<(RoaringBoolArray, Nullability)> {
+    let rhs = RoaringBoolArray::try_from(array).ok()?;
+    if !matches!(lhs.logical_validity(), LogicalValidity::AllValid(_))
+        || !matches!(rhs.logical_validity(), LogicalValidity::AllValid(_))
+    {
+        return None;
+    }
+
+    let nullability = if lhs.dtype().is_nullable() || rhs.dtype().is_nullable() {
+        Nullability::Nullable
+    } else {
+        Nullability::NonNullable
+    };
+    Some((rhs, nullability))
+}
+
+fn combine(bitmap: Bitmap, length: usize, nullability: Nullability) -> VortexResult<Array> {
+    let validity = match nullability {
+        Nullability::NonNullable => Validity::NonNullable,
+        Nullability::Nullable => Validity::AllValid,
+    };
+    RoaringBoolArray::try_new_with_validity(bitmap, length, validity).map(|a| a.into_array())
+}
@@ -1,59 +1,85 @@
 use std::fmt::Debug;
+use std::sync::OnceLock;
 
 use arrow_buffer::{BooleanBuffer, Buffer as ArrowBuffer};
 pub use compress::*;
 pub use croaring::{Bitmap, Portable};
 use serde::{Deserialize, Serialize};
 use vortex::array::{Bool, BoolArray};
+use vortex::encoding::ids;
 use vortex::stats::{ArrayStatisticsCompute, StatsSet};
-use vortex::validity::{ArrayValidity, LogicalValidity, Validity};
+use vortex::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
 use vortex::variants::{ArrayVariants, BoolArrayTrait};
 use vortex::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use vortex::{
-    impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, Canonical, IntoArray, IntoCanonical,
-    TypedArray,
+    impl_encoding, Array, ArrayDef, ArrayTrait, Canonical, IntoArray, IntoCanonical, TypedArray,
 };
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
-use vortex_dtype::Nullability::{NonNullable, Nullable};
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
 mod compress;
 mod compute;
 
-impl_encoding!("vortex.roaring_bool", 17u16, RoaringBool);
+impl_encoding!("vortex.roaring_bool", ids::ROARING_BOOL, RoaringBool);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoaringBoolMetadata {
     length: usize,
+    validity: ValidityMetadata,
+    /// The bitmap deserialized from the array buffer, populated on first access.
+    #[serde(skip)]
+    bitmap: OnceLock<Bitmap>,
 }
 
 impl RoaringBoolArray {
     pub fn try_new(bitmap: Bitmap, length: usize) -> VortexResult<Self> {
+        Self::try_new_with_validity(bitmap, length, Validity::NonNullable)
+    }
+
+    pub fn try_new_with_validity(
+        bitmap: Bitmap,
+        length: usize,
+        validity: Validity,
+    ) -> VortexResult<Self> {
         if length < bitmap.cardinality() as usize {
             vortex_bail!("RoaringBoolArray length is less than bitmap cardinality")
-        } else {
-            Ok(Self {
-                typed: TypedArray::try_from_parts(
-                    DType::Bool(NonNullable),
-                    length,
-                    RoaringBoolMetadata { length },
-                    Some(Buffer::from(bitmap.serialize::<Portable>())),
-                    vec![].into(),
-                    StatsSet::new(),
-                )?,
-            })
         }
+
+        let buffer = Buffer::from(bitmap.serialize::<Portable>());
+        let metadata = RoaringBoolMetadata {
+            length,
+            validity: validity.to_metadata(length)?,
+            bitmap: OnceLock::from(bitmap),
+        };
+        Ok(Self {
+            typed: TypedArray::try_from_parts(
+                DType::Bool(validity.nullability()),
+                length,
+                metadata,
+                Some(buffer),
+                validity.into_array().into_iter().collect::<Vec<_>>().into(),
+                StatsSet::new(),
+            )?,
+        })
     }
 
-    pub fn bitmap(&self) -> Bitmap {
-        //TODO(@jdcasale): figure out a way to avoid this deserialization per-call
-        Bitmap::deserialize::<Portable>(
-            self.array()
-                .buffer()
-                .expect("RoaringBoolArray buffer is missing")
-                .as_ref(),
-        )
+    /// The bitmap of the array, deserialized from the array buffer on first access.
+    pub fn bitmap(&self) -> &Bitmap {
+        self.metadata().bitmap.get_or_init(|| {
+            Bitmap::deserialize::<Portable>(
+                self.array()
+                    .buffer()
+                    .expect("RoaringBoolArray buffer is missing")
+                    .as_ref(),
+            )
+        })
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata()
+            .validity
+            .to_validity(self.array().child(0, &Validity::DTYPE, self.len()))
     }
 
     pub fn encode(array: Array) -> VortexResult<Array> {
@@ -75,33 +101,42 @@ impl ArrayVariants for RoaringBoolArray {
 
 impl BoolArrayTrait for RoaringBoolArray {
     fn maybe_null_indices_iter<'a>(&'a self) -> Box<dyn Iterator<Item = usize> + 'a> {
-        todo!()
+        Box::new(self.bitmap().iter().map(|i| i as usize))
     }
 
     fn maybe_null_slices_iter<'a>(&'a self) -> Box<dyn Iterator<Item = (usize, usize)> + 'a> {
-        todo!()
+        let mut indices = self.maybe_null_indices_iter().peekable();
+        Box::new(std::iter::from_fn(move || {
+            let start = indices.next()?;
+            let mut end = start + 1;
+            while indices.next_if_eq(&end).is_some() {
+                end += 1;
+            }
+            Some((start, end))
+        }))
     }
 }
 
 impl AcceptArrayVisitor for RoaringBoolArray {
-    fn accept(&self, _visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
-        // TODO(ngates): should we store a buffer in memory? Or delay serialization?
-        //  Or serialize into metadata? The only reason we support buffers is so we can write to
-        //  the wire without copying into FlatBuffers. But if we need to allocate to serialize
-        //  the bitmap anyway, then may as well shove it into metadata.
-        todo!()
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_buffer(
+            self.array()
+                .buffer()
+                .ok_or_else(|| vortex_err!("RoaringBoolArray buffer is missing"))?,
+        )?;
+        visitor.visit_validity(&self.validity())
     }
 }
 
 impl ArrayStatisticsCompute for RoaringBoolArray {}
 
 impl ArrayValidity for RoaringBoolArray {
-    fn logical_validity(&self) -> LogicalValidity {
-        LogicalValidity::AllValid(self.len())
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
     }
 
-    fn is_valid(&self, _index: usize) -> bool {
-        true
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
     }
 }
 
@@ -114,14 +149,13 @@ impl IntoCanonical for RoaringBoolArray {
             .to_bitset()
             .ok_or_else(|| vortex_err!("Failed to convert RoaringBitmap to Bitset"))?;
 
-        let bytes = &bitset.as_slice()[0..bitset.size_in_bytes()];
-        let buffer = ArrowBuffer::from_slice_ref(bytes);
+        // The bitset only extends to the last set bit, so pad it out to the array length.
+        let mut words = bitset.as_slice().to_vec();
+        words.resize(words.len().max((self.len() + 63) / 64), 0);
+        let buffer = ArrowBuffer::from_vec(words);
         Ok(Canonical::Bool(BoolArray::try_new(
-            BooleanBuffer::new(buffer, 0, bitset.size_in_bits()),
-            match self.dtype().nullability() {
-                NonNullable => Validity::NonNullable,
-                Nullable => Validity::AllValid,
-            },
+            BooleanBuffer::new(buffer, 0, self.len()),
+            self.validity(),
         )?))
     }
 }
@@ -131,11 +165,13 @@ impl IntoCanonical for RoaringBoolArray {
 mod test {
     use vortex::array::BoolArray;
     use vortex::compute::unary::scalar_at;
-    use vortex::IntoArray;
+    use vortex::compute::{and, or, slice};
+    use vortex::{ArrayDType, IntoArray, IntoArrayVariant};
+    use vortex_dtype::{DType, Nullability};
     use vortex_error::VortexResult;
     use vortex_scalar::Scalar;
 
-    use crate::RoaringBoolArray;
+    use crate::{Bitmap, RoaringBoolArray};
 
     #[test]
     #[cfg_attr(miri, ignore)]
@@ -165,4 +201,89 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn test_nullable_round_trip() -> VortexResult<()> {
+        let bool = BoolArray::from_iter([Some(true), None, Some(false), Some(true), None]);
+        let array = RoaringBoolArray::encode(bool.into_array())?;
+        assert_eq!(array.dtype(), &DType::Bool(Nullability::Nullable));
+        assert_eq!(
+            array.nbytes(),
+            array.buffer().unwrap().len() + array.children()[0].nbytes()
+        );
+
+        assert_eq!(scalar_at(&array, 1)?, Scalar::null(array.dtype().clone()));
+        assert_eq!(
+            scalar_at(&array, 3)?,
+            Scalar::bool(true, Nullability::Nullable)
+        );
+
+        let canonical = array.clone().into_bool()?;
+        assert_eq!(
+            canonical.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, false, false, true, false]
+        );
+        assert!(!canonical.validity().is_valid(4));
+
+        let sliced = slice(&array, 1, 4)?;
+        assert_eq!(sliced.len(), 3);
+        assert_eq!(scalar_at(&sliced, 0)?, Scalar::null(array.dtype().clone()));
+        assert_eq!(
+            scalar_at(&sliced, 2)?,
+            Scalar::bool(true, Nullability::Nullable)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn test_decoded_once() -> VortexResult<()> {
+        let array =
+            RoaringBoolArray::encode(BoolArray::from(vec![true, false, true, true]).into_array())?;
+        let roaring = || RoaringBoolArray::try_from(&array).unwrap();
+        let bitmap = roaring().bitmap() as *const Bitmap;
+
+        assert_eq!(scalar_at(&array, 0)?, true.into());
+        assert_eq!(scalar_at(&array, 1)?, false.into());
+        and(&array, &array)?;
+        assert!(std::ptr::eq(roaring().bitmap(), bitmap));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn test_and_or() -> VortexResult<()> {
+        let lhs =
+            RoaringBoolArray::encode(BoolArray::from(vec![true, true, false, false]).into_array())?;
+        let rhs =
+            RoaringBoolArray::encode(BoolArray::from(vec![true, false, true, false]).into_array())?;
+
+        let conjunction = RoaringBoolArray::try_from(and(&lhs, &rhs)?)?;
+        assert_eq!(conjunction.bitmap().to_vec(), vec![0]);
+        assert_eq!(conjunction.len(), 4);
+
+        let disjunction = RoaringBoolArray::try_from(or(&lhs, &rhs)?)?;
+        assert_eq!(disjunction.bitmap().to_vec(), vec![0, 1, 2]);
+
+        // Nulls fall back to the canonical boolean kernels.
+        let nullable = RoaringBoolArray::encode(
+            BoolArray::from_iter([Some(true), None, Some(true), Some(false)]).into_array(),
+        )?;
+        let masked = and(&lhs, &nullable)?.into_bool()?;
+        assert_eq!(
+            (0..4)
+                .map(|i| scalar_at(masked.array(), i)
+                    .unwrap()
+                    .value()
+                    .as_bool()
+                    .unwrap())
+                .collect::<Vec<_>>(),
+            vec![Some(true), None, Some(false), Some(false)]
+        );
+
+        Ok(())
+    }
 }
@@ -1,27 +1,41 @@
 use croaring::Bitmap;
 use num_traits::NumCast;
 use vortex::array::PrimitiveArray;
+use vortex::validity::ArrayValidity;
 use vortex_dtype::{NativePType, PType};
-use vortex_error::{vortex_bail, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
 use crate::RoaringIntArray;
 
 pub fn roaring_int_encode(parray: PrimitiveArray) -> VortexResult<RoaringIntArray> {
     match parray.ptype() {
-        PType::U8 => roaring_encode_primitive::<u8>(parray.maybe_null_slice()),
-        PType::U16 => roaring_encode_primitive::<u16>(parray.maybe_null_slice()),
-        PType::U32 => roaring_encode_primitive::<u32>(parray.maybe_null_slice()),
-        PType::U64 => roaring_encode_primitive::<u64>(parray.maybe_null_slice()),
+        PType::U8 => roaring_encode_primitive::<u8>(&parray),
+        PType::U16 => roaring_encode_primitive::<u16>(&parray),
+        PType::U32 => roaring_encode_primitive::<u32>(&parray),
+        PType::U64 => roaring_encode_primitive::<u64>(&parray),
         _ => vortex_bail!("Unsupported ptype {}", parray.ptype()),
     }
 }
 
 fn roaring_encode_primitive<T: NumCast + NativePType>(
-    values: &[T],
+    parray: &PrimitiveArray,
 ) -> VortexResult<RoaringIntArray> {
+    let nulls = parray.logical_validity().to_null_buffer()?;
     let mut bitmap = Bitmap::new();
-    bitmap.extend(values.iter().map(|i| i.to_u32().unwrap()));
+    let mut previous = None;
+    for (i, value) in parray.maybe_null_slice::<T>().iter().enumerate() {
+        if nulls.as_ref().map_or(true, |n| n.is_valid(i)) {
+            let value = value
+                .to_u32()
+                .ok_or_else(|| vortex_err!("RoaringInt value {} exceeds u32", value))?;
+            if previous.map_or(false, |p| p >= value) {
+                vortex_bail!("RoaringInt can only encode strictly sorted values");
+            }
+            bitmap.add(value);
+            previous = Some(value);
+        }
+    }
     bitmap.run_optimize();
     bitmap.shrink_to_fit();
-    RoaringIntArray::try_new(bitmap, T::PTYPE)
+    RoaringIntArray::try_new_with_validity(bitmap, T::PTYPE, parray.len(), parray.validity())
 }
@@ -1,5 +1,8 @@
+use croaring::Bitmap;
 use vortex::compute::unary::ScalarAtFn;
-use vortex::compute::ArrayCompute;
+use vortex::compute::{ArrayCompute, SliceFn};
+use vortex::validity::ArrayValidity;
+use vortex::{Array, ArrayDType, IntoArray};
 use vortex_dtype::PType;
 use vortex_error::{vortex_err, VortexResult};
 use vortex_scalar::Scalar;
@@ -10,19 +13,28 @@ impl ArrayCompute for RoaringIntArray {
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
 }
 
 impl ScalarAtFn for RoaringIntArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
+
         let bitmap_value = self
             .bitmap()
-            .select(index as u32)
+            .select(self.valid_count_before(index)? as u32)
             .ok_or_else(|| vortex_err!(OutOfBounds: index, 0, self.len()))?;
-        let scalar: Scalar = match self.metadata().ptype {
-            PType::U8 => (bitmap_value as u8).into(),
-            PType::U16 => (bitmap_value as u16).into(),
-            PType::U32 => bitmap_value.into(),
-            PType::U64 => (bitmap_value as u64).into(),
+        let nullability = self.dtype().nullability();
+        let scalar = match self.metadata().ptype {
+            PType::U8 => Scalar::primitive(bitmap_value as u8, nullability),
+            PType::U16 => Scalar::primitive(bitmap_value as u16, nullability),
+            PType::U32 => Scalar::primitive(bitmap_value, nullability),
+            PType::U64 => Scalar::primitive(bitmap_value as u64, nullability),
             _ => unreachable!("RoaringIntArray constructor should have disallowed this type"),
         };
         Ok(scalar)
@@ -32,3 +44,29 @@ impl ScalarAtFn for RoaringIntArray {
         <Self as ScalarAtFn>::scalar_at(self, index).unwrap()
     }
 }
+
+impl SliceFn for RoaringIntArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        // The valid values of the slice are a contiguous run of elements of the sorted bitmap.
+        let first = self.valid_count_before(start)?;
+        let end = self.valid_count_before(stop)?;
+        let bitmap = if first < end {
+            let bitmap = self.bitmap();
+            let range = bitmap
+                .select(first as u32)
+                .zip(bitmap.select(end as u32 - 1))
+                .ok_or_else(|| vortex_err!(OutOfBounds: stop, 0, self.len()))?;
+            bitmap.and(&Bitmap::from_range(range.0..=range.1))
+        } else {
+            Bitmap::new()
+        };
+
+        Self::try_new_with_validity(
+            bitmap,
+            self.ptype(),
+            stop - start,
+            self.validity().slice(start, stop)?,
+        )
+        .map(|a| a.into_array())
+    }
+}
@@ -1,63 +1,142 @@
 use std::fmt::Debug;
+use std::sync::OnceLock;
 
 pub use compress::*;
 use croaring::{Bitmap, Portable};
+use num_traits::NumCast;
 use serde::{Deserialize, Serialize};
 use vortex::array::{Primitive, PrimitiveArray};
+use vortex::encoding::ids;
 use vortex::stats::{ArrayStatisticsCompute, StatsSet};
-use vortex::validity::{ArrayValidity, LogicalValidity};
+use vortex::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
 use vortex::variants::{ArrayVariants, PrimitiveArrayTrait};
 use vortex::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use vortex::{
-    impl_encoding, Array, ArrayDef, ArrayTrait, Canonical, IntoArray, IntoCanonical, TypedArray,
+    impl_encoding, Array, ArrayDef, ArrayTrait, Canonical, IntoArray, IntoArrayVariant,
+    IntoCanonical, TypedArray,
 };
 use vortex_buffer::Buffer;
-use vortex_dtype::Nullability::NonNullable;
-use vortex_dtype::{DType, PType};
+use vortex_dtype::{DType, NativePType, PType};
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
 mod compress;
 mod compute;
 
-impl_encoding!("vortex.roaring_int", 18u16, RoaringInt);
+impl_encoding!("vortex.roaring_int", ids::ROARING_INT, RoaringInt);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoaringIntMetadata {
     ptype: PType,
+    validity: ValidityMetadata,
+    /// The bitmap deserialized from the array buffer, populated on first access.
+    #[serde(skip)]
+    bitmap: OnceLock<Bitmap>,
+    /// The positions of the valid values, populated on first access to a nullable array.
+    #[serde(skip)]
+    valid_positions: OnceLock<Bitmap>,
 }
 
 impl RoaringIntArray {
     pub fn try_new(bitmap: Bitmap, ptype: PType) -> VortexResult<Self> {
+        let length = bitmap.cardinality() as usize;
+        Self::try_new_with_validity(bitmap, ptype, length, Validity::NonNullable)
+    }
+
+    /// Build a RoaringIntArray of `length` values, where the valid values are the sorted elements
+    /// of `bitmap`.
+    pub fn try_new_with_validity(
+        bitmap: Bitmap,
+        ptype: PType,
+        length: usize,
+        validity: Validity,
+    ) -> VortexResult<Self> {
         if !ptype.is_unsigned_int() {
             vortex_bail!("RoaringInt expected unsigned int");
         }
-        let length = bitmap.statistics().cardinality as usize;
+
+        let valid_count = match validity.to_logical(length) {
+            LogicalValidity::AllValid(len) => len,
+            LogicalValidity::AllInvalid(_) => 0,
+            LogicalValidity::Array(a) => a.into_bool()?.boolean_buffer().count_set_bits(),
+        };
+        if valid_count != bitmap.cardinality() as usize {
+            vortex_bail!(
+                "RoaringIntArray has {} valid values but bitmap cardinality {}",
+                valid_count,
+                bitmap.cardinality()
+            );
+        }
+
+        let buffer = Buffer::from(bitmap.serialize::<Portable>());
+        let metadata = RoaringIntMetadata {
+            ptype,
+            validity: validity.to_metadata(length)?,
+            bitmap: OnceLock::from(bitmap),
+            valid_positions: OnceLock::new(),
+        };
         Ok(Self {
             typed: TypedArray::try_from_parts(
-                DType::Primitive(ptype, NonNullable),
+                DType::Primitive(ptype, validity.nullability()),
                 length,
-                RoaringIntMetadata { ptype },
-                Some(Buffer::from(bitmap.serialize::<Portable>())),
-                vec![].into(),
+                metadata,
+                Some(buffer),
+                validity.into_array().into_iter().collect::<Vec<_>>().into(),
                 StatsSet::new(),
             )?,
         })
     }
 
-    pub fn bitmap(&self) -> Bitmap {
-        //TODO(@jdcasale): figure out a way to avoid this deserialization per-call
-        Bitmap::deserialize::<Portable>(
-            self.array()
-                .buffer()
-                .expect("RoaringBoolArray buffer is missing")
-                .as_ref(),
-        )
+    /// The bitmap of the array, deserialized from the array buffer on first access.
+    pub fn bitmap(&self) -> &Bitmap {
+        self.metadata().bitmap.get_or_init(|| {
+            Bitmap::deserialize::<Portable>(
+                self.array()
+                    .buffer()
+                    .expect("RoaringIntArray buffer is missing")
+                    .as_ref(),
+            )
+        })
     }
 
     pub fn ptype(&self) -> PType {
         self.metadata().ptype
     }
 
+    pub fn validity(&self) -> Validity {
+        self.metadata()
+            .validity
+            .to_validity(self.array().child(0, &Validity::DTYPE, self.len()))
+    }
+
+    /// The number of valid values before `index`, which is the position in the bitmap of the
+    /// value at `index`.
+    pub(crate) fn valid_count_before(&self, index: usize) -> VortexResult<usize> {
+        let validity = match self.validity() {
+            Validity::NonNullable | Validity::AllValid => return Ok(index),
+            Validity::AllInvalid => return Ok(0),
+            Validity::Array(validity) => validity,
+        };
+        if index == 0 {
+            return Ok(0);
+        }
+
+        let valid_positions = match self.metadata().valid_positions.get() {
+            Some(valid_positions) => valid_positions,
+            None => {
+                let valid_positions = validity
+                    .into_bool()?
+                    .boolean_buffer()
+                    .set_indices()
+                    .map(|i| i as u32)
+                    .collect::<Bitmap>();
+                self.metadata()
+                    .valid_positions
+                    .get_or_init(|| valid_positions)
+            }
+        };
+        Ok(valid_positions.rank(index as u32 - 1) as usize)
+    }
+
     pub fn encode(array: Array) -> VortexResult<Array> {
         if array.encoding().id() == Primitive::ID {
             Ok(roaring_int_encode(PrimitiveArray::try_from(array)?)?.into_array())
@@ -78,24 +157,70 @@ impl ArrayVariants for RoaringIntArray {
 impl PrimitiveArrayTrait for RoaringIntArray {}
 
 impl ArrayValidity for RoaringIntArray {
-    fn is_valid(&self, _index: usize) -> bool {
-        true
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
     }
 
     fn logical_validity(&self) -> LogicalValidity {
-        LogicalValidity::AllValid(self.bitmap().iter().count())
+        self.validity().to_logical(self.len())
     }
 }
 
 impl IntoCanonical for RoaringIntArray {
     fn into_canonical(self) -> VortexResult<Canonical> {
-        todo!()
+        let validity = self.validity();
+        let valid = match validity.to_logical(self.len()) {
+            LogicalValidity::Array(a) => Some(a.into_bool()?.boolean_buffer()),
+            LogicalValidity::AllValid(_) | LogicalValidity::AllInvalid(_) => None,
+        };
+
+        let bitmap = self.bitmap();
+        let primitive = match self.ptype() {
+            PType::U8 => decode::<u8>(bitmap, self.len(), valid.as_ref(), validity),
+            PType::U16 => decode::<u16>(bitmap, self.len(), valid.as_ref(), validity),
+            PType::U32 => decode::<u32>(bitmap, self.len(), valid.as_ref(), validity),
+            PType::U64 => decode::<u64>(bitmap, self.len(), valid.as_ref(), validity),
+            _ => unreachable!("RoaringIntArray constructor should have disallowed this type"),
+        };
+        Ok(Canonical::Primitive(primitive))
     }
 }
 
+/// Decode the bitmap into `len` values, placing them at the valid positions in order.
+fn decode<T: NativePType + NumCast>(
+    bitmap: &Bitmap,
+    len: usize,
+    valid: Option<&arrow_buffer::BooleanBuffer>,
+    validity: Validity,
+) -> PrimitiveArray {
+    let mut values = bitmap.iter().map(|v| T::from(v).unwrap_or_default());
+    let values = match valid {
+        None => {
+            let mut values = values.collect::<Vec<T>>();
+            values.resize(len, T::default());
+            values
+        }
+        Some(valid) => valid
+            .iter()
+            .map(|is_valid| {
+                is_valid
+                    .then(|| values.next())
+                    .flatten()
+                    .unwrap_or_default()
+            })
+            .collect(),
+    };
+    PrimitiveArray::from_vec(values, validity)
+}
+
 impl AcceptArrayVisitor for RoaringIntArray {
-    fn accept(&self, _visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
-        todo!()
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_buffer(
+            self.array()
+                .buffer()
+                .ok_or_else(|| vortex_err!("RoaringIntArray buffer is missing"))?,
+        )?;
+        visitor.visit_validity(&self.validity())
     }
 }
 
@@ -103,9 +228,12 @@ impl ArrayStatisticsCompute for RoaringIntArray {}
 
 #[cfg(test)]
 mod test {
+    use croaring::Bitmap;
     use vortex::array::PrimitiveArray;
+    use vortex::compute::slice;
     use vortex::compute::unary::scalar_at;
-    use vortex::IntoArray;
+    use vortex::{ArrayDType, IntoArray, IntoArrayVariant};
+    use vortex_scalar::Scalar;
 
     use crate::RoaringIntArray;
 
@@ -118,4 +246,65 @@ mod test {
         assert_eq!(scalar_at(&array, 0).unwrap(), 2u32.into());
         assert_eq!(scalar_at(&array, 1).unwrap(), 12u32.into());
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn test_canonical() {
+        let ints = PrimitiveArray::from(vec![2u16, 12, 22, 32]).into_array();
+        let array = RoaringIntArray::encode(ints).unwrap();
+
+        let canonical = array.into_primitive().unwrap();
+        assert_eq!(canonical.maybe_null_slice::<u16>(), &[2, 12, 22, 32]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn test_nullable() {
+        let ints =
+            PrimitiveArray::from_nullable_vec(vec![Some(2u32), None, Some(12), None, Some(22)])
+                .into_array();
+        let array = RoaringIntArray::encode(ints).unwrap();
+        assert!(array.dtype().is_nullable());
+
+        assert_eq!(
+            scalar_at(&array, 1).unwrap(),
+            Scalar::null(array.dtype().clone())
+        );
+        assert_eq!(scalar_at(&array, 2).unwrap(), Scalar::from(Some(12u32)));
+
+        let sliced = slice(&array, 2, 5).unwrap();
+        assert_eq!(scalar_at(&sliced, 0).unwrap(), Scalar::from(Some(12u32)));
+        assert_eq!(scalar_at(&sliced, 2).unwrap(), Scalar::from(Some(22u32)));
+
+        let canonical = array.into_primitive().unwrap();
+        assert_eq!(canonical.maybe_null_slice::<u32>(), &[2, 0, 12, 0, 22]);
+        assert!(!canonical.validity().is_valid(3));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn test_decoded_once() {
+        let ints =
+            PrimitiveArray::from_nullable_vec(vec![Some(2u32), None, Some(12), None, Some(22)])
+                .into_array();
+        let array = RoaringIntArray::encode(ints).unwrap();
+        let roaring = || RoaringIntArray::try_from(&array).unwrap();
+        let bitmap = roaring().bitmap() as *const Bitmap;
+
+        assert_eq!(scalar_at(&array, 2).unwrap(), Scalar::from(Some(12u32)));
+        let valid_positions = roaring().metadata().valid_positions.get().unwrap() as *const Bitmap;
+        assert_eq!(scalar_at(&array, 4).unwrap(), Scalar::from(Some(22u32)));
+
+        assert!(std::ptr::eq(roaring().bitmap(), bitmap));
+        assert!(std::ptr::eq(
+            roaring().metadata().valid_positions.get().unwrap(),
+            valid_positions
+        ));
+    }
+
+    #[test]
+    pub fn test_unsorted() {
+        let ints = PrimitiveArray::from(vec![3u32, 1, 2]).into_array();
+        assert!(RoaringIntArray::encode(ints).is_err());
+    }
 }
@@ -1,6 +1,9 @@
-use vortex::array::PrimitiveArray;
+use std::cmp::min;
+
+use vortex::array::{BoolArray, PrimitiveArray};
 use vortex::compute::unary::{scalar_at, scalar_at_unchecked, ScalarAtFn};
-use vortex::compute::{slice, take, ArrayCompute, SliceFn, TakeFn};
+use vortex::compute::{filter, slice, take, ArrayCompute, FilterFn, SliceFn, TakeFn};
+use vortex::validity::filter_validity;
 use vortex::{Array, IntoArray, IntoArrayVariant};
 use vortex_dtype::match_each_integer_ptype;
 use vortex_error::{vortex_bail, VortexResult};
@@ -9,6 +12,10 @@ use vortex_scalar::Scalar;
 use crate::RunEndArray;
 
 impl ArrayCompute for RunEndArray {
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -58,6 +65,40 @@ impl TakeFn for RunEndArray {
     }
 }
 
+impl FilterFn for RunEndArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        let validity = filter_validity(self.validity(), predicate)?;
+        let predicate = predicate.clone().into_bool()?.boolean_buffer();
+        let ends = self.ends().into_primitive()?;
+
+        // Keep the runs with at least one selected value, shortened to the selected values.
+        let mut filtered_ends = Vec::new();
+        let mut keep_runs = Vec::with_capacity(ends.len());
+        let mut start = 0;
+        match_each_integer_ptype!(ends.ptype(), |$E| {
+            for &end in ends.maybe_null_slice::<$E>() {
+                let end = min(end as usize - self.offset(), self.len());
+                let selected = predicate.slice(start, end - start).count_set_bits();
+                keep_runs.push(selected > 0);
+                if selected > 0 {
+                    filtered_ends.push(filtered_ends.last().copied().unwrap_or(0) + selected as u64);
+                }
+                start = end;
+            }
+        });
+
+        let length = filtered_ends.last().copied().unwrap_or(0) as usize;
+        Ok(Self::with_offset_and_size(
+            PrimitiveArray::from(filtered_ends).into_array(),
+            filter(&self.values(), &BoolArray::from(keep_runs).into_array())?,
+            validity,
+            length,
+            0,
+        )?
+        .into_array())
+    }
+}
+
 impl SliceFn for RunEndArray {
     fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
         let slice_begin = self.find_physical_index(start)?;
@@ -66,7 +107,7 @@ impl SliceFn for RunEndArray {
         Ok(Self::with_offset_and_size(
             slice(&self.ends(), slice_begin, slice_end + 1)?,
             slice(&self.values(), slice_begin, slice_end + 1)?,
-            self.validity().slice(slice_begin, slice_end + 1)?,
+            self.validity().slice(start, stop)?,
             stop - start,
             start,
         )?
@@ -76,14 +117,14 @@ impl SliceFn for RunEndArray {
 
 #[cfg(test)]
 mod test {
-    use vortex::array::PrimitiveArray;
-    use vortex::compute::take;
+    use vortex::array::{BoolArray, PrimitiveArray};
     use vortex::compute::unary::{scalar_at, try_cast};
+    use vortex::compute::{filter, slice, take};
     use vortex::validity::Validity;
-    use vortex::{ArrayDType, IntoArrayVariant, ToArray};
+    use vortex::{ArrayDType, ArrayDef, IntoArrayVariant, ToArray};
     use vortex_scalar::Scalar;
 
-    use crate::RunEndArray;
+    use crate::{RunEnd, RunEndArray};
 
     fn ree_array() -> RunEndArray {
         RunEndArray::encode(
@@ -138,4 +179,42 @@ mod test {
         let scalar = scalar_at(null_ree.array(), 11).unwrap();
         assert_eq!(scalar, Scalar::null(null_ree.dtype().clone()));
     }
+
+    #[test]
+    fn ree_filter() {
+        // Filter a slice so that the first run starts before the array.
+        let sliced = slice(ree_array().array(), 2, 11).unwrap();
+        let filtered = filter(
+            &sliced,
+            BoolArray::from(vec![
+                true, false, false, false, true, true, false, true, false,
+            ])
+            .array(),
+        )
+        .unwrap();
+        assert_eq!(filtered.encoding().id(), RunEnd::ID);
+        assert_eq!(
+            filtered.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[1, 2, 2, 5]
+        );
+    }
+
+    #[test]
+    fn ree_filter_nullable() {
+        let array = RunEndArray::encode(
+            PrimitiveArray::from_nullable_vec(vec![Some(1), Some(1), None, Some(3), Some(3)])
+                .to_array(),
+        )
+        .unwrap();
+        let filtered = filter(
+            array.array(),
+            BoolArray::from(vec![false, true, true, false, true]).array(),
+        )
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+        assert_eq!(filtered.maybe_null_slice::<i32>()[0], 1);
+        assert!(!filtered.validity().is_valid(1));
+        assert_eq!(filtered.maybe_null_slice::<i32>()[2], 3);
+    }
 }
@@ -1,14 +1,25 @@
+use vortex::array::ConstantArray;
 use vortex::compute::unary::{scalar_at_unchecked, ScalarAtFn};
-use vortex::compute::{slice, ArrayCompute, SliceFn};
-use vortex::{Array, IntoArray};
+use vortex::compute::{
+    compare, filter, slice, ArrayCompute, CompareFn, FilterFn, Operator, SliceFn,
+};
+use vortex::{Array, ArrayDType, IntoArray, IntoCanonical};
 use vortex_dtype::PType;
-use vortex_error::{vortex_err, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 use vortex_scalar::{PrimitiveScalar, Scalar};
 use zigzag::ZigZag as ExternalZigZag;
 
 use crate::ZigZagArray;
 
 impl ArrayCompute for ZigZagArray {
+    fn compare(&self) -> Option<&dyn CompareFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -55,7 +66,7 @@ impl ScalarAtFn for ZigZagArray {
                 )
             })?)
             .into()),
-            _ => unreachable!(),
+            ptype => vortex_bail!(MismatchedTypes: "unsigned int", ptype),
         }
     }
 
@@ -64,8 +75,90 @@ impl ScalarAtFn for ZigZagArray {
     }
 }
 
+impl CompareFn for ZigZagArray {
+    fn compare(&self, other: &Array, operator: Operator) -> VortexResult<Array> {
+        // ZigZag encoding does not preserve ordering, so only equality can be checked on the
+        // encoded values.
+        if let (Ok(constant), Operator::Eq | Operator::NotEq) =
+            (ConstantArray::try_from(other), operator)
+        {
+            if !constant.scalar().is_null() {
+                let value = constant.scalar().cast(self.dtype())?;
+                let nullability = self.dtype().nullability();
+                let encoded = match PType::try_from(self.dtype())? {
+                    PType::I8 => Scalar::primitive(i8::encode(i8::try_from(&value)?), nullability),
+                    PType::I16 => {
+                        Scalar::primitive(i16::encode(i16::try_from(&value)?), nullability)
+                    }
+                    PType::I32 => {
+                        Scalar::primitive(i32::encode(i32::try_from(&value)?), nullability)
+                    }
+                    PType::I64 => {
+                        Scalar::primitive(i64::encode(i64::try_from(&value)?), nullability)
+                    }
+                    ptype => vortex_bail!(MismatchedTypes: "signed int", ptype),
+                };
+                return compare(
+                    &self.encoded(),
+                    ConstantArray::new(encoded, self.len()).array(),
+                    operator,
+                );
+            }
+        }
+
+        let decoded = Array::from(self.clone().into_canonical()?);
+        compare(&decoded, other, operator)
+    }
+}
+
+impl FilterFn for ZigZagArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        Ok(Self::try_new(filter(&self.encoded(), predicate)?)?.into_array())
+    }
+}
+
 impl SliceFn for ZigZagArray {
     fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
         Ok(Self::try_new(slice(&self.encoded(), start, stop)?)?.into_array())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex::array::{ConstantArray, PrimitiveArray};
+    use vortex::compute::{compare, Operator};
+    use vortex::IntoArrayVariant;
+
+    use crate::zigzag_encode;
+
+    #[test]
+    fn compare_constant() {
+        let zigzag = zigzag_encode(&PrimitiveArray::from(vec![-3i32, 2, -1, 2, 5])).unwrap();
+        let compare_to = |value: i32, operator: Operator| {
+            compare(
+                zigzag.array(),
+                ConstantArray::new(value, zigzag.len()).array(),
+                operator,
+            )
+            .unwrap()
+            .into_bool()
+            .unwrap()
+            .boolean_buffer()
+            .iter()
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            compare_to(2, Operator::Eq),
+            vec![false, true, false, true, false]
+        );
+        assert_eq!(
+            compare_to(-1, Operator::NotEq),
+            vec![true, true, false, true, true]
+        );
+        assert_eq!(
+            compare_to(0, Operator::Lt),
+            vec![true, false, true, false, false]
+        );
+    }
+}
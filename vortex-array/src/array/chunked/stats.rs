@@ -2,9 +2,20 @@ use vortex_error::VortexResult;
 
 use crate::array::chunked::ChunkedArray;
 use crate::stats::{ArrayStatistics, ArrayStatisticsCompute, Stat, StatsSet};
+use crate::{Array, IntoArray, IntoCanonical};
 
 impl ArrayStatisticsCompute for ChunkedArray {
     fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        if stat == Stat::Histogram {
+            // Histograms of the chunks cannot be merged, see `StatsSet::merge`.
+            let canonical = Array::from(self.clone().into_array().into_canonical()?);
+            return Ok(canonical
+                .statistics()
+                .compute(stat)
+                .map(|histogram| StatsSet::of(stat, histogram))
+                .unwrap_or_default());
+        }
+
         Ok(self
             .chunks()
             .map(|c| {
@@ -20,3 +31,33 @@ impl ArrayStatisticsCompute for ChunkedArray {
             .unwrap_or_default())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::ListScalar;
+
+    use crate::array::{ChunkedArray, PrimitiveArray};
+    use crate::stats::{ArrayStatistics, Stat, HISTOGRAM_BUCKETS};
+    use crate::IntoArray;
+
+    #[test]
+    fn distribution_stats() {
+        let chunked = ChunkedArray::try_new(
+            vec![
+                PrimitiveArray::from((0..1000u32).collect::<Vec<_>>()).into_array(),
+                PrimitiveArray::from((500..3000u32).collect::<Vec<_>>()).into_array(),
+            ],
+            DType::Primitive(PType::U32, Nullability::NonNullable),
+        )
+        .unwrap();
+
+        let distinct = chunked.statistics().compute_distinct_count().unwrap();
+        assert!((2800..=3200).contains(&distinct), "{distinct}");
+
+        let histogram = chunked.statistics().compute(Stat::Histogram).unwrap();
+        let bounds = ListScalar::try_from(&histogram).unwrap();
+        assert_eq!(bounds.element(0).unwrap(), 0u32.into());
+        assert_eq!(bounds.element(HISTOGRAM_BUCKETS).unwrap(), 2999u32.into());
+    }
+}
@@ -1,8 +1,12 @@
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
-use vortex_dtype::{DType, ExtDType, ExtID};
+use vortex_dtype::{DType, ExtDType, ExtID, Nullability};
 use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
 
-use crate::stats::ArrayStatisticsCompute;
+use crate::encoding::ids;
+use crate::stats::{ArrayStatistics, ArrayStatisticsCompute, Stat, StatsSet};
 use crate::validity::{ArrayValidity, LogicalValidity};
 use crate::variants::{ArrayVariants, ExtensionArrayTrait};
 use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
@@ -10,7 +14,7 @@ use crate::{impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, Canonical, I
 
 mod compute;
 
-impl_encoding!("vortex.ext", 16u16, Extension);
+impl_encoding!("vortex.ext", ids::EXTENSION, Extension);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExtensionMetadata {
@@ -85,5 +89,62 @@ impl AcceptArrayVisitor for ExtensionArray {
 }
 
 impl ArrayStatisticsCompute for ExtensionArray {
-    // TODO(ngates): pass through stats to the underlying and cast the scalars.
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        let storage = self.storage();
+        storage.statistics().compute(stat);
+
+        let mut stats = StatsSet::new();
+        for (stat, value) in storage.statistics().to_set() {
+            let value = match stat {
+                Stat::Min | Stat::Max => Scalar::extension(self.ext_dtype().clone(), value),
+                Stat::Histogram => Scalar::new(
+                    DType::List(
+                        Arc::new(self.dtype().as_nonnullable()),
+                        Nullability::NonNullable,
+                    ),
+                    value.into_value(),
+                ),
+                _ => value,
+            };
+            stats.set(stat, value);
+        }
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{ExtDType, ExtID, Nullability};
+    use vortex_scalar::{ListScalar, Scalar};
+
+    use crate::array::{ExtensionArray, PrimitiveArray};
+    use crate::stats::{ArrayStatistics, Stat};
+    use crate::{ArrayDType, IntoArray};
+
+    #[test]
+    fn storage_stats() {
+        let ext_dtype = ExtDType::new(ExtID::from("test.ext"), None);
+        let array = ExtensionArray::new(
+            ext_dtype.clone(),
+            PrimitiveArray::from(vec![3i64, 1, 2]).into_array(),
+        );
+
+        assert_eq!(
+            array.statistics().compute(Stat::Min),
+            Some(Scalar::extension(ext_dtype.clone(), 1i64.into()))
+        );
+        assert_eq!(
+            array.statistics().compute(Stat::Max),
+            Some(Scalar::extension(ext_dtype, 3i64.into()))
+        );
+        assert_eq!(array.statistics().compute_null_count(), Some(0));
+        assert_eq!(array.statistics().compute_is_sorted(), Some(false));
+
+        let histogram = array.statistics().compute(Stat::Histogram).unwrap();
+        let bounds = ListScalar::try_from(&histogram).unwrap();
+        assert_eq!(
+            bounds.element_dtype(),
+            array.dtype().with_nullability(Nullability::NonNullable)
+        );
+    }
 }
@@ -7,6 +7,7 @@ use vortex_buffer::Buffer;
 use vortex_dtype::{match_each_native_ptype, DType, NativePType, PType};
 use vortex_error::{vortex_bail, VortexResult};
 
+use crate::encoding::ids;
 use crate::stats::StatsSet;
 use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
 use crate::variants::{ArrayVariants, PrimitiveArrayTrait};
@@ -20,7 +21,7 @@ mod accessor;
 mod compute;
 mod stats;
 
-impl_encoding!("vortex.primitive", 3u16, Primitive);
+impl_encoding!("vortex.primitive", ids::PRIMITIVE, Primitive);
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PrimitiveMetadata {
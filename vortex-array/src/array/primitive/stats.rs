@@ -1,15 +1,16 @@
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::mem::size_of;
 
 use arrow_buffer::buffer::BooleanBuffer;
 use num_traits::PrimInt;
 use vortex_dtype::half::f16;
-use vortex_dtype::{match_each_native_ptype, NativePType};
+use vortex_dtype::{match_each_native_ptype, DType, NativePType, Nullability};
 use vortex_error::{vortex_err, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::array::primitive::PrimitiveArray;
-use crate::stats::{ArrayStatisticsCompute, Stat, StatsSet};
+use crate::stats::{equi_depth_bounds, ArrayStatisticsCompute, HyperLogLog, Stat, StatsSet};
 use crate::validity::{ArrayValidity, LogicalValidity};
 use crate::{ArrayDType, IntoArrayVariant};
 
@@ -19,6 +20,10 @@ impl<T: NativePType + Into<Scalar> + BitWidth> PStatsType for T {}
 
 impl ArrayStatisticsCompute for PrimitiveArray {
     fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        if matches!(stat, Stat::DistinctCount | Stat::Histogram) {
+            return self.compute_distribution(stat);
+        }
+
         match_each_native_ptype!(self.ptype(), |$P| {
             match self.logical_validity() {
                 LogicalValidity::AllValid(_) => self.maybe_null_slice::<$P>().compute_statistics(stat),
@@ -33,6 +38,49 @@ impl ArrayStatisticsCompute for PrimitiveArray {
     }
 }
 
+impl PrimitiveArray {
+    /// Compute a stat describing the distribution of the non-null values. These are too expensive
+    /// to compute along with the other stats.
+    fn compute_distribution(&self, stat: Stat) -> VortexResult<StatsSet> {
+        let validity = match self.logical_validity() {
+            LogicalValidity::AllValid(_) => None,
+            LogicalValidity::AllInvalid(len) => Some(BooleanBuffer::new_unset(len)),
+            LogicalValidity::Array(a) => Some(a.into_bool()?.boolean_buffer()),
+        };
+
+        match_each_native_ptype!(self.ptype(), |$P| {
+            let values = self
+                .maybe_null_slice::<$P>()
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| validity.as_ref().map_or(true, |v| v.value(*i)))
+                .map(|(_, v)| *v);
+            Ok(match stat {
+                Stat::DistinctCount => {
+                    let mut sketch = HyperLogLog::new();
+                    values.for_each(|v| sketch.insert(&v.to_le_bytes()));
+                    StatsSet::of(stat, sketch.into())
+                }
+                _ => {
+                    let mut sorted = values.filter(|v| !v.is_nan()).collect::<Vec<_>>();
+                    if sorted.is_empty() {
+                        return Ok(StatsSet::new());
+                    }
+                    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+                    let bounds = equi_depth_bounds(&sorted)
+                        .into_iter()
+                        .map(|v| Scalar::from(v).into_value())
+                        .collect();
+                    StatsSet::of(
+                        stat,
+                        Scalar::list(DType::Primitive(self.ptype(), Nullability::NonNullable), bounds),
+                    )
+                }
+            })
+        })
+    }
+}
+
 impl<T: PStatsType> ArrayStatisticsCompute for &[T] {
     fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
         if self.is_empty() {
@@ -208,7 +256,7 @@ impl<T: PStatsType> StatsAccumulator<T> {
             || self.null_count == self.len
             || self.nan_count == self.len;
 
-        StatsSet::from(HashMap::from([
+        let mut stats = HashMap::from([
             (Stat::Min, self.min.into()),
             (Stat::Max, self.max.into()),
             (Stat::NullCount, self.null_count.into()),
@@ -221,17 +269,21 @@ impl<T: PStatsType> StatsAccumulator<T> {
                 (self.is_sorted && self.is_strict_sorted).into(),
             ),
             (Stat::RunCount, self.run_count.into()),
-        ]))
+        ]);
+        if T::PTYPE.is_float() {
+            stats.insert(Stat::NanCount, self.nan_count.into());
+        }
+        StatsSet::from(stats)
     }
 }
 
 #[cfg(test)]
 mod test {
     use vortex_dtype::{DType, Nullability, PType};
-    use vortex_scalar::Scalar;
+    use vortex_scalar::{ListScalar, Scalar};
 
     use crate::array::primitive::PrimitiveArray;
-    use crate::stats::{ArrayStatistics, Stat};
+    use crate::stats::{ArrayStatistics, Stat, HISTOGRAM_BUCKETS};
 
     #[test]
     fn stats() {
@@ -290,6 +342,19 @@ mod test {
         assert!(is_strict_sorted);
     }
 
+    #[test]
+    fn nan_count() {
+        let arr = PrimitiveArray::from(vec![1.0f64, f64::NAN, 3.0, f64::NAN]);
+        assert_eq!(
+            arr.statistics().compute_as::<usize>(Stat::NanCount),
+            Some(2)
+        );
+        assert_eq!(arr.statistics().compute_max::<f64>(), Some(3.0));
+
+        let ints = PrimitiveArray::from(vec![1i32, 2]);
+        assert_eq!(ints.statistics().compute_as::<usize>(Stat::NanCount), None);
+    }
+
     #[test]
     fn all_null() {
         let arr = PrimitiveArray::from_nullable_vec(vec![Option::<i32>::None, None, None]);
@@ -299,4 +364,30 @@ mod test {
         assert_eq!(min, Some(null_i32.clone()));
         assert_eq!(max, Some(null_i32));
     }
+
+    #[test]
+    fn distribution_stats() {
+        let arr = PrimitiveArray::from_nullable_vec(
+            (0..1000i64).map(|i| (i % 3 != 0).then_some(i)).collect(),
+        );
+        let distinct = arr.statistics().compute_distinct_count().unwrap();
+        assert!((630..=700).contains(&distinct), "{distinct}");
+
+        let histogram = arr.statistics().compute(Stat::Histogram).unwrap();
+        let bounds = ListScalar::try_from(&histogram).unwrap();
+        assert_eq!(bounds.len(), HISTOGRAM_BUCKETS + 1);
+        assert_eq!(bounds.element(0).unwrap(), 1i64.into());
+        assert_eq!(bounds.element(HISTOGRAM_BUCKETS).unwrap(), 998i64.into());
+        assert_eq!(
+            bounds.element(HISTOGRAM_BUCKETS / 2).unwrap(),
+            499i64.into()
+        );
+    }
+
+    #[test]
+    fn distribution_stats_all_null() {
+        let arr = PrimitiveArray::from_nullable_vec(vec![Option::<i32>::None; 5]);
+        assert_eq!(arr.statistics().compute_distinct_count(), Some(0));
+        assert_eq!(arr.statistics().compute(Stat::Histogram), None);
+    }
 }
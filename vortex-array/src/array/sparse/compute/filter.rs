@@ -0,0 +1,70 @@
+use vortex_error::VortexResult;
+
+use crate::array::primitive::PrimitiveArray;
+use crate::array::sparse::SparseArray;
+use crate::compute::{take, FilterFn};
+use crate::{Array, IntoArray, IntoArrayVariant};
+
+impl FilterFn for SparseArray {
+    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+        let predicate = predicate.clone().into_bool()?.boolean_buffer();
+
+        // Patched positions that are selected, and their index in the filtered array.
+        let mut positions = Vec::new();
+        let mut filtered_indices = Vec::new();
+        let mut selected_before = 0;
+        let mut counted_until = 0;
+        for (position, index) in self.resolved_indices().into_iter().enumerate() {
+            if predicate.value(index) {
+                selected_before += predicate
+                    .slice(counted_until, index - counted_until)
+                    .count_set_bits();
+                counted_until = index;
+                positions.push(position as u64);
+                filtered_indices.push(selected_before as u64);
+            }
+        }
+
+        Ok(Self::try_new(
+            PrimitiveArray::from(filtered_indices).into_array(),
+            take(
+                &self.values(),
+                &PrimitiveArray::from(positions).into_array(),
+            )?,
+            predicate.count_set_bits(),
+            self.fill_value().clone(),
+        )?
+        .into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{BoolArray, PrimitiveArray, Sparse, SparseArray};
+    use crate::compute::filter;
+    use crate::{ArrayDef, IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn filter_sparse() {
+        let sparse = SparseArray::try_new(
+            PrimitiveArray::from(vec![1u64, 4, 6]).into_array(),
+            PrimitiveArray::from_nullable_vec(vec![Some(10i32), Some(40), Some(60)]).into_array(),
+            8,
+            Scalar::null(DType::Primitive(PType::I32, Nullability::Nullable)),
+        )
+        .unwrap();
+        let predicate =
+            BoolArray::from(vec![false, true, true, false, false, true, true, true]).into_array();
+
+        let filtered = filter(sparse.array(), &predicate).unwrap();
+        assert_eq!(filtered.encoding().id(), Sparse::ID);
+        let filtered = filtered.into_primitive().unwrap();
+        assert_eq!(filtered.len(), 5);
+        assert_eq!(filtered.maybe_null_slice::<i32>()[0], 10);
+        assert_eq!(filtered.maybe_null_slice::<i32>()[3], 60);
+        assert!(!filtered.validity().is_valid(1));
+    }
+}
@@ -4,14 +4,20 @@ use vortex_scalar::Scalar;
 use crate::array::sparse::SparseArray;
 use crate::compute::unary::{scalar_at, scalar_at_unchecked, ScalarAtFn};
 use crate::compute::{
-    search_sorted, ArrayCompute, SearchResult, SearchSortedFn, SearchSortedSide, SliceFn, TakeFn,
+    search_sorted, ArrayCompute, FilterFn, SearchResult, SearchSortedFn, SearchSortedSide, SliceFn,
+    TakeFn,
 };
 use crate::ArrayDType;
 
+mod filter;
 mod slice;
 mod take;
 
 impl ArrayCompute for SparseArray {
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -6,16 +6,18 @@ use vortex_scalar::Scalar;
 use crate::array::constant::ConstantArray;
 use crate::compute::unary::scalar_at;
 use crate::compute::{search_sorted, SearchSortedSide};
-use crate::stats::{ArrayStatisticsCompute, StatsSet};
+use crate::encoding::ids;
+use crate::stats::StatsSet;
 use crate::validity::{ArrayValidity, LogicalValidity};
 use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use crate::{impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, IntoArray, IntoArrayVariant};
 
 mod compute;
 mod flatten;
+mod stats;
 mod variants;
 
-impl_encoding!("vortex.sparse", 9u16, Sparse);
+impl_encoding!("vortex.sparse", ids::SPARSE, Sparse);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SparseMetadata {
@@ -158,8 +160,6 @@ impl AcceptArrayVisitor for SparseArray {
     }
 }
 
-impl ArrayStatisticsCompute for SparseArray {}
-
 impl ArrayValidity for SparseArray {
     fn is_valid(&self, index: usize) -> bool {
         match self.find_index(index).unwrap_or_else(|err| {
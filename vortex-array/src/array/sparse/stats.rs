@@ -0,0 +1,155 @@
+use vortex_error::VortexResult;
+
+use crate::array::sparse::SparseArray;
+use crate::stats::{ArrayStatistics, ArrayStatisticsCompute, Stat, StatsSet};
+use crate::{Array, IntoArray, IntoCanonical};
+
+impl ArrayStatisticsCompute for SparseArray {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        if self.is_empty() {
+            return Ok(StatsSet::new());
+        }
+
+        if stat == Stat::Histogram {
+            // The depth of each bucket depends on how often the fill value repeats.
+            let canonical = Array::from(self.clone().into_array().into_canonical()?);
+            return Ok(canonical
+                .statistics()
+                .compute(stat)
+                .map(|histogram| StatsSet::of(stat, histogram))
+                .unwrap_or_default());
+        }
+
+        let compacted = Array::from(self.compacted()?.into_array().into_canonical()?);
+        compacted.statistics().compute(stat);
+
+        let values = self.values();
+        let fill_len = self.len() - values.len();
+        let mut stats = StatsSet::new();
+        for (stat, value) in compacted.statistics().to_set() {
+            match stat {
+                Stat::NullCount => {
+                    let fill_nulls = if self.fill_value().is_null() {
+                        fill_len
+                    } else {
+                        0
+                    };
+                    if let Some(null_count) = values.statistics().compute_null_count() {
+                        stats.set(stat, (null_count + fill_nulls).into());
+                    }
+                }
+                Stat::TrueCount => {
+                    let fill_trues = if bool::try_from(self.fill_value()).unwrap_or(false) {
+                        fill_len
+                    } else {
+                        0
+                    };
+                    if let Some(true_count) = values.statistics().compute_true_count() {
+                        stats.set(stat, (true_count + fill_trues).into());
+                    }
+                }
+                // The compacted array has a NaN exactly when the array has one, but fewer of them.
+                Stat::NanCount => {
+                    if usize::try_from(&value).ok() == Some(0) {
+                        stats.set(stat, value);
+                    }
+                }
+                // These count every element, so cannot be taken from the compacted array.
+                Stat::BitWidthFreq | Stat::TrailingZeroFreq | Stat::Histogram => {}
+                _ => stats.set(stat, value),
+            }
+        }
+        Ok(stats)
+    }
+}
+
+impl SparseArray {
+    /// The array with each run of fill values between two patches cut down to at most two
+    /// elements.
+    ///
+    /// This keeps the distinct values, the ordering and the runs of the array, so all stats
+    /// other than counts can be computed from it in time proportional to the number of patches.
+    fn compacted(&self) -> VortexResult<Self> {
+        let mut indices = Vec::with_capacity(self.values().len());
+        let mut len = 0;
+        let mut next = 0;
+        for index in self.resolved_indices() {
+            len += (index - next).min(2);
+            indices.push(len as u64);
+            len += 1;
+            next = index + 1;
+        }
+        len += (self.len() - next).min(2);
+
+        Self::try_new(
+            indices.into_array(),
+            self.values(),
+            len,
+            self.fill_value().clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::sparse::SparseArray;
+    use crate::array::{BoolArray, PrimitiveArray};
+    use crate::stats::{ArrayStatistics, Stat};
+    use crate::IntoArray;
+
+    fn sparse(indices: Vec<u64>, values: Vec<i32>, len: usize, fill_value: Scalar) -> SparseArray {
+        SparseArray::try_new(indices.into_array(), values.into_array(), len, fill_value).unwrap()
+    }
+
+    #[test]
+    fn stats_with_fill() {
+        let array = sparse(vec![2, 5, 8], vec![100, -5, 300], 10, 0i32.into());
+        assert_eq!(array.statistics().compute_min::<i32>(), Some(-5));
+        assert_eq!(array.statistics().compute_max::<i32>(), Some(300));
+        assert_eq!(array.statistics().compute_null_count(), Some(0));
+        assert_eq!(array.statistics().compute_is_sorted(), Some(false));
+        assert_eq!(array.statistics().compute_is_constant(), Some(false));
+        assert_eq!(array.statistics().compute_run_count(), Some(7));
+        assert!(array.statistics().get(Stat::BitWidthFreq).is_none());
+    }
+
+    #[test]
+    fn sorted_with_fill() {
+        let array = sparse(vec![7, 8, 9], vec![1, 2, 3], 10, 0i32.into());
+        assert_eq!(array.statistics().compute_is_sorted(), Some(true));
+        assert_eq!(array.statistics().compute_is_strict_sorted(), Some(false));
+        assert_eq!(array.statistics().compute_min::<i32>(), Some(0));
+
+        let array = sparse(vec![0, 2], vec![1, 3], 3, 2i32.into());
+        assert_eq!(array.statistics().compute_is_strict_sorted(), Some(true));
+    }
+
+    #[test]
+    fn null_fill() {
+        let array = SparseArray::try_new(
+            vec![2u64, 5].into_array(),
+            PrimitiveArray::from_nullable_vec(vec![Some(100i32), Some(50)]).into_array(),
+            10,
+            Scalar::null(DType::Primitive(PType::I32, Nullability::Nullable)),
+        )
+        .unwrap();
+        assert_eq!(array.statistics().compute_null_count(), Some(8));
+        assert_eq!(array.statistics().compute_min::<i32>(), Some(50));
+        assert_eq!(array.statistics().compute_max::<i32>(), Some(100));
+    }
+
+    #[test]
+    fn bool_true_count() {
+        let array = SparseArray::try_new(
+            vec![1u64, 3].into_array(),
+            BoolArray::from(vec![false, false]).into_array(),
+            6,
+            true.into(),
+        )
+        .unwrap();
+        assert_eq!(array.statistics().compute_true_count(), Some(4));
+    }
+}
@@ -1,9 +1,10 @@
 use serde::{Deserialize, Serialize};
 use vortex_dtype::field::Field;
-use vortex_dtype::{DType, FieldName, FieldNames, Nullability, StructDType};
+use vortex_dtype::{DType, FieldName, FieldNames, StructDType};
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
-use crate::stats::{ArrayStatisticsCompute, StatsSet};
+use crate::encoding::ids;
+use crate::stats::{ArrayStatistics, ArrayStatisticsCompute, Stat, StatsSet};
 use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
 use crate::variants::{ArrayVariants, StructArrayTrait};
 use crate::visitor::{AcceptArrayVisitor, ArrayVisitor};
@@ -11,7 +12,7 @@ use crate::{impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, Canonical, I
 
 mod compute;
 
-impl_encoding!("vortex.struct", 8u16, Struct);
+impl_encoding!("vortex.struct", ids::STRUCT, Struct);
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct StructMetadata {
@@ -35,6 +36,8 @@ impl StructArray {
         })
     }
 
+    /// Build a struct array from its fields, nullable exactly when `validity` is. See
+    /// [`DType::is_nullable`].
     pub fn try_new(
         names: FieldNames,
         fields: Vec<Array>,
@@ -51,6 +54,7 @@ impl StructArray {
 
         let field_dtypes: Vec<_> = fields.iter().map(|d| d.dtype()).cloned().collect();
 
+        let nullability = validity.nullability();
         let validity_metadata = validity.to_metadata(length)?;
 
         let mut children = Vec::with_capacity(fields.len() + 1);
@@ -60,10 +64,7 @@ impl StructArray {
         }
 
         Self::try_from_parts(
-            DType::Struct(
-                StructDType::new(names, field_dtypes),
-                Nullability::NonNullable,
-            ),
+            DType::Struct(StructDType::new(names, field_dtypes), nullability),
             length,
             StructMetadata {
                 length,
@@ -172,7 +173,20 @@ impl AcceptArrayVisitor for StructArray {
     }
 }
 
-impl ArrayStatisticsCompute for StructArray {}
+impl ArrayStatisticsCompute for StructArray {
+    fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
+        let null_count = match self.logical_validity() {
+            LogicalValidity::AllValid(_) => 0,
+            LogicalValidity::AllInvalid(len) => len,
+            LogicalValidity::Array(a) => a
+                .statistics()
+                .compute_true_count()
+                .map(|true_count| self.len() - true_count)
+                .ok_or_else(|| vortex_err!("Failed to compute true count of struct validity"))?,
+        };
+        Ok(StatsSet::of(Stat::NullCount, null_count.into()))
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -183,9 +197,11 @@ mod test {
     use crate::array::struct_::StructArray;
     use crate::array::varbin::VarBinArray;
     use crate::array::BoolArray;
-    use crate::validity::Validity;
+    use crate::compute::unary::scalar_at;
+    use crate::stats::ArrayStatistics;
+    use crate::validity::{ArrayValidity, Validity};
     use crate::variants::StructArrayTrait;
-    use crate::IntoArray;
+    use crate::{ArrayDType, IntoArray};
 
     #[test]
     fn test_project() {
@@ -223,4 +239,53 @@ mod test {
         let prims = PrimitiveArray::try_from(struct_b.field(1).unwrap()).unwrap();
         assert_eq!(prims.maybe_null_slice::<i64>(), [0i64, 1, 2, 3, 4]);
     }
+
+    #[test]
+    fn null_count() {
+        let xs = PrimitiveArray::from_vec(vec![0i64, 1, 2, 3], Validity::NonNullable);
+        let array = StructArray::try_new(
+            FieldNames::from(["xs".into()]),
+            vec![xs.into_array()],
+            4,
+            Validity::from(vec![true, false, false, true]),
+        )
+        .unwrap();
+        assert_eq!(array.statistics().compute_null_count(), Some(2));
+    }
+
+    #[test]
+    fn nullability_follows_validity() {
+        let xs = || PrimitiveArray::from_vec(vec![0i64, 1, 2], Validity::NonNullable).into_array();
+        let names = FieldNames::from(["xs".into()]);
+
+        // The nullability of the struct does not depend on that of its fields.
+        let nullable_field = PrimitiveArray::from_vec(vec![0i64, 1, 2], Validity::AllValid);
+        let non_nullable = StructArray::try_new(
+            names.clone(),
+            vec![nullable_field.into_array()],
+            3,
+            Validity::NonNullable,
+        )
+        .unwrap();
+        assert!(!non_nullable.dtype().is_nullable());
+
+        let all_valid =
+            StructArray::try_new(names.clone(), vec![xs()], 3, Validity::AllValid).unwrap();
+        assert_eq!(all_valid.dtype().nullability(), Nullability::Nullable);
+
+        let nullable = StructArray::try_new(
+            names,
+            vec![xs()],
+            3,
+            Validity::from(vec![true, false, true]),
+        )
+        .unwrap();
+        assert_eq!(nullable.dtype().nullability(), Nullability::Nullable);
+        assert!(scalar_at(nullable.array(), 1).unwrap().is_null());
+        assert!(!scalar_at(nullable.array(), 2).unwrap().is_null());
+
+        let projected = nullable.project(&[Field::from(0)]).unwrap();
+        assert_eq!(projected.dtype(), nullable.dtype());
+        assert!(!projected.is_valid(1));
+    }
 }
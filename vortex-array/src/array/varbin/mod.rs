@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use num_traits::AsPrimitive;
 use serde::{Deserialize, Serialize};
-pub use stats::compute_stats;
+pub use stats::{compute_distribution, compute_stats};
 use vortex_buffer::Buffer;
 use vortex_dtype::{match_each_native_ptype, DType, NativePType, Nullability};
 use vortex_error::{vortex_bail, VortexError, VortexResult};
@@ -12,6 +12,7 @@ use crate::array::primitive::PrimitiveArray;
 use crate::array::varbin::builder::VarBinBuilder;
 use crate::compute::slice;
 use crate::compute::unary::scalar_at;
+use crate::encoding::ids;
 use crate::stats::StatsSet;
 use crate::validity::{Validity, ValidityMetadata};
 use crate::{impl_encoding, Array, ArrayDType, ArrayDef, ArrayTrait, IntoArrayVariant};
@@ -24,7 +25,7 @@ mod flatten;
 mod stats;
 mod variants;
 
-impl_encoding!("vortex.varbin", 4u16, VarBin);
+impl_encoding!("vortex.varbin", ids::VARBIN, VarBin);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VarBinMetadata {
@@ -4,21 +4,54 @@ use std::collections::HashMap;
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
 use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
 
 use crate::accessor::ArrayAccessor;
 use crate::array::varbin::{varbin_scalar, VarBinArray};
-use crate::stats::{ArrayStatisticsCompute, Stat, StatsSet};
+use crate::stats::{equi_depth_bounds, ArrayStatisticsCompute, HyperLogLog, Stat, StatsSet};
 use crate::ArrayDType;
 
 impl ArrayStatisticsCompute for VarBinArray {
-    fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
         if self.is_empty() {
             return Ok(StatsSet::new());
         }
+        if matches!(stat, Stat::DistinctCount | Stat::Histogram) {
+            return self.with_iterator(|iter| compute_distribution(iter, stat, self.dtype()));
+        }
         self.with_iterator(|iter| compute_stats(iter, self.dtype()))
     }
 }
 
+/// Compute a stat describing the distribution of the non-null values. These are too expensive to
+/// compute along with the other stats.
+pub fn compute_distribution(
+    iter: &mut dyn Iterator<Item = Option<&[u8]>>,
+    stat: Stat,
+    dtype: &DType,
+) -> StatsSet {
+    let values = iter.flatten();
+    match stat {
+        Stat::DistinctCount => {
+            let mut sketch = HyperLogLog::new();
+            values.for_each(|v| sketch.insert(v));
+            StatsSet::of(stat, sketch.into())
+        }
+        _ => {
+            let mut sorted = values.collect::<Vec<_>>();
+            if sorted.is_empty() {
+                return StatsSet::new();
+            }
+            sorted.sort_unstable();
+            let bounds = equi_depth_bounds(&sorted)
+                .into_iter()
+                .map(|v| varbin_scalar(Buffer::from(v), dtype).into_value())
+                .collect();
+            StatsSet::of(stat, Scalar::list(dtype.as_nonnullable(), bounds))
+        }
+    }
+}
+
 pub fn compute_stats(iter: &mut dyn Iterator<Item = Option<&[u8]>>, dtype: &DType) -> StatsSet {
     let mut leading_nulls: usize = 0;
     let mut first_value: Option<&[u8]> = None;
@@ -119,9 +152,10 @@ mod test {
 
     use vortex_buffer::{Buffer, BufferString};
     use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::{ListScalar, Scalar};
 
     use crate::array::varbin::VarBinArray;
-    use crate::stats::{ArrayStatistics, Stat};
+    use crate::stats::{ArrayStatistics, Stat, HISTOGRAM_BUCKETS};
 
     fn array(dtype: DType) -> VarBinArray {
         VarBinArray::from_vec(
@@ -183,6 +217,25 @@ mod test {
         );
     }
 
+    #[test]
+    fn distribution_stats() {
+        let array = VarBinArray::from_iter(
+            (0..100).map(|i| (i % 10 != 0).then(|| format!("value {}", i % 20))),
+            DType::Utf8(Nullability::Nullable),
+        );
+        let distinct = array.statistics().compute_distinct_count().unwrap();
+        assert!((17..=19).contains(&distinct), "{distinct}");
+
+        let histogram = array.statistics().compute(Stat::Histogram).unwrap();
+        let bounds = ListScalar::try_from(&histogram).unwrap();
+        assert_eq!(bounds.len(), HISTOGRAM_BUCKETS + 1);
+        assert_eq!(bounds.element(0).unwrap(), Scalar::from("value 1"));
+        assert_eq!(
+            bounds.element(HISTOGRAM_BUCKETS).unwrap(),
+            Scalar::from("value 9")
+        );
+    }
+
     #[test]
     fn all_nulls() {
         let array = VarBinArray::from_iter(
@@ -1,16 +1,46 @@
 use vortex_error::VortexResult;
 
 use crate::accessor::ArrayAccessor;
-use crate::array::varbin::compute_stats;
+use crate::array::varbin::{compute_distribution, compute_stats};
 use crate::array::varbinview::VarBinViewArray;
 use crate::stats::{ArrayStatisticsCompute, Stat, StatsSet};
 use crate::ArrayDType;
 
 impl ArrayStatisticsCompute for VarBinViewArray {
-    fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
         if self.is_empty() {
             return Ok(StatsSet::new());
         }
+        if matches!(stat, Stat::DistinctCount | Stat::Histogram) {
+            return self.with_iterator(|iter| compute_distribution(iter, stat, self.dtype()));
+        }
         self.with_iterator(|iter| compute_stats(iter, self.dtype()))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex_buffer::BufferString;
+
+    use crate::array::VarBinViewArray;
+    use crate::stats::ArrayStatistics;
+
+    #[test]
+    fn utf8_stats() {
+        let array = VarBinViewArray::from_iter_nullable_str([
+            Some("a short string"),
+            None,
+            Some("a string that is too long to be inlined"),
+        ]);
+        assert_eq!(
+            array.statistics().compute_min::<BufferString>().unwrap(),
+            BufferString::from("a short string".to_string())
+        );
+        assert_eq!(
+            array.statistics().compute_max::<BufferString>().unwrap(),
+            BufferString::from("a string that is too long to be inlined".to_string())
+        );
+        assert_eq!(array.statistics().compute_null_count(), Some(1));
+        assert_eq!(array.statistics().compute_is_sorted(), Some(true));
+    }
+}
@@ -5,7 +5,7 @@ use arrow_array::array::{
 };
 use arrow_array::cast::{as_null_array, AsArray};
 use arrow_array::types::{
-    ByteArrayType, ByteViewType, Date32Type, Date64Type, DurationMicrosecondType,
+    ByteArrayType, ByteViewType, Date32Type, Date64Type, Decimal128Type, DurationMicrosecondType,
     DurationMillisecondType, DurationNanosecondType, DurationSecondType, Float16Type, Float32Type,
     Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, Time32MillisecondType,
     Time32SecondType, Time64MicrosecondType, Time64NanosecondType, TimestampMicrosecondType,
@@ -23,10 +23,11 @@ use vortex_dtype::{DType, NativePType, PType};
 use crate::array::{
     BoolArray, NullArray, PrimitiveArray, StructArray, TemporalArray, VarBinArray, VarBinViewArray,
 };
+use crate::arrow::decimal::decimal_from_arrow;
 use crate::arrow::FromArrowArray;
 use crate::stats::{Stat, Statistics};
 use crate::validity::Validity;
-use crate::{Array, ArrayData, ToArrayData};
+use crate::{Array, ArrayData, IntoArray, ToArrayData};
 
 impl From<Buffer> for ArrayData {
     fn from(value: Buffer) -> Self {
@@ -281,6 +282,11 @@ impl FromArrowArray<ArrowArrayRef> for Array {
                     Self::from_arrow(array.as_primitive::<DurationNanosecondType>(), nullable)
                 }
             },
+            DataType::Decimal128(..) => {
+                decimal_from_arrow(array.as_primitive::<Decimal128Type>(), nullable)
+                    .unwrap_or_else(|err| panic!("{err}"))
+                    .into_array()
+            }
             _ => panic!(
                 "TODO(robert): Missing array encoding for dtype {}",
                 array.data_type().clone()
@@ -0,0 +1,110 @@
+//! Arrow `Decimal128` values stored as a Vortex extension type.
+//!
+//! Decimals of up to [`MAX_DECIMAL_PRECISION`] digits fit in an `i64`, so their unscaled values
+//! are stored as a primitive I64 array, with the precision and scale kept in the extension
+//! metadata.
+
+use std::sync::Arc;
+
+use arrow_array::types::Decimal128Type;
+use arrow_array::{Array as _, ArrayRef, Decimal128Array, PrimitiveArray as ArrowPrimitiveArray};
+use arrow_buffer::ScalarBuffer;
+use vortex_dtype::{ExtDType, ExtID, ExtMetadata};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::{ExtensionArray, PrimitiveArray};
+use crate::validity::{ArrayValidity, Validity};
+use crate::{IntoArray, IntoArrayVariant};
+
+/// ID of the decimal extension type.
+pub const DECIMAL_ID: &str = "vortex.decimal";
+
+/// The largest precision of a decimal whose unscaled values always fit in an `i64`.
+pub const MAX_DECIMAL_PRECISION: u8 = 18;
+
+/// The extension type of decimals with the given `precision` and `scale`.
+pub fn decimal_ext_dtype(precision: u8, scale: i8) -> ExtDType {
+    ExtDType::new(
+        ExtID::from(DECIMAL_ID),
+        Some(ExtMetadata::from(&[precision, scale as u8][..])),
+    )
+}
+
+/// The precision and scale of `ext_dtype`, if it is the decimal extension type.
+pub fn decimal_precision_scale(ext_dtype: &ExtDType) -> Option<(u8, i8)> {
+    if ext_dtype.id().as_ref() != DECIMAL_ID {
+        return None;
+    }
+    match ext_dtype.metadata()?.as_ref() {
+        [precision, scale] => Some((*precision, *scale as i8)),
+        _ => None,
+    }
+}
+
+pub(crate) fn decimal_from_arrow(
+    array: &ArrowPrimitiveArray<Decimal128Type>,
+    nullable: bool,
+) -> VortexResult<ExtensionArray> {
+    let precision = array.precision();
+    if precision > MAX_DECIMAL_PRECISION {
+        vortex_bail!(
+            "Decimals with precision {} are not supported, the maximum is {}",
+            precision,
+            MAX_DECIMAL_PRECISION
+        )
+    }
+
+    let values = array.values().iter().map(|v| *v as i64).collect::<Vec<_>>();
+    let validity = match array.nulls() {
+        Some(nulls) if nullable => Validity::from(nulls.inner().clone()),
+        _ if nullable => Validity::AllValid,
+        _ => Validity::NonNullable,
+    };
+    Ok(ExtensionArray::new(
+        decimal_ext_dtype(precision, array.scale()),
+        PrimitiveArray::from_vec(values, validity).into_array(),
+    ))
+}
+
+pub(crate) fn decimal_to_arrow(
+    array: &ExtensionArray,
+    precision: u8,
+    scale: i8,
+) -> VortexResult<ArrayRef> {
+    let storage = array.storage().into_primitive()?;
+    let nulls = storage.logical_validity().to_null_buffer()?;
+    let values = storage
+        .maybe_null_slice::<i64>()
+        .iter()
+        .map(|v| *v as i128)
+        .collect::<ScalarBuffer<_>>();
+    Ok(Arc::new(
+        Decimal128Array::try_new(values, nulls)?.with_precision_and_scale(precision, scale)?,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::{Array as _, Decimal128Array};
+
+    use super::*;
+    use crate::arrow::FromArrowArray;
+    use crate::{Array, IntoCanonical};
+
+    #[test]
+    fn decimal_round_trip() {
+        let arrow: ArrayRef = Arc::new(
+            Decimal128Array::from(vec![Some(12345), None, Some(-1)])
+                .with_precision_and_scale(10, 2)
+                .unwrap(),
+        );
+
+        let array = Array::from_arrow(arrow.clone(), true);
+        let ext = ExtensionArray::try_from(&array).unwrap();
+        assert_eq!(decimal_precision_scale(ext.ext_dtype()), Some((10, 2)));
+
+        let round_tripped = array.into_canonical().unwrap().into_arrow();
+        assert_eq!(round_tripped.data_type(), arrow.data_type());
+        assert_eq!(&round_tripped, &arrow);
+    }
+}
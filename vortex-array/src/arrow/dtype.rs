@@ -6,6 +6,7 @@ use vortex_datetime_dtype::arrow::make_temporal_ext_dtype;
 use vortex_dtype::{DType, Nullability, PType, StructDType};
 use vortex_error::{vortex_err, VortexResult};
 
+use crate::arrow::decimal::{decimal_ext_dtype, MAX_DECIMAL_PRECISION};
 use crate::arrow::{FromArrowType, TryFromArrowType};
 
 impl TryFromArrowType<&DataType> for PType {
@@ -74,6 +75,9 @@ impl FromArrowType<&Field> for DType {
                 make_temporal_ext_dtype(field.data_type()),
                 field.is_nullable().into(),
             ),
+            DataType::Decimal128(precision, scale) if *precision <= MAX_DECIMAL_PRECISION => {
+                Extension(decimal_ext_dtype(*precision, *scale), nullability)
+            }
             DataType::List(e) | DataType::LargeList(e) => {
                 List(Arc::new(Self::from_arrow(e.as_ref())), nullability)
             }
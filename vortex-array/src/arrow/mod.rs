@@ -1,6 +1,7 @@
 use vortex_error::VortexResult;
 
 mod array;
+pub mod decimal;
 mod dtype;
 mod recordbatch;
 pub mod wrappers;
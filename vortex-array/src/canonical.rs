@@ -21,6 +21,7 @@ use vortex_error::{vortex_bail, VortexResult};
 use crate::array::{
     BoolArray, ExtensionArray, NullArray, PrimitiveArray, StructArray, TemporalArray, VarBinArray,
 };
+use crate::arrow::decimal::{decimal_precision_scale, decimal_to_arrow};
 use crate::arrow::wrappers::as_offset_buffer;
 use crate::compute::unary::try_cast;
 use crate::encoding::ArrayEncoding;
@@ -78,8 +79,18 @@ impl Canonical {
             Canonical::Struct(a) => struct_to_arrow(a),
             Canonical::VarBin(a) => varbin_to_arrow(a),
             Canonical::Extension(a) => {
+                if let Some((precision, scale)) = decimal_precision_scale(a.ext_dtype()) {
+                    return decimal_to_arrow(&a, precision, scale)
+                        .expect("decimal storage must convert to arrow");
+                }
+
+                // Extension types unknown to Arrow are exported as their storage array.
                 if !is_temporal_ext_type(a.id()) {
-                    panic!("unsupported extension dtype with ID {}", a.id().as_ref())
+                    return a
+                        .storage()
+                        .into_canonical()
+                        .expect("extension storage must canonicalize")
+                        .into_arrow();
                 }
 
                 temporal_to_arrow(
@@ -451,10 +462,10 @@ mod test {
     use arrow_array::{
         Array, PrimitiveArray as ArrowPrimitiveArray, StructArray as ArrowStructArray,
     };
-    use vortex_dtype::Nullability;
+    use vortex_dtype::{ExtDType, ExtID, Nullability};
     use vortex_scalar::Scalar;
 
-    use crate::array::{PrimitiveArray, SparseArray, StructArray};
+    use crate::array::{ExtensionArray, PrimitiveArray, SparseArray, StructArray};
     use crate::validity::Validity;
     use crate::{IntoArray, IntoCanonical};
 
@@ -522,4 +533,22 @@ mod test {
             ArrowPrimitiveArray::from(vec![100i64]),
         );
     }
+
+    #[test]
+    fn test_extension_storage_to_arrow() {
+        let storage = PrimitiveArray::from_vec(vec![1u64, 2, 3], Validity::NonNullable);
+        let ext = ExtensionArray::new(
+            ExtDType::new(ExtID::from("example.id"), None),
+            storage.into_array(),
+        );
+
+        let arrow = ext.into_canonical().unwrap().into_arrow();
+        assert_eq!(
+            arrow
+                .as_any()
+                .downcast_ref::<ArrowPrimitiveArray<UInt64Type>>()
+                .unwrap(),
+            &ArrowPrimitiveArray::<UInt64Type>::from(vec![1u64, 2, 3]),
+        );
+    }
 }
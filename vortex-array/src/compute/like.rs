@@ -0,0 +1,59 @@
+use arrow_array::{Scalar, StringArray};
+use arrow_cast::cast;
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::arrow::FromArrowArray;
+use crate::{Array, ArrayDType, IntoCanonical};
+
+pub trait LikeFn {
+    fn like(&self, pattern: &str) -> VortexResult<Array>;
+}
+
+/// Evaluate the SQL `LIKE` `pattern` over the values of a string array.
+///
+/// `%` matches any sequence of characters, `_` matches a single character and `\` escapes the
+/// following character. The result is a boolean array that is null wherever the value is null.
+pub fn like(array: &Array, pattern: &str) -> VortexResult<Array> {
+    if !matches!(array.dtype(), DType::Utf8(_)) {
+        vortex_bail!(
+            "LIKE is only supported on Utf8 arrays, got {}",
+            array.dtype()
+        )
+    }
+
+    if let Some(matches) = array.with_dyn(|a| a.like().map(|a| a.like(pattern))) {
+        return matches;
+    }
+
+    // Otherwise, decode the array and use Arrow's implementation.
+    let arrow = array.clone().into_canonical()?.into_arrow();
+    let pattern = cast(&StringArray::from(vec![pattern]), arrow.data_type())?;
+    let matches = arrow_string::like::like(&arrow, &Scalar::new(pattern))?;
+
+    Ok(Array::from_arrow(&matches, array.dtype().is_nullable()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{BoolArray, VarBinArray};
+    use crate::validity::ArrayValidity;
+    use crate::IntoArray;
+
+    #[test]
+    fn like_varbin() {
+        let array = VarBinArray::from_iter(
+            [Some("apple"), None, Some("banana"), Some("apricot")],
+            DType::Utf8(true.into()),
+        )
+        .into_array();
+
+        let matches = BoolArray::try_from(like(&array, "ap%").unwrap()).unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, false, false, true]
+        );
+        assert!(!matches.is_valid(1));
+    }
+}
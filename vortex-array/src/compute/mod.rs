@@ -10,6 +10,7 @@
 pub use boolean::{and, or, AndFn, OrFn};
 pub use compare::{compare, scalar_cmp, CompareFn, Operator};
 pub use filter::{filter, FilterFn};
+pub use like::{like, LikeFn};
 pub use search_sorted::*;
 pub use slice::{slice, SliceFn};
 pub use take::{take, TakeFn};
@@ -18,6 +19,7 @@ use unary::{CastFn, FillForwardFn, ScalarAtFn, SubtractScalarFn};
 mod boolean;
 mod compare;
 mod filter;
+mod like;
 mod search_sorted;
 mod slice;
 mod take;
@@ -54,6 +56,13 @@ pub trait ArrayCompute {
         None
     }
 
+    /// Matching of string arrays against a SQL `LIKE` pattern.
+    ///
+    /// See: [LikeFn].
+    fn like(&self) -> Option<&dyn LikeFn> {
+        None
+    }
+
     /// Single item indexing on Vortex arrays.
     ///
     /// See: [ScalarAtFn].
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use vortex_error::{vortex_bail, VortexResult};
+
 use crate::array::{
     BoolEncoding, ChunkedEncoding, ConstantEncoding, ExtensionEncoding, PrimitiveEncoding,
     SparseEncoding, StructEncoding, VarBinEncoding, VarBinViewEncoding,
@@ -12,15 +14,44 @@ pub struct Context {
 }
 
 impl Context {
-    pub fn with_encoding(mut self, encoding: EncodingRef) -> Self {
-        self.encodings.insert(encoding.id().code(), encoding);
-        self
+    /// Register an encoding with this context.
+    ///
+    /// Registering the same encoding again has no effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a different encoding with the same code is already registered, see
+    /// [`Context::try_with_encoding`].
+    pub fn with_encoding(self, encoding: EncodingRef) -> Self {
+        self.try_with_encoding(encoding)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Register an encoding with this context.
+    ///
+    /// Registering the same encoding again has no effect. Fails if a different encoding with the
+    /// same code is already registered, since arrays of either encoding could then not be told
+    /// apart when read back.
+    pub fn try_with_encoding(mut self, encoding: EncodingRef) -> VortexResult<Self> {
+        self.register(encoding)?;
+        Ok(self)
     }
 
-    pub fn with_encodings<E: IntoIterator<Item = EncodingRef>>(mut self, encodings: E) -> Self {
-        self.encodings
-            .extend(encodings.into_iter().map(|e| (e.id().code(), e)));
-        self
+    /// Register each of the given encodings, see [`Context::with_encoding`].
+    pub fn with_encodings<E: IntoIterator<Item = EncodingRef>>(self, encodings: E) -> Self {
+        self.try_with_encodings(encodings)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Register each of the given encodings, see [`Context::try_with_encoding`].
+    pub fn try_with_encodings<E: IntoIterator<Item = EncodingRef>>(
+        mut self,
+        encodings: E,
+    ) -> VortexResult<Self> {
+        for encoding in encodings {
+            self.register(encoding)?;
+        }
+        Ok(self)
     }
 
     pub fn encodings(&self) -> impl Iterator<Item = EncodingRef> + '_ {
@@ -30,25 +61,91 @@ impl Context {
     pub fn lookup_encoding(&self, encoding_code: u16) -> Option<EncodingRef> {
         self.encodings.get(&encoding_code).cloned()
     }
+
+    fn register(&mut self, encoding: EncodingRef) -> VortexResult<()> {
+        let existing = self
+            .encodings
+            .entry(encoding.id().code())
+            .or_insert(encoding);
+        if existing.id() != encoding.id() {
+            vortex_bail!(
+                "Encoding {} has the same code as {}",
+                encoding.id(),
+                existing.id()
+            )
+        }
+        Ok(())
+    }
 }
 
 impl Default for Context {
     fn default() -> Self {
         Self {
-            encodings: [
-                &BoolEncoding as EncodingRef,
-                &ChunkedEncoding,
-                &ConstantEncoding,
-                &ExtensionEncoding,
-                &PrimitiveEncoding,
-                &SparseEncoding,
-                &StructEncoding,
-                &VarBinEncoding,
-                &VarBinViewEncoding,
-            ]
-            .into_iter()
-            .map(|e| (e.id().code(), e))
-            .collect(),
+            encodings: HashMap::new(),
+        }
+        .with_encodings([
+            &BoolEncoding as EncodingRef,
+            &ChunkedEncoding,
+            &ConstantEncoding,
+            &ExtensionEncoding,
+            &PrimitiveEncoding,
+            &SparseEncoding,
+            &StructEncoding,
+            &VarBinEncoding,
+            &VarBinViewEncoding,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_error::{vortex_bail, VortexResult};
+
+    use crate::array::{NullEncoding, PrimitiveEncoding};
+    use crate::encoding::{ArrayEncoding, EncodingId};
+    use crate::{Array, ArrayTrait, Canonical, Context};
+
+    #[derive(Debug)]
+    struct Clashing;
+
+    impl ArrayEncoding for Clashing {
+        fn id(&self) -> EncodingId {
+            EncodingId::new("test.clashing", PrimitiveEncoding.id().code())
+        }
+
+        fn canonicalize(&self, _array: Array) -> VortexResult<Canonical> {
+            vortex_bail!("test.clashing cannot be canonicalized")
+        }
+
+        fn with_dyn(
+            &self,
+            _array: &Array,
+            _f: &mut dyn for<'b> FnMut(&'b (dyn ArrayTrait + 'b)) -> VortexResult<()>,
+        ) -> VortexResult<()> {
+            vortex_bail!("test.clashing has no array implementation")
         }
     }
+
+    #[test]
+    fn register_same_encoding_twice() {
+        let ctx = Context::default()
+            .with_encoding(&PrimitiveEncoding)
+            .with_encoding(&NullEncoding)
+            .with_encoding(&NullEncoding);
+        assert_eq!(ctx.encodings().count(), 10);
+    }
+
+    #[test]
+    fn reject_duplicate_code() {
+        let err = Context::default().try_with_encoding(&Clashing).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("has the same code as vortex.primitive"));
+    }
+
+    #[test]
+    #[should_panic(expected = "has the same code as vortex.primitive")]
+    fn with_encoding_panics_on_duplicate_code() {
+        let _ = Context::default().with_encoding(&Clashing);
+    }
 }
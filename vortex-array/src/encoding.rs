@@ -6,16 +6,54 @@ use vortex_error::VortexResult;
 use crate::canonical::{Canonical, IntoCanonical};
 use crate::{Array, ArrayDef, ArrayTrait};
 
-// TODO(robert): Outline how you create a well known encoding id
 /// EncodingId is a unique name and numerical code of the array
 ///
 /// 0x0000 - reserved marker encoding
-/// 0x0001 - 0x04FF - vortex internal encodings
-/// 0x0401 - 0x7FFF - well known extension encodings
+/// 0x0001 - 0x03FF - vortex internal encodings
+/// 0x0400 - 0x7FFF - well known extension encodings
 /// 0x8000 - 0xFFFF - custom extension encodings
+///
+/// Codes for internal and well known encodings are allocated in [`ids`]. Third-party encodings
+/// should pick a code of at least [`ids::CUSTOM_START`].
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub struct EncodingId(&'static str, u16);
 
+/// Registry of the codes of the internal and well known encodings.
+///
+/// New encodings allocate the next free code here rather than in their `impl_encoding!` call, so
+/// that two encodings cannot end up sharing a code.
+pub mod ids {
+    pub const NULL: u16 = 1;
+    pub const BOOL: u16 = 2;
+    pub const PRIMITIVE: u16 = 3;
+    pub const VARBIN: u16 = 4;
+    pub const VARBINVIEW: u16 = 5;
+    pub const STRUCT: u16 = 8;
+    pub const SPARSE: u16 = 9;
+    pub const CONSTANT: u16 = 10;
+    pub const CHUNKED: u16 = 11;
+    pub const BYTE_BOOL: u16 = 12;
+    pub const ALP: u16 = 13;
+    pub const FL_BITPACKED: u16 = 14;
+    pub const FL_FOR: u16 = 15;
+    pub const EXTENSION: u16 = 16;
+    pub const ROARING_BOOL: u16 = 17;
+    pub const ROARING_INT: u16 = 18;
+    pub const RUN_END: u16 = 19;
+    pub const DICT: u16 = 20;
+    pub const ZIGZAG: u16 = 21;
+    pub const DATE_TIME_PARTS: u16 = 22;
+    pub const RUN_END_BOOL: u16 = 23;
+    pub const FSST: u16 = 24;
+    pub const ALP_RD: u16 = 25;
+    /// Format change: delta arrays used to be written with code 16, shared with [`EXTENSION`].
+    /// Files holding delta arrays written before the move cannot be read anymore.
+    pub const FL_DELTA: u16 = 26;
+
+    /// The first code of the range reserved for encodings defined outside of this repository.
+    pub const CUSTOM_START: u16 = 0x8000;
+}
+
 impl EncodingId {
     pub const fn new(id: &'static str, code: u16) -> Self {
         Self(id, code)
@@ -24,6 +62,11 @@ impl EncodingId {
     pub const fn code(&self) -> u16 {
         self.1
     }
+
+    /// Whether the code is in the range reserved for third-party encodings.
+    pub const fn is_custom(&self) -> bool {
+        self.1 >= ids::CUSTOM_START
+    }
 }
 
 impl Display for EncodingId {
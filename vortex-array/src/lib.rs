@@ -27,7 +27,7 @@ use vortex_error::VortexResult;
 use crate::compute::ArrayCompute;
 use crate::encoding::{ArrayEncodingRef, EncodingRef};
 use crate::iter::{ArrayIterator, ArrayIteratorAdapter};
-use crate::stats::{ArrayStatistics, ArrayStatisticsCompute};
+use crate::stats::{ArrayStatistics, ArrayStatisticsCompute, Statistics};
 use crate::stream::{ArrayStream, ArrayStreamAdapter};
 use crate::validity::ArrayValidity;
 use crate::variants::ArrayVariants;
@@ -113,6 +113,18 @@ impl Array {
         }
     }
 
+    /// Copy the statistics known for `parent`, an array holding the same values, onto this array.
+    ///
+    /// Views cannot hold statistics, so this is a no-op for them.
+    pub fn inherit_statistics(&self, parent: &dyn Statistics) {
+        if let Self::Data(d) = self {
+            let stats = d.statistics();
+            for (stat, value) in parent.to_set() {
+                stats.set(stat, value);
+            }
+        }
+    }
+
     pub fn depth_first_traversal(&self) -> ArrayChildrenIterator {
         ArrayChildrenIterator::new(self.clone())
     }
@@ -0,0 +1,155 @@
+use vortex_buffer::Buffer;
+use vortex_dtype::Nullability;
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+use vortex_scalar::Scalar;
+
+/// A HyperLogLog sketch of the distinct values of an array, the value of
+/// [`Stat::DistinctCount`](crate::stats::Stat::DistinctCount).
+///
+/// Sketches of two arrays merge into the sketch of their union, so the distinct count of a chunked
+/// array can be estimated from the sketches of its chunks. Estimates have a standard error of about
+/// 3%.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    /// The number of bits of each hash used to pick a register.
+    const PRECISION: u32 = 10;
+    const NUM_REGISTERS: usize = 1 << Self::PRECISION;
+
+    pub fn new() -> Self {
+        Self {
+            registers: vec![0; Self::NUM_REGISTERS],
+        }
+    }
+
+    pub fn insert(&mut self, value: &[u8]) {
+        let hash = hash_bytes(value);
+        let register = (hash >> (64 - Self::PRECISION)) as usize;
+        // The position of the first set bit after the register bits, counting from one.
+        let rank = ((hash << Self::PRECISION) | (1 << (Self::PRECISION - 1))).leading_zeros() + 1;
+        self.registers[register] = self.registers[register].max(rank as u8);
+    }
+
+    /// Merge the sketch of another array into this one.
+    pub fn merge(&mut self, other: &Self) {
+        for (own, other) in self.registers.iter_mut().zip(other.registers.iter()) {
+            *own = (*own).max(*other);
+        }
+    }
+
+    /// The estimated number of distinct values inserted into the sketch.
+    pub fn estimate(&self) -> u64 {
+        let m = Self::NUM_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self.registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+        let estimate = alpha * m * m / sum;
+
+        // Linear counting is more accurate while many registers are still empty.
+        let empty = self.registers.iter().filter(|&&r| r == 0).count();
+        if estimate <= 2.5 * m && empty > 0 {
+            (m * (m / empty as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+
+    pub fn registers(&self) -> &[u8] {
+        &self.registers
+    }
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryFrom<&[u8]> for HyperLogLog {
+    type Error = VortexError;
+
+    fn try_from(registers: &[u8]) -> VortexResult<Self> {
+        if registers.len() != Self::NUM_REGISTERS {
+            vortex_bail!(
+                "HyperLogLog sketch must have {} registers, got {}",
+                Self::NUM_REGISTERS,
+                registers.len()
+            );
+        }
+        Ok(Self {
+            registers: registers.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<&Scalar> for HyperLogLog {
+    type Error = VortexError;
+
+    fn try_from(value: &Scalar) -> VortexResult<Self> {
+        Self::try_from(Buffer::try_from(value)?.as_ref())
+    }
+}
+
+impl From<HyperLogLog> for Scalar {
+    fn from(value: HyperLogLog) -> Self {
+        Self::binary(Buffer::from(value.registers), Nullability::NonNullable)
+    }
+}
+
+/// A stable 64-bit hash, so that sketches written by one process can be merged with sketches
+/// written by another. FNV-1a, followed by the MurmurHash3 finalizer to spread the bits of short
+/// values across the whole hash.
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for &b in bytes {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    hash ^ (hash >> 33)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::stats::HyperLogLog;
+
+    fn sketch(values: impl Iterator<Item = u64>) -> HyperLogLog {
+        let mut sketch = HyperLogLog::new();
+        values.for_each(|v| sketch.insert(&v.to_le_bytes()));
+        sketch
+    }
+
+    fn assert_close(estimate: u64, expected: u64) {
+        let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+        assert!(error < 0.1, "estimated {estimate}, expected {expected}");
+    }
+
+    #[test]
+    fn estimate() {
+        assert_eq!(HyperLogLog::new().estimate(), 0);
+        assert_close(sketch(0..10).estimate(), 10);
+        assert_close(sketch((0..100_000).map(|i| i % 5000)).estimate(), 5000);
+        assert_close(sketch(0..1_000_000).estimate(), 1_000_000);
+    }
+
+    #[test]
+    fn merge() {
+        let mut first = sketch(0..60_000);
+        first.merge(&sketch(40_000..100_000));
+        assert_close(first.estimate(), 100_000);
+    }
+
+    #[test]
+    fn scalar_round_trip() {
+        let sketch = sketch(0..1000);
+        let scalar = Scalar::from(sketch.clone());
+        assert_eq!(HyperLogLog::try_from(&scalar).unwrap(), sketch);
+    }
+}
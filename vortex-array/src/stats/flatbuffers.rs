@@ -2,7 +2,7 @@ use flatbuffers::{FlatBufferBuilder, WIPOffset};
 use itertools::Itertools;
 use vortex_flatbuffers::WriteFlatBuffer;
 
-use crate::stats::{Stat, Statistics};
+use crate::stats::{HyperLogLog, Stat, Statistics};
 
 impl WriteFlatBuffer for &dyn Statistics {
     type Target<'t> = crate::flatbuffers::ArrayStats<'t>;
@@ -29,6 +29,14 @@ impl WriteFlatBuffer for &dyn Statistics {
             .get(Stat::Max)
             .map(|max| max.value().write_flatbuffer(fbb));
 
+        let distinct_count_sketch = self
+            .get_as::<HyperLogLog>(Stat::DistinctCount)
+            .map(|sketch| fbb.create_vector(sketch.registers()));
+
+        let histogram = self
+            .get(Stat::Histogram)
+            .map(|histogram| histogram.value().write_flatbuffer(fbb));
+
         let stat_args = &crate::flatbuffers::ArrayStatsArgs {
             min,
             max,
@@ -40,6 +48,9 @@ impl WriteFlatBuffer for &dyn Statistics {
             null_count: self.get_as_cast::<u64>(Stat::NullCount),
             bit_width_freq,
             trailing_zero_freq,
+            distinct_count_sketch,
+            histogram,
+            nan_count: self.get_as_cast::<u64>(Stat::NanCount),
         };
 
         crate::flatbuffers::ArrayStats::create(fbb, stat_args)
@@ -1,6 +1,7 @@
 use std::fmt::{Display, Formatter};
 use std::hash::Hash;
 
+pub use distinct::*;
 use enum_iterator::Sequence;
 use itertools::Itertools;
 pub use statsset::*;
@@ -11,9 +12,13 @@ use vortex_scalar::Scalar;
 
 use crate::Array;
 
+mod distinct;
 pub mod flatbuffers;
 mod statsset;
 
+/// The number of buckets of [`Stat::Histogram`].
+pub const HISTOGRAM_BUCKETS: usize = 32;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sequence)]
 pub enum Stat {
     BitWidthFreq,
@@ -26,6 +31,14 @@ pub enum Stat {
     RunCount,
     TrueCount,
     NullCount,
+    /// The number of NaN values of a floating point array.
+    NanCount,
+    /// A [`HyperLogLog`] sketch of the distinct non-null values.
+    DistinctCount,
+    /// An equi-depth histogram of the non-null values, as a list of [`HISTOGRAM_BUCKETS`] + 1
+    /// bounds. About the same number of values lie between each pair of consecutive bounds, the
+    /// first bound being the minimum and the last the maximum.
+    Histogram,
 }
 
 impl Display for Stat {
@@ -41,6 +54,9 @@ impl Display for Stat {
             Self::RunCount => write!(f, "run_count"),
             Self::TrueCount => write!(f, "true_count"),
             Self::NullCount => write!(f, "null_count"),
+            Self::NanCount => write!(f, "nan_count"),
+            Self::DistinctCount => write!(f, "distinct_count"),
+            Self::Histogram => write!(f, "histogram"),
         }
     }
 }
@@ -190,6 +206,22 @@ impl dyn Statistics + '_ {
     pub fn compute_trailing_zero_freq(&self) -> Option<Vec<usize>> {
         self.compute_as::<Vec<usize>>(Stat::TrailingZeroFreq)
     }
+
+    /// The estimated number of distinct non-null values.
+    pub fn compute_distinct_count(&self) -> Option<u64> {
+        self.compute_as::<HyperLogLog>(Stat::DistinctCount)
+            .map(|sketch| sketch.estimate())
+    }
+}
+
+/// The bounds of an equi-depth histogram of `sorted` values, see [`Stat::Histogram`].
+pub(crate) fn equi_depth_bounds<T: Clone>(sorted: &[T]) -> Vec<T> {
+    if sorted.is_empty() {
+        return Vec::new();
+    }
+    (0..=HISTOGRAM_BUCKETS)
+        .map(|bucket| sorted[bucket * (sorted.len() - 1) / HISTOGRAM_BUCKETS].clone())
+        .collect()
 }
 
 pub fn trailing_zeros(array: &Array) -> u8 {
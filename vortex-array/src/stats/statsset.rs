@@ -7,7 +7,7 @@ use vortex_dtype::DType;
 use vortex_error::VortexError;
 use vortex_scalar::Scalar;
 
-use crate::stats::Stat;
+use crate::stats::{HyperLogLog, Stat};
 
 #[derive(Debug, Clone, Default)]
 pub struct StatsSet {
@@ -38,6 +38,7 @@ impl StatsSet {
             (Stat::IsStrictSorted, (len < 2).into()),
             (Stat::RunCount, 1.into()),
             (Stat::NullCount, len.into()),
+            (Stat::DistinctCount, HyperLogLog::new().into()),
         ]);
 
         // Add any DType-specific stats.
@@ -46,7 +47,9 @@ impl StatsSet {
                 stats.insert(Stat::TrueCount, 0.into());
             }
             DType::Primitive(ptype, _) => {
-                ptype.byte_width();
+                if ptype.is_float() {
+                    stats.insert(Stat::NanCount, 0.into());
+                }
                 stats.insert(
                     Stat::BitWidthFreq,
                     vec![0; ptype.byte_width() * 8 + 1].into(),
@@ -100,6 +103,9 @@ impl StatsSet {
                 Stat::RunCount => self.merge_run_count(other),
                 Stat::TrueCount => self.merge_true_count(other),
                 Stat::NullCount => self.merge_null_count(other),
+                Stat::NanCount => self.merge_nan_count(other),
+                Stat::DistinctCount => self.merge_distinct_count(other),
+                Stat::Histogram => self.merge_histogram(),
             }
         }
 
@@ -181,6 +187,10 @@ impl StatsSet {
         self.merge_scalar_stat(other, Stat::NullCount)
     }
 
+    fn merge_nan_count(&mut self, other: &Self) {
+        self.merge_scalar_stat(other, Stat::NanCount)
+    }
+
     fn merge_scalar_stat(&mut self, other: &Self, stat: Stat) {
         if let Entry::Occupied(mut e) = self.values.entry(stat) {
             if let Some(other_value) = other.get_as::<usize>(stat) {
@@ -219,6 +229,24 @@ impl StatsSet {
         }
     }
 
+    fn merge_distinct_count(&mut self, other: &Self) {
+        if let Entry::Occupied(mut e) = self.values.entry(Stat::DistinctCount) {
+            if let Some(other_value) = other.get_as::<HyperLogLog>(Stat::DistinctCount) {
+                let mut self_value: HyperLogLog = e.get().try_into().unwrap();
+                self_value.merge(&other_value);
+                e.insert(self_value.into());
+            } else {
+                e.remove();
+            }
+        }
+    }
+
+    /// The bounds of two equi-depth histograms do not tell where the values of their union lie, so
+    /// histograms are dropped from merged stats.
+    fn merge_histogram(&mut self) {
+        self.values.remove(&Stat::Histogram);
+    }
+
     /// Merged run count is an upper bound where we assume run is interrupted at the boundary
     fn merge_run_count(&mut self, other: &Self) {
         if let Entry::Occupied(mut e) = self.values.entry(Stat::RunCount) {
@@ -252,7 +280,7 @@ impl IntoIterator for StatsSet {
 mod test {
     use itertools::Itertools;
 
-    use crate::stats::{Stat, StatsSet};
+    use crate::stats::{HyperLogLog, Stat, StatsSet};
 
     #[test]
     fn merge_into_min() {
@@ -342,6 +370,31 @@ mod test {
         assert_eq!(first.get(Stat::BitWidthFreq).cloned(), Some(vec_out.into()));
     }
 
+    #[test]
+    fn merge_distinct_counts() {
+        let sketch = |values: std::ops::Range<u32>| {
+            let mut sketch = HyperLogLog::new();
+            values.for_each(|v| sketch.insert(&v.to_le_bytes()));
+            sketch
+        };
+        let mut first = StatsSet::of(Stat::DistinctCount, sketch(0..100).into());
+        first.merge(&StatsSet::of(Stat::DistinctCount, sketch(50..200).into()));
+        assert_eq!(
+            first.get(Stat::DistinctCount).cloned(),
+            Some(sketch(0..200).into())
+        );
+
+        first.merge(&StatsSet::new());
+        assert_eq!(first.get(Stat::DistinctCount), None);
+    }
+
+    #[test]
+    fn merge_histograms() {
+        let mut first = StatsSet::of(Stat::Histogram, vec![1u64, 2, 3].into());
+        first.merge(&StatsSet::of(Stat::Histogram, vec![1u64, 2, 3].into()));
+        assert_eq!(first.get(Stat::Histogram), None);
+    }
+
     #[test]
     fn merge_into_sortedness() {
         let mut first = StatsSet::of(Stat::IsStrictSorted, true.into());
@@ -1,4 +1,5 @@
-use std::sync::{Arc, OnceLock};
+use std::marker::PhantomData;
+use std::sync::Arc;
 
 use vortex_buffer::Buffer;
 use vortex_dtype::DType;
@@ -10,7 +11,7 @@ use crate::{Array, ArrayData, ArrayDef, AsArray, IntoArray, ToArray, TryDeserial
 #[derive(Debug, Clone)]
 pub struct TypedArray<D: ArrayDef> {
     array: Array,
-    lazy_metadata: OnceLock<D::Metadata>,
+    phantom: PhantomData<D>,
 }
 
 impl<D: ArrayDef> TypedArray<D> {
@@ -33,37 +34,37 @@ impl<D: ArrayDef> TypedArray<D> {
         )?);
         Ok(Self {
             array,
-            lazy_metadata: OnceLock::new(),
+            phantom: PhantomData,
         })
     }
 
     pub fn metadata(&self) -> &D::Metadata {
-        match &self.array {
-            Array::Data(d) => d
-                .metadata()
-                .as_any()
-                .downcast_ref::<D::Metadata>()
-                .unwrap_or_else(|| {
+        let metadata = match &self.array {
+            Array::Data(d) => d.metadata(),
+            // Views cache the deserialized metadata, so that typed arrays created from the same
+            // view, e.g. once per compute function call, share it.
+            Array::View(v) => v.deserialized_metadata(|bytes| {
+                Arc::new(D::Metadata::try_deserialize_metadata(bytes).unwrap_or_else(|err| {
                     panic!(
-                        "Failed to downcast metadata to {} for typed array with ID {} and encoding {}",
-                        std::any::type_name::<D::Metadata>(),
+                        "Failed to deserialize ArrayView metadata for typed array with ID {} and encoding {}: {}",
                         D::ID.as_ref(),
                         D::ENCODING.id().as_ref(),
+                        err
                     )
-                }),
-            Array::View(v) => self
-                .lazy_metadata
-                .get_or_init(|| {
-                    D::Metadata::try_deserialize_metadata(v.metadata()).unwrap_or_else(|err| {
-                        panic!(
-                            "Failed to deserialize ArrayView metadata for typed array with ID {} and encoding {}: {}", 
-                            D::ID.as_ref(),
-                            D::ENCODING.id().as_ref(),
-                            err
-                        )
-                    })
-                }),
-        }
+                }))
+            }),
+        };
+        metadata
+            .as_any()
+            .downcast_ref::<D::Metadata>()
+            .unwrap_or_else(|| {
+                panic!(
+                    "Failed to downcast metadata to {} for typed array with ID {} and encoding {}",
+                    std::any::type_name::<D::Metadata>(),
+                    D::ID.as_ref(),
+                    D::ENCODING.id().as_ref(),
+                )
+            })
     }
 }
 
@@ -86,7 +87,7 @@ impl<D: ArrayDef> TryFrom<Array> for TypedArray<D> {
         }
         Ok(Self {
             array,
-            lazy_metadata: OnceLock::new(),
+            phantom: PhantomData,
         })
     }
 }
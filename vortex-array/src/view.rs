@@ -1,5 +1,5 @@
 use std::fmt::{Debug, Formatter};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use enum_iterator::all;
 use itertools::Itertools;
@@ -12,7 +12,7 @@ use vortex_scalar::{PValue, Scalar, ScalarValue};
 use crate::encoding::EncodingRef;
 use crate::stats::{Stat, Statistics, StatsSet};
 use crate::visitor::ArrayVisitor;
-use crate::{flatbuffers as fb, Array, Context, IntoArray, ToArray};
+use crate::{flatbuffers as fb, Array, ArrayMetadata, Context, IntoArray, ToArray};
 
 #[derive(Clone)]
 pub struct ArrayView {
@@ -24,6 +24,8 @@ pub struct ArrayView {
     // TODO(ngates): create an RC'd vector that can be lazily sliced.
     buffers: Vec<Buffer>,
     ctx: Arc<Context>,
+    /// The deserialized metadata, shared by clones of this view so that it is deserialized once.
+    metadata_cache: Arc<OnceLock<Arc<dyn ArrayMetadata>>>,
     // TODO(ngates): a store a Projection. A projected ArrayView contains the full fb::Array
     //  metadata, but only the buffers from the selected columns. Therefore we need to know
     //  which fb:Array children to skip when calculating how to slice into buffers.
@@ -74,6 +76,7 @@ impl ArrayView {
             flatbuffer_loc,
             buffers,
             ctx,
+            metadata_cache: Arc::default(),
         };
 
         // Validate here that the metadata correctly parses, so that an encoding can infallibly
@@ -111,6 +114,15 @@ impl ArrayView {
         self.flatbuffer().metadata().map(|m| m.bytes())
     }
 
+    /// The metadata deserialized by `deserialize` on first access.
+    pub(crate) fn deserialized_metadata(
+        &self,
+        deserialize: impl FnOnce(Option<&[u8]>) -> Arc<dyn ArrayMetadata>,
+    ) -> &Arc<dyn ArrayMetadata> {
+        self.metadata_cache
+            .get_or_init(|| deserialize(self.metadata()))
+    }
+
     // TODO(ngates): should we separate self and DType lifetimes? Should DType be cloned?
     pub fn child(&self, idx: usize, dtype: &DType, len: usize) -> Option<Self> {
         let child = self.array_child(idx)?;
@@ -119,14 +131,15 @@ impl ArrayView {
         let encoding = self.ctx.lookup_encoding(child.encoding())?;
 
         // Figure out how many buffers to skip...
-        // We store them depth-first.
-        let buffer_offset = self
-            .flatbuffer()
-            .children()?
-            .iter()
-            .take(idx)
-            .map(|child| Self::cumulative_nbuffers(child))
-            .sum();
+        // We store them depth-first, so the buffer of this array comes before those of its children.
+        let buffer_offset = usize::from(self.has_buffer())
+            + self
+                .flatbuffer()
+                .children()?
+                .iter()
+                .take(idx)
+                .map(|child| Self::cumulative_nbuffers(child))
+                .sum::<usize>();
         let buffer_count = Self::cumulative_nbuffers(child);
 
         Some(Self {
@@ -137,6 +150,7 @@ impl ArrayView {
             flatbuffer_loc,
             buffers: self.buffers[buffer_offset..][0..buffer_count].to_vec(),
             ctx: self.ctx.clone(),
+            metadata_cache: Arc::default(),
         })
     }
 
@@ -215,6 +229,7 @@ impl Statistics for ArrayView {
             Stat::RunCount => self.flatbuffer().stats()?.run_count().map(u64::into),
             Stat::TrueCount => self.flatbuffer().stats()?.true_count().map(u64::into),
             Stat::NullCount => self.flatbuffer().stats()?.null_count().map(u64::into),
+            Stat::NanCount => self.flatbuffer().stats()?.nan_count().map(u64::into),
             Stat::BitWidthFreq => self
                 .flatbuffer()
                 .stats()?
@@ -236,6 +251,25 @@ impl Statistics for ArrayView {
                 .trailing_zero_freq()
                 .map(|v| v.iter().collect_vec())
                 .map(|v| v.into()),
+            Stat::DistinctCount => self
+                .flatbuffer()
+                .stats()?
+                .distinct_count_sketch()
+                .map(|v| Scalar::binary(Buffer::from(v.bytes()), Nullability::NonNullable)),
+            Stat::Histogram => {
+                let histogram = self.flatbuffer().stats()?.histogram();
+                histogram
+                    .and_then(|v| ScalarValue::try_from(v).ok())
+                    .map(|v| {
+                        Scalar::new(
+                            DType::List(
+                                Arc::new(self.dtype.as_nonnullable()),
+                                Nullability::NonNullable,
+                            ),
+                            v,
+                        )
+                    })
+            }
         }
     }
 
@@ -283,3 +317,87 @@ impl IntoArray for ArrayView {
         Array::View(self)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use flatbuffers::{FlatBufferBuilder, WIPOffset};
+    use vortex_buffer::Buffer;
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::{Primitive, PrimitiveArray};
+    use crate::{
+        flatbuffers as fb, ArrayData, ArrayDef, ArrayView, Context, IntoArray, ToArray, TypedArray,
+    };
+
+    /// Write an array of primitive encodings with a buffer each, and the given children.
+    fn write_array<'fb>(
+        fbb: &mut FlatBufferBuilder<'fb>,
+        children: &[WIPOffset<fb::Array<'fb>>],
+    ) -> WIPOffset<fb::Array<'fb>> {
+        let metadata = ArrayData::from(PrimitiveArray::from(vec![0u8]).into_array())
+            .metadata()
+            .try_serialize_metadata()
+            .unwrap();
+        let metadata = Some(fbb.create_vector(metadata.as_ref()));
+        let children = Some(fbb.create_vector(children));
+        fb::Array::create(
+            fbb,
+            &fb::ArrayArgs {
+                version: Default::default(),
+                has_buffer: true,
+                encoding: Primitive::ENCODING.id().code(),
+                metadata,
+                stats: None,
+                children,
+            },
+        )
+    }
+
+    /// A view of `parent -> [first -> [grandchild], second]`, each with a buffer of its name.
+    fn nested_view() -> ArrayView {
+        let mut fbb = FlatBufferBuilder::new();
+        let grandchild = write_array(&mut fbb, &[]);
+        let first = write_array(&mut fbb, &[grandchild]);
+        let second = write_array(&mut fbb, &[]);
+        let parent = write_array(&mut fbb, &[first, second]);
+        fbb.finish_minimal(parent);
+        let flatbuffer = Buffer::from(fbb.finished_data().to_vec());
+
+        // Buffers are stored depth-first.
+        let buffers = ["parent", "first", "grandchild", "second"]
+            .into_iter()
+            .map(|name| Buffer::from(name.as_bytes()))
+            .collect();
+        ArrayView::try_new(
+            Arc::new(Context::default()),
+            DType::Primitive(PType::U8, Nullability::NonNullable),
+            1,
+            flatbuffer,
+            |bytes| Ok(flatbuffers::root::<fb::Array>(bytes)?),
+            buffers,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn child_buffers_follow_parent_buffer() {
+        let view = nested_view();
+        let dtype = view.dtype().clone();
+
+        let buffer = |view: &ArrayView| view.buffer().unwrap().as_ref().to_vec();
+        assert_eq!(buffer(&view), b"parent");
+        let first = view.child(0, &dtype, 1).unwrap();
+        assert_eq!(buffer(&first), b"first");
+        assert_eq!(buffer(&first.child(0, &dtype, 1).unwrap()), b"grandchild");
+        assert_eq!(buffer(&view.child(1, &dtype, 1).unwrap()), b"second");
+    }
+
+    #[test]
+    fn typed_arrays_share_view_metadata() {
+        let view = nested_view();
+        let typed = || TypedArray::<Primitive>::try_from(view.to_array()).unwrap();
+        assert!(std::ptr::eq(typed().metadata(), typed().metadata()));
+    }
+}
@@ -7,27 +7,43 @@
 //! logical type is encoded in its simplest corresponding Arrow type. This reflects the reality that
 //! most compute engines don't make use of the entire type range arrow-rs supports.
 //!
+//! Extension types without an Arrow equivalent are encoded as their storage type, with the
+//! extension recorded in the [Arrow extension type](https://arrow.apache.org/docs/format/Columnar.html#extension-types)
+//! metadata of their field.
+//!
 //! For this reason, it's recommended to do as much computation as possible within Vortex, and then
 //! materialize an Arrow ArrayRef at the very end of the processing chain.
 
+use std::collections::HashMap;
+
 use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, SchemaBuilder};
+use datafusion_common::{not_impl_err, plan_err, Result as DFResult};
+use vortex::arrow::decimal::decimal_precision_scale;
+use vortex::arrow::FromArrowType;
 use vortex_datetime_dtype::arrow::make_arrow_temporal_dtype;
 use vortex_datetime_dtype::is_temporal_ext_type;
-use vortex_dtype::{DType, Nullability, PType};
+use vortex_dtype::{DType, ExtDType, ExtID, ExtMetadata, Nullability, PType};
+
+/// Arrow field metadata key holding the name of an
+/// [extension type](https://arrow.apache.org/docs/format/Columnar.html#extension-types).
+pub(crate) const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+
+/// Arrow field metadata key holding the serialized metadata of an extension type.
+pub(crate) const EXTENSION_METADATA_KEY: &str = "ARROW:extension:metadata";
 
 /// Convert a Vortex [struct DType][DType] to an Arrow [Schema].
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function will panic if the provided `dtype` is not a StructDType, or if the struct DType
-/// has top-level nullability.
-pub(crate) fn infer_schema(dtype: &DType) -> Schema {
+/// Fails if the provided `dtype` is not a StructDType, if the struct DType has top-level
+/// nullability, or if any field has no Arrow equivalent.
+pub(crate) fn infer_schema(dtype: &DType) -> DFResult<Schema> {
     let DType::Struct(struct_dtype, nullable) = dtype else {
-        panic!("only DType::Struct can be converted to arrow schema");
+        return plan_err!("only DType::Struct can be converted to arrow schema");
     };
 
     if *nullable != Nullability::NonNullable {
-        panic!("top-level struct in Schema must be NonNullable");
+        return plan_err!("top-level struct in Schema must be NonNullable");
     }
 
     let mut builder = SchemaBuilder::with_capacity(struct_dtype.names().len());
@@ -36,18 +52,41 @@ pub(crate) fn infer_schema(dtype: &DType) -> Schema {
         .iter()
         .zip(struct_dtype.dtypes().iter())
     {
-        builder.push(FieldRef::from(Field::new(
-            field_name.to_string(),
-            infer_data_type(field_dtype),
-            field_dtype.is_nullable(),
-        )));
+        builder.push(FieldRef::from(infer_field(field_name, field_dtype)?));
+    }
+
+    Ok(builder.finish())
+}
+
+/// Convert a Vortex [DType] into an Arrow [Field] named `name`, annotated with the extension type
+/// of `dtype` if its Arrow type is the storage type of the extension.
+pub(crate) fn infer_field(name: &str, dtype: &DType) -> DFResult<Field> {
+    let field = Field::new(name, infer_data_type(dtype)?, dtype.is_nullable());
+    let DType::Extension(ext_dtype, _) = dtype else {
+        return Ok(field);
+    };
+    if is_temporal_ext_type(ext_dtype.id()) || decimal_precision_scale(ext_dtype).is_some() {
+        return Ok(field);
     }
 
-    builder.finish()
+    let mut metadata = HashMap::from([(
+        EXTENSION_NAME_KEY.to_string(),
+        ext_dtype.id().as_ref().to_string(),
+    )]);
+    if let Some(ext_metadata) = ext_dtype
+        .metadata()
+        .and_then(|m| std::str::from_utf8(m.as_ref()).ok())
+    {
+        metadata.insert(EXTENSION_METADATA_KEY.to_string(), ext_metadata.to_string());
+    }
+    Ok(field.with_metadata(metadata))
 }
 
-pub(crate) fn infer_data_type(dtype: &DType) -> DataType {
-    match dtype {
+/// Convert a Vortex [DType] into the Arrow [DataType] its arrays are exported as.
+///
+/// Fails for extension types that are neither known to Arrow nor record their storage type.
+pub(crate) fn infer_data_type(dtype: &DType) -> DFResult<DataType> {
+    Ok(match dtype {
         DType::Null => DataType::Null,
         DType::Bool(_) => DataType::Boolean,
         DType::Primitive(ptype, _) => match ptype {
@@ -72,66 +111,82 @@ pub(crate) fn infer_data_type(dtype: &DType) -> DataType {
                 .iter()
                 .zip(struct_dtype.dtypes().iter())
             {
-                fields.push(FieldRef::from(Field::new(
-                    field_name.to_string(),
-                    infer_data_type(field_dt),
-                    field_dt.is_nullable(),
-                )));
+                fields.push(FieldRef::from(infer_field(field_name, field_dt)?));
             }
 
             DataType::Struct(Fields::from(fields))
         }
-        DType::List(list_dt, _) => {
-            let dtype: &DType = list_dt;
-            DataType::List(FieldRef::from(Field::new(
-                "element",
-                infer_data_type(dtype),
-                dtype.is_nullable(),
-            )))
-        }
+        DType::List(list_dt, _) => DataType::List(FieldRef::from(infer_field("element", list_dt)?)),
         DType::Extension(ext_dtype, _) => {
             // Try and match against the known extension DTypes.
             if is_temporal_ext_type(ext_dtype.id()) {
                 make_arrow_temporal_dtype(ext_dtype)
+            } else if let Some((precision, scale)) = decimal_precision_scale(ext_dtype) {
+                DataType::Decimal128(precision, scale)
+            } else if let Some(storage_dtype) = ext_dtype.storage_dtype() {
+                infer_data_type(storage_dtype)?
             } else {
-                panic!("unsupported extension type \"{}\"", ext_dtype.id())
+                return not_impl_err!(
+                    "Extension type \"{}\" has no Arrow type nor a storage type",
+                    ext_dtype.id()
+                );
             }
         }
-    }
+    })
+}
+
+/// Extract the Vortex extension type of an Arrow field annotated with extension type metadata.
+///
+/// The Arrow data type of such a field is the storage type of the extension, which is recorded in
+/// the extension type so that [`infer_data_type`] can restore it.
+pub(crate) fn infer_extension_dtype(field: &Field) -> Option<ExtDType> {
+    let name = field.metadata().get(EXTENSION_NAME_KEY)?;
+    let metadata = field
+        .metadata()
+        .get(EXTENSION_METADATA_KEY)
+        .map(|m| ExtMetadata::from(m.as_bytes()));
+    let storage_field = field.clone().with_metadata(HashMap::new());
+
+    Some(
+        ExtDType::new(ExtID::from(name.as_str()), metadata)
+            .with_storage_dtype(DType::from_arrow(&storage_field)),
+    )
 }
 
 #[cfg(test)]
 mod test {
+    use std::collections::HashMap;
     use std::sync::Arc;
 
     use arrow_schema::{DataType, Field, FieldRef, Fields, Schema};
+    use vortex::arrow::FromArrowType;
     use vortex_dtype::{
-        DType, ExtDType, ExtID, FieldName, FieldNames, Nullability, PType, StructDType,
+        DType, ExtDType, ExtID, ExtMetadata, FieldName, FieldNames, Nullability, PType, StructDType,
     };
 
     use super::*;
 
     #[test]
     fn test_dtype_conversion_success() {
-        assert_eq!(infer_data_type(&DType::Null), DataType::Null);
+        assert_eq!(infer_data_type(&DType::Null).unwrap(), DataType::Null);
 
         assert_eq!(
-            infer_data_type(&DType::Bool(Nullability::NonNullable)),
+            infer_data_type(&DType::Bool(Nullability::NonNullable)).unwrap(),
             DataType::Boolean
         );
 
         assert_eq!(
-            infer_data_type(&DType::Primitive(PType::U64, Nullability::NonNullable)),
+            infer_data_type(&DType::Primitive(PType::U64, Nullability::NonNullable)).unwrap(),
             DataType::UInt64
         );
 
         assert_eq!(
-            infer_data_type(&DType::Utf8(Nullability::NonNullable)),
+            infer_data_type(&DType::Utf8(Nullability::NonNullable)).unwrap(),
             DataType::Utf8
         );
 
         assert_eq!(
-            infer_data_type(&DType::Binary(Nullability::NonNullable)),
+            infer_data_type(&DType::Binary(Nullability::NonNullable)).unwrap(),
             DataType::Binary
         );
 
@@ -139,7 +194,8 @@ mod test {
             infer_data_type(&DType::List(
                 Arc::new(DType::Bool(Nullability::NonNullable)),
                 Nullability::Nullable,
-            )),
+            ))
+            .unwrap(),
             DataType::List(FieldRef::from(Field::new(
                 "element".to_string(),
                 DataType::Boolean,
@@ -154,7 +210,8 @@ mod test {
                     vec![DType::Bool(false.into()), DType::Utf8(true.into())],
                 ),
                 Nullability::NonNullable,
-            )),
+            ))
+            .unwrap(),
             DataType::Struct(Fields::from(vec![
                 FieldRef::from(Field::new("field_a", DataType::Boolean, false)),
                 FieldRef::from(Field::new("field_b", DataType::Utf8, true)),
@@ -163,12 +220,80 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
-    fn test_dtype_conversion_panics() {
-        let _ = infer_data_type(&DType::Extension(
+    fn test_list_round_trip() {
+        let dtype = DType::List(
+            Arc::new(DType::Struct(
+                StructDType::new(
+                    FieldNames::from(vec![FieldName::from("ts"), FieldName::from("tags")]),
+                    vec![
+                        DType::Primitive(PType::I64, Nullability::NonNullable),
+                        DType::List(
+                            Arc::new(DType::Utf8(Nullability::Nullable)),
+                            Nullability::Nullable,
+                        ),
+                    ],
+                ),
+                Nullability::Nullable,
+            )),
+            Nullability::Nullable,
+        );
+
+        let field = infer_field("events", &dtype).unwrap();
+        assert_eq!(DType::from_arrow(&field), dtype);
+    }
+
+    #[test]
+    fn test_infer_extension_dtype() {
+        let field = Field::new("ip", DataType::UInt32, true).with_metadata(HashMap::from([
+            (EXTENSION_NAME_KEY.to_string(), "example.ipv4".to_string()),
+            (EXTENSION_METADATA_KEY.to_string(), "v1".to_string()),
+        ]));
+
+        assert_eq!(
+            infer_extension_dtype(&field),
+            Some(
+                ExtDType::new(
+                    ExtID::from("example.ipv4"),
+                    Some(ExtMetadata::from("v1".as_bytes()))
+                )
+                .with_storage_dtype(DType::Primitive(PType::U32, Nullability::Nullable))
+            )
+        );
+        assert_eq!(
+            infer_extension_dtype(&Field::new("ip", DataType::UInt32, true)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_unknown_extension_fails() {
+        assert!(infer_data_type(&DType::Extension(
             ExtDType::new(ExtID::from("my-fake-ext-dtype"), None),
             Nullability::NonNullable,
-        ));
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn test_extension_round_trip() {
+        let field = Field::new("ip", DataType::UInt32, true).with_metadata(HashMap::from([
+            (EXTENSION_NAME_KEY.to_string(), "example.ipv4".to_string()),
+            (EXTENSION_METADATA_KEY.to_string(), "v1".to_string()),
+        ]));
+        let dtype = DType::Extension(
+            infer_extension_dtype(&field).unwrap(),
+            Nullability::Nullable,
+        );
+
+        assert_eq!(infer_field("ip", &dtype).unwrap(), field);
+    }
+
+    #[test]
+    fn test_decimal_round_trip() {
+        let field = Field::new("price", DataType::Decimal128(10, 2), true);
+        let dtype = DType::from_arrow(&field);
+
+        assert_eq!(infer_field("price", &dtype).unwrap(), field);
     }
 
     #[test]
@@ -177,7 +302,7 @@ mod test {
         let schema_nonnull = DType::Struct(struct_dtype.clone(), Nullability::NonNullable);
 
         assert_eq!(
-            infer_schema(&schema_nonnull),
+            infer_schema(&schema_nonnull).unwrap(),
             Schema::new(Fields::from(vec![
                 Field::new("field_a", DataType::Boolean, false),
                 Field::new("field_b", DataType::Utf8, false),
@@ -187,11 +312,10 @@ mod test {
     }
 
     #[test]
-    #[should_panic]
-    fn test_schema_conversion_panics() {
+    fn test_schema_conversion_fails() {
         let struct_dtype = the_struct();
         let schema_null = DType::Struct(struct_dtype.clone(), Nullability::Nullable);
-        let _ = infer_schema(&schema_null);
+        assert!(infer_schema(&schema_null).is_err());
     }
 
     fn the_struct() -> StructDType {
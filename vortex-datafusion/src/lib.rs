@@ -9,13 +9,15 @@ use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use arrow_array::RecordBatch;
-use arrow_schema::{DataType, Schema, SchemaRef};
+use arrow_schema::{DataType, FieldRef, Schema, SchemaRef};
 use datafusion::execution::{RecordBatchStream, SendableRecordBatchStream, TaskContext};
 use datafusion::prelude::{DataFrame, SessionContext};
-use datafusion_common::{exec_datafusion_err, DataFusionError, Result as DFResult};
+use datafusion_common::{exec_datafusion_err, DataFusionError, Result as DFResult, ScalarValue};
 use datafusion_execution::object_store::ObjectStoreUrl;
-use datafusion_expr::{Expr, Operator};
+use datafusion_expr::utils::{conjunction, split_conjunction};
+use datafusion_expr::{Expr, Operator, TableProviderFilterPushDown};
 use datafusion_physical_plan::{DisplayAs, DisplayFormatType, ExecutionPlan, PlanProperties};
+use datatype::EXTENSION_NAME_KEY;
 use futures::Stream;
 use memory::{VortexMemTable, VortexMemTableOptions};
 use persistent::config::VortexTableOptions;
@@ -109,7 +111,7 @@ impl SessionContextExt for SessionContext {
             .into());
         }
 
-        let vortex_table = VortexMemTable::new(array, options);
+        let vortex_table = VortexMemTable::new(array, options)?;
         self.register_table(name.as_ref(), Arc::new(vortex_table))
             .map(|_| ())
     }
@@ -127,7 +129,7 @@ impl SessionContextExt for SessionContext {
             .into());
         }
 
-        let vortex_table = VortexMemTable::new(array, options);
+        let vortex_table = VortexMemTable::new(array, options)?;
 
         self.read_table(Arc::new(vortex_table))
     }
@@ -154,6 +156,35 @@ impl SessionContextExt for SessionContext {
     }
 }
 
+/// Decide how much of `expr` a Vortex scan can evaluate.
+///
+/// Filters that can be fully evaluated by Vortex are pushed down as `Exact`. Conjunctions where
+/// only some of the terms are supported are pushed down as `Inexact`, the scan evaluates the
+/// supported terms and DataFusion re-applies the whole filter on the result.
+fn filter_pushdown(expr: &Expr, schema: &Schema) -> TableProviderFilterPushDown {
+    if can_be_pushed_down(expr, schema) {
+        TableProviderFilterPushDown::Exact
+    } else if split_conjunction(expr)
+        .into_iter()
+        .any(|term| can_be_pushed_down(term, schema))
+    {
+        TableProviderFilterPushDown::Inexact
+    } else {
+        TableProviderFilterPushDown::Unsupported
+    }
+}
+
+/// Combine the terms of `filters` that can be evaluated by Vortex into a single conjunction.
+fn pushdown_conjunction(filters: &[Expr], schema: &Schema) -> Option<Expr> {
+    conjunction(
+        filters
+            .iter()
+            .flat_map(split_conjunction)
+            .filter(|term| can_be_pushed_down(term, schema))
+            .cloned(),
+    )
+}
+
 fn can_be_pushed_down(expr: &Expr, schema: &Schema) -> bool {
     match expr {
         Expr::BinaryExpr(expr)
@@ -162,15 +193,46 @@ fn can_be_pushed_down(expr: &Expr, schema: &Schema) -> bool {
             can_be_pushed_down(expr.left.as_ref(), schema)
                 & can_be_pushed_down(expr.right.as_ref(), schema)
         }
-        Expr::Column(col) => match schema.column_with_name(col.name()) {
-            Some((_, field)) => supported_data_types(field.data_type().clone()),
-            _ => false,
-        },
+        // Vortex evaluates `LIKE` with `\` as the escape character, as DataFusion does by default.
+        Expr::Like(like)
+            if !like.negated
+                && !like.case_insensitive
+                && matches!(like.escape_char, None | Some('\\')) =>
+        {
+            matches!(
+                like.pattern.as_ref(),
+                Expr::Literal(ScalarValue::Utf8(Some(_)))
+            ) && nested_field(like.expr.as_ref(), schema)
+                .is_some_and(|field| field.data_type() == &DataType::Utf8)
+        }
+        Expr::Column(_) | Expr::ScalarFunction(_) => {
+            nested_field(expr, schema).is_some_and(|field| {
+                !field.metadata().contains_key(EXTENSION_NAME_KEY)
+                    && supported_data_types(field.data_type().clone())
+            })
+        }
         Expr::Literal(lit) => supported_data_types(lit.data_type()),
         _ => false,
     }
 }
 
+/// Resolve a column, or a chain of `get_field` calls on a struct column, to the accessed field.
+fn nested_field(expr: &Expr, schema: &Schema) -> Option<FieldRef> {
+    match expr {
+        Expr::Column(col) => schema.fields().find(col.name()).map(|(_, f)| f.clone()),
+        Expr::ScalarFunction(func) if func.name() == "get_field" => {
+            let [child, Expr::Literal(ScalarValue::Utf8(Some(name)))] = func.args.as_slice() else {
+                return None;
+            };
+            match nested_field(child, schema)?.data_type() {
+                DataType::Struct(fields) => fields.find(name).map(|(_, f)| f.clone()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 /// Physical plan node for scans against an in-memory, possibly chunked Vortex Array.
 #[derive(Clone)]
 struct VortexScanExec {
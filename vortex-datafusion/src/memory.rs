@@ -7,11 +7,9 @@ use datafusion::catalog::Session;
 use datafusion::datasource::TableProvider;
 use datafusion::prelude::*;
 use datafusion_common::{Result as DFResult, ToDFSchema};
-use datafusion_expr::utils::conjunction;
 use datafusion_expr::{TableProviderFilterPushDown, TableType};
 use datafusion_physical_expr::{create_physical_expr, EquivalenceProperties};
 use datafusion_physical_plan::{ExecutionMode, ExecutionPlan, Partitioning, PlanProperties};
-use itertools::Itertools;
 use vortex::array::ChunkedArray;
 use vortex::{Array, ArrayDType as _};
 use vortex_expr::datafusion::convert_expr_to_vortex;
@@ -19,7 +17,7 @@ use vortex_expr::VortexExpr;
 
 use crate::datatype::infer_schema;
 use crate::plans::{RowSelectorExec, TakeRowsExec};
-use crate::{can_be_pushed_down, VortexScanExec};
+use crate::{filter_pushdown, pushdown_conjunction, VortexScanExec};
 
 /// A [`TableProvider`] that exposes an existing Vortex Array to the DataFusion SQL engine.
 ///
@@ -35,26 +33,25 @@ pub struct VortexMemTable {
 impl VortexMemTable {
     /// Build a new table provider from an existing [struct type](vortex_dtype::StructDType) array.
     ///
-    /// # Panics
-    ///
-    /// Creation will panic if the provided array is not of `DType::Struct` type.
-    pub fn new(array: Array, options: VortexMemTableOptions) -> Self {
-        let arrow_schema = infer_schema(array.dtype());
+    /// Fails if the provided array is not of `DType::Struct` type, or if a column of the array has
+    /// no Arrow type, see [`infer_data_type`](crate::datatype::infer_data_type).
+    pub fn new(array: Array, options: VortexMemTableOptions) -> DFResult<Self> {
+        let arrow_schema = infer_schema(array.dtype())?;
         let schema_ref = SchemaRef::new(arrow_schema);
 
         let array = match ChunkedArray::try_from(&array) {
             Ok(a) => a,
             _ => {
                 let dtype = array.dtype().clone();
-                ChunkedArray::try_new(vec![array], dtype).unwrap()
+                ChunkedArray::try_new(vec![array], dtype)?
             }
         };
 
-        Self {
+        Ok(Self {
             array,
             schema_ref,
             options,
-        }
+        })
     }
 }
 
@@ -88,7 +85,7 @@ impl TableProvider for VortexMemTable {
             Some(proj) => proj.clone(),
         };
 
-        match conjunction(filters.to_vec()) {
+        match pushdown_conjunction(filters, self.schema_ref.as_ref()) {
             // If there is a filter expression, we execute in two phases, first performing a filter
             // on the input to get back row indices, and then taking the remaining struct columns
             // using the calculated indices from the filter.
@@ -145,16 +142,10 @@ impl TableProvider for VortexMemTable {
                 .collect());
         }
 
-        filters
+        Ok(filters
             .iter()
-            .map(|expr| {
-                if can_be_pushed_down(expr, self.schema().as_ref()) {
-                    Ok(TableProviderFilterPushDown::Exact)
-                } else {
-                    Ok(TableProviderFilterPushDown::Unsupported)
-                }
-            })
-            .try_collect()
+            .map(|expr| filter_pushdown(expr, self.schema().as_ref()))
+            .collect())
     }
 }
 
@@ -205,20 +196,25 @@ fn make_filter_then_take_plan(
 
 #[cfg(test)]
 mod test {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
     use arrow_array::cast::AsArray as _;
-    use arrow_array::types::Int64Type;
-    use arrow_schema::{DataType, Field, Schema};
+    use arrow_array::types::{Int64Type, UInt16Type};
+    use arrow_schema::{DataType, Field, Fields, Schema};
+    use datafusion::functions::core::expr_fn::get_field;
     use datafusion::functions_aggregate::count::count_distinct;
     use datafusion::prelude::SessionContext;
     use datafusion_common::{Column, TableReference};
-    use datafusion_expr::{and, col, lit, BinaryExpr, Expr, Operator};
+    use datafusion_expr::{and, col, lit, BinaryExpr, Expr, Operator, TableProviderFilterPushDown};
     use vortex::array::{PrimitiveArray, StructArray, VarBinArray};
     use vortex::validity::Validity;
     use vortex::{Array, IntoArray};
     use vortex_dtype::{DType, Nullability};
 
+    use crate::datatype::EXTENSION_NAME_KEY;
     use crate::memory::VortexMemTableOptions;
-    use crate::{can_be_pushed_down, SessionContextExt as _};
+    use crate::{can_be_pushed_down, filter_pushdown, SessionContextExt as _};
 
     fn presidents_array() -> Array {
         let names = VarBinArray::from_vec(
@@ -273,6 +269,31 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_datafusion_like_pushdown() {
+        let ctx = SessionContext::new();
+
+        let df = ctx.read_mem_vortex(presidents_array()).unwrap();
+
+        let terms = df
+            .filter(col("president").like(lit("%a_s%")))
+            .unwrap()
+            .select(vec![col("term_start")])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            terms
+                .iter()
+                .flat_map(|b| b.column(0).as_primitive::<UInt16Type>().values().to_vec())
+                .collect::<Vec<_>>(),
+            vec![1797, 1825]
+        );
+    }
+
     #[tokio::test]
     #[cfg_attr(miri, ignore)]
     async fn test_datafusion_no_pushdown() {
@@ -374,4 +395,153 @@ mod test {
             ])
         ));
     }
+
+    #[test]
+    fn test_filter_pushdown() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::UInt64, true),
+            Field::new("b", DataType::UInt64, true),
+        ]);
+        let supported = col("a").eq(lit(2u64));
+        let unsupported = (col("b") % lit(5u64)).eq(lit(0u64));
+
+        assert_eq!(
+            filter_pushdown(&supported, &schema),
+            TableProviderFilterPushDown::Exact
+        );
+        assert_eq!(
+            filter_pushdown(&unsupported, &schema),
+            TableProviderFilterPushDown::Unsupported
+        );
+        assert_eq!(
+            filter_pushdown(&and(supported.clone(), unsupported.clone()), &schema),
+            TableProviderFilterPushDown::Inexact
+        );
+        assert_eq!(
+            filter_pushdown(&supported.or(unsupported), &schema),
+            TableProviderFilterPushDown::Unsupported
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_datafusion_inexact_pushdown() {
+        let ctx = SessionContext::new();
+
+        let df = ctx.read_mem_vortex(presidents_array()).unwrap();
+
+        let distinct_names = df
+            .filter(and(
+                col("term_start").gt_eq(lit(1795)),
+                (col("term_start") % lit(2)).eq(lit(1)),
+            ))
+            .unwrap()
+            .aggregate(vec![], vec![count_distinct(col("president"))])
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *distinct_names[0]
+                .column(0)
+                .as_primitive::<Int64Type>()
+                .values()
+                .first()
+                .unwrap(),
+            4i64
+        );
+    }
+
+    #[test]
+    fn test_can_be_pushed_down_nested() {
+        let schema = Schema::new(vec![
+            Field::new(
+                "event",
+                DataType::Struct(Fields::from(vec![
+                    Field::new("ts", DataType::Int64, false),
+                    Field::new(
+                        "tags",
+                        DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+                        true,
+                    ),
+                ])),
+                false,
+            ),
+            Field::new("ip", DataType::UInt32, true).with_metadata(HashMap::from([(
+                EXTENSION_NAME_KEY.to_string(),
+                "example.ipv4".to_string(),
+            )])),
+        ]);
+
+        assert!(can_be_pushed_down(
+            &get_field(col("event"), "ts").gt(lit(5i64)),
+            &schema
+        ));
+        assert!(!can_be_pushed_down(
+            &get_field(col("event"), "tags").is_not_null(),
+            &schema
+        ));
+        assert!(!can_be_pushed_down(
+            &get_field(col("event"), "missing").eq(lit(5i64)),
+            &schema
+        ));
+        assert!(!can_be_pushed_down(&col("ip").eq(lit(1u32)), &schema));
+    }
+
+    #[test]
+    fn test_can_be_pushed_down_like() {
+        let schema = Schema::new(vec![
+            Field::new("name", DataType::Utf8, true),
+            Field::new("id", DataType::Int64, true),
+        ]);
+
+        assert!(can_be_pushed_down(&col("name").like(lit("a%")), &schema));
+        assert!(!can_be_pushed_down(
+            &col("name").not_like(lit("a%")),
+            &schema
+        ));
+        assert!(!can_be_pushed_down(&col("name").ilike(lit("a%")), &schema));
+        assert!(!can_be_pushed_down(&col("name").like(col("name")), &schema));
+        assert!(!can_be_pushed_down(&col("id").like(lit("1%")), &schema));
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_datafusion_nested_pushdown() {
+        let ctx = SessionContext::new();
+
+        let array = StructArray::from_fields(&[
+            ("president", presidents_array()),
+            (
+                "order",
+                PrimitiveArray::from_vec(vec![1u8, 2, 3, 4, 5, 6], Validity::NonNullable)
+                    .into_array(),
+            ),
+        ])
+        .into_array();
+        let df = ctx.read_mem_vortex(array).unwrap();
+
+        let distinct_names = df
+            .filter(get_field(col("president"), "term_start").gt_eq(lit(1795)))
+            .unwrap()
+            .aggregate(
+                vec![],
+                vec![count_distinct(get_field(col("president"), "president"))],
+            )
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *distinct_names[0]
+                .column(0)
+                .as_primitive::<Int64Type>()
+                .values()
+                .first()
+                .unwrap(),
+            4i64
+        );
+    }
 }
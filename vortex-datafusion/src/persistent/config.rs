@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use arrow_schema::SchemaRef;
 use chrono::TimeZone as _;
-use datafusion::datasource::listing::PartitionedFile;
+use datafusion::datasource::listing::{ListingTableUrl, PartitionedFile};
 use object_store::path::Path;
 use object_store::ObjectMeta;
 use vortex::Context;
@@ -37,6 +37,7 @@ pub struct VortexTableOptions {
     pub(crate) data_files: Vec<VortexFile>,
     pub(crate) schema: Option<SchemaRef>,
     pub(crate) ctx: Arc<Context>,
+    pub(crate) insert_path: Option<ListingTableUrl>,
 }
 
 impl VortexTableOptions {
@@ -45,6 +46,13 @@ impl VortexTableOptions {
             data_files,
             schema: Some(schema),
             ctx,
+            insert_path: None,
         }
     }
+
+    /// Directory that receives new files written through `INSERT INTO`.
+    pub fn with_insert_path(mut self, insert_path: ListingTableUrl) -> Self {
+        self.insert_path = Some(insert_path);
+        self
+    }
 }
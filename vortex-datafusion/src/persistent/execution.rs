@@ -5,7 +5,7 @@ use datafusion::datasource::physical_plan::{FileScanConfig, FileStream};
 use datafusion_common::{project_schema, Result as DFResult};
 use datafusion_execution::{SendableRecordBatchStream, TaskContext};
 use datafusion_physical_expr::{EquivalenceProperties, Partitioning, PhysicalExpr};
-use datafusion_physical_plan::metrics::ExecutionPlanMetricsSet;
+use datafusion_physical_plan::metrics::{ExecutionPlanMetricsSet, MetricsSet};
 use datafusion_physical_plan::{
     DisplayAs, DisplayFormatType, ExecutionMode, ExecutionPlan, PlanProperties,
 };
@@ -76,6 +76,10 @@ impl ExecutionPlan for VortexExec {
         vec![]
     }
 
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+
     fn with_new_children(
         self: Arc<Self>,
         _children: Vec<Arc<dyn ExecutionPlan>>,
@@ -101,6 +105,8 @@ impl ExecutionPlan for VortexExec {
             batch_size: None,
             predicate: self.predicate.clone(),
             arrow_schema,
+            metrics: self.metrics.clone(),
+            partition,
         };
         let stream = FileStream::new(&self.file_scan_config, partition, opener, &self.metrics)?;
 
@@ -0,0 +1,429 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_schema::{Schema, SchemaRef};
+use async_trait::async_trait;
+use datafusion::datasource::file_format::file_compression_type::FileCompressionType;
+use datafusion::datasource::file_format::{FileFormat, FileFormatFactory};
+use datafusion::datasource::physical_plan::{FileScanConfig, FileSinkConfig};
+use datafusion::execution::context::SessionState;
+use datafusion_common::{not_impl_err, plan_err, GetExt, Result as DFResult, Statistics};
+use datafusion_physical_expr::{PhysicalExpr, PhysicalSortRequirement};
+use datafusion_physical_plan::insert::DataSinkExec;
+use datafusion_physical_plan::metrics::ExecutionPlanMetricsSet;
+use datafusion_physical_plan::ExecutionPlan;
+use object_store::{ObjectMeta, ObjectStore};
+use vortex::compress::CompressionStrategy as _;
+use vortex::Context;
+use vortex_dtype::{DType, Nullability};
+use vortex_error::vortex_err;
+use vortex_sampling_compressor::SamplingCompressor;
+use vortex_serde::io::ObjectStoreReadAt;
+use vortex_serde::layouts::{LayoutContext, LayoutDeserializer, LayoutReaderBuilder};
+
+use crate::datatype::infer_schema;
+use crate::persistent::execution::VortexExec;
+use crate::persistent::sink::{VortexSink, VORTEX_EXTENSION};
+
+/// DataFusion [`FileFormat`] for reading and writing Vortex files.
+///
+/// Registering the matching [`VortexFormatFactory`] with a session enables
+/// `COPY ... TO 'out.vortex'` statements.
+#[derive(Debug, Clone)]
+pub struct VortexFormat {
+    context: Arc<Context>,
+}
+
+impl Default for VortexFormat {
+    fn default() -> Self {
+        Self::new(Arc::new(default_context()))
+    }
+}
+
+impl VortexFormat {
+    pub fn new(context: Arc<Context>) -> Self {
+        Self { context }
+    }
+}
+
+#[async_trait]
+impl FileFormat for VortexFormat {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_ext(&self) -> String {
+        VORTEX_EXTENSION.to_string()
+    }
+
+    fn get_ext_with_compression(
+        &self,
+        file_compression_type: &FileCompressionType,
+    ) -> DFResult<String> {
+        if file_compression_type.is_compressed() {
+            return not_impl_err!("Vortex files are compressed internally");
+        }
+        Ok(self.get_ext())
+    }
+
+    async fn infer_schema(
+        &self,
+        _state: &SessionState,
+        store: &Arc<dyn ObjectStore>,
+        objects: &[ObjectMeta],
+    ) -> DFResult<SchemaRef> {
+        let mut schemas = Vec::with_capacity(objects.len());
+        for object in objects {
+            let stream = LayoutReaderBuilder::new(
+                ObjectStoreReadAt::new(store.clone(), object.location.clone()),
+                LayoutDeserializer::new(self.context.clone(), Arc::new(LayoutContext::default())),
+            )
+            .with_length(object.size as u64)
+            .build()
+            .await?;
+
+            let schema = stream.schema();
+            let DType::Struct(struct_dtype, _) = schema.dtype() else {
+                return Err(vortex_err!(
+                    "Vortex file {} does not have a struct schema",
+                    object.location
+                )
+                .into());
+            };
+            schemas.push(infer_schema(&DType::Struct(
+                struct_dtype.clone(),
+                Nullability::NonNullable,
+            ))?);
+        }
+
+        Ok(Arc::new(Schema::try_merge(schemas)?))
+    }
+
+    async fn infer_stats(
+        &self,
+        _state: &SessionState,
+        _store: &Arc<dyn ObjectStore>,
+        table_schema: SchemaRef,
+        _object: &ObjectMeta,
+    ) -> DFResult<Statistics> {
+        Ok(Statistics::new_unknown(&table_schema))
+    }
+
+    async fn create_physical_plan(
+        &self,
+        _state: &SessionState,
+        conf: FileScanConfig,
+        filters: Option<&Arc<dyn PhysicalExpr>>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        let projection = conf.projection.clone();
+        let exec = VortexExec::try_new(
+            conf,
+            ExecutionPlanMetricsSet::new(),
+            projection.as_ref(),
+            filters.cloned(),
+            self.context.clone(),
+        )?;
+
+        Ok(exec.into_arc())
+    }
+
+    async fn create_writer_physical_plan(
+        &self,
+        input: Arc<dyn ExecutionPlan>,
+        _state: &SessionState,
+        conf: FileSinkConfig,
+        order_requirements: Option<Vec<PhysicalSortRequirement>>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        if conf.overwrite {
+            return plan_err!("Overwrites are not supported for Vortex files");
+        }
+
+        if !conf.table_partition_cols.is_empty() {
+            return plan_err!("Partitioned writes are not supported for Vortex files");
+        }
+
+        let sink_schema = conf.output_schema().clone();
+        let sink = Arc::new(VortexSink::new(conf));
+
+        Ok(Arc::new(DataSinkExec::new(
+            input,
+            sink,
+            sink_schema,
+            order_requirements,
+        )) as _)
+    }
+}
+
+/// [`FileFormatFactory`] for [`VortexFormat`].
+///
+/// ```ignore
+/// ctx.state_ref()
+///     .write()
+///     .register_file_format(Arc::new(VortexFormatFactory::default()), false)?;
+/// ```
+#[derive(Debug, Default)]
+pub struct VortexFormatFactory {
+    format: VortexFormat,
+}
+
+impl VortexFormatFactory {
+    pub fn new(context: Arc<Context>) -> Self {
+        Self {
+            format: VortexFormat::new(context),
+        }
+    }
+}
+
+impl GetExt for VortexFormatFactory {
+    fn get_ext(&self) -> String {
+        VORTEX_EXTENSION.to_string()
+    }
+}
+
+impl FileFormatFactory for VortexFormatFactory {
+    fn create(
+        &self,
+        _state: &SessionState,
+        format_options: &HashMap<String, String>,
+    ) -> DFResult<Arc<dyn FileFormat>> {
+        if !format_options.is_empty() {
+            return not_impl_err!("Vortex format does not accept any options");
+        }
+        Ok(Arc::new(self.format.clone()))
+    }
+
+    fn default(&self) -> Arc<dyn FileFormat> {
+        Arc::new(self.format.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A [`Context`] that can decode every encoding produced by the default [`SamplingCompressor`].
+pub(crate) fn default_context() -> Context {
+    Context::default().with_encodings(SamplingCompressor::default().used_encodings())
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray as _;
+    use arrow_array::types::{Int64Type, UInt64Type};
+    use arrow_schema::{DataType, Field, Schema};
+    use datafusion::datasource::listing::ListingTableUrl;
+    use datafusion::datasource::provider::DefaultTableFactory;
+    use datafusion::prelude::SessionContext;
+    use datafusion_common::DataFusionError;
+    use datafusion_execution::object_store::ObjectStoreUrl;
+    use object_store::path::Path;
+    use tempfile::tempdir;
+
+    use crate::persistent::config::{VortexFile, VortexTableOptions};
+    use crate::persistent::format::{default_context, VortexFormatFactory};
+    use crate::SessionContextExt as _;
+
+    async fn count_rows(ctx: &SessionContext, query: &str) -> u64 {
+        let batches = ctx.sql(query).await.unwrap().collect().await.unwrap();
+        let count = batches[0].column(0);
+        match count.data_type() {
+            DataType::Int64 => count.as_primitive::<Int64Type>().value(0) as u64,
+            _ => count.as_primitive::<UInt64Type>().value(0),
+        }
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_copy_to_vortex() {
+        let temp_dir = tempdir().unwrap();
+        let filepath = temp_dir.path().join("out.vortex");
+
+        let ctx = SessionContext::new();
+        ctx.state_ref()
+            .write()
+            .register_file_format(Arc::new(VortexFormatFactory::default()), false)
+            .unwrap();
+
+        let written = count_rows(
+            &ctx,
+            &format!(
+                "COPY (SELECT * FROM (VALUES (1, 'a'), (2, 'b'), (3, 'c')) AS t(id, name)) TO '{}'",
+                filepath.display()
+            ),
+        )
+        .await;
+        assert_eq!(written, 3);
+
+        let file_size = std::fs::metadata(&filepath).unwrap().len();
+        ctx.register_disk_vortex_opts(
+            "copied",
+            ObjectStoreUrl::local_filesystem(),
+            VortexTableOptions::new(
+                Arc::new(Schema::new(vec![
+                    Field::new("id", DataType::Int64, true),
+                    Field::new("name", DataType::Utf8, true),
+                ])),
+                vec![VortexFile::new(
+                    Path::from_filesystem_path(&filepath).unwrap(),
+                    file_size,
+                )],
+                Arc::new(default_context()),
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(
+            count_rows(&ctx, "SELECT COUNT(name) FROM copied WHERE id > 1").await,
+            2
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_copy_to_directory_keeps_existing_files() {
+        let temp_dir = tempdir().unwrap();
+
+        // Each session starts without knowledge of the files already in the directory.
+        for _ in 0..2 {
+            let ctx = SessionContext::new();
+            ctx.state_ref()
+                .write()
+                .register_file_format(Arc::new(VortexFormatFactory::default()), false)
+                .unwrap();
+
+            let written = count_rows(
+                &ctx,
+                &format!(
+                    "COPY (SELECT * FROM (VALUES (1), (2)) AS t(n)) TO '{}/' STORED AS VORTEX",
+                    temp_dir.path().display()
+                ),
+            )
+            .await;
+            assert_eq!(written, 2);
+        }
+
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 2);
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_copy_decimals() {
+        let temp_dir = tempdir().unwrap();
+
+        let ctx = SessionContext::new();
+        ctx.state_ref()
+            .write()
+            .register_file_format(Arc::new(VortexFormatFactory::default()), false)
+            .unwrap();
+        ctx.state_ref()
+            .write()
+            .table_factories_mut()
+            .insert("VORTEX".to_string(), Arc::new(DefaultTableFactory::new()));
+
+        let written = count_rows(
+            &ctx,
+            &format!(
+                "COPY (SELECT CAST(n AS DECIMAL(10, 2)) / 4 AS price FROM (VALUES (1), (2), (3)) AS t(n)) TO '{}/' STORED AS VORTEX",
+                temp_dir.path().display()
+            ),
+        )
+        .await;
+        assert_eq!(written, 3);
+
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE prices STORED AS VORTEX LOCATION '{}/'",
+            temp_dir.path().display()
+        ))
+        .await
+        .unwrap();
+        let batches = ctx
+            .sql("SELECT CAST(SUM(price) AS VARCHAR) FROM prices")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+        assert_eq!(batches[0].column(0).as_string::<i32>().value(0), "1.500000");
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_insert_into_vortex() {
+        let temp_dir = tempdir().unwrap();
+
+        let ctx = SessionContext::new();
+        ctx.register_disk_vortex_opts(
+            "numbers",
+            ObjectStoreUrl::local_filesystem(),
+            VortexTableOptions::new(
+                Arc::new(Schema::new(vec![Field::new("n", DataType::Int64, true)])),
+                vec![],
+                Arc::new(default_context()),
+            )
+            .with_insert_path(
+                ListingTableUrl::parse(format!("{}/", temp_dir.path().display())).unwrap(),
+            ),
+        )
+        .unwrap();
+
+        for _ in 0..2 {
+            let inserted = count_rows(
+                &ctx,
+                "INSERT INTO numbers SELECT * FROM (VALUES (1), (2), (3), (4)) AS t(n)",
+            )
+            .await;
+            assert_eq!(inserted, 4);
+        }
+
+        assert_eq!(
+            count_rows(&ctx, "SELECT COUNT(n) FROM numbers WHERE n >= 3").await,
+            4
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_reject_unsupported_inserts_at_planning() {
+        let temp_dir = tempdir().unwrap();
+
+        let ctx = SessionContext::new();
+        ctx.state_ref()
+            .write()
+            .register_file_format(Arc::new(VortexFormatFactory::default()), false)
+            .unwrap();
+        ctx.state_ref()
+            .write()
+            .table_factories_mut()
+            .insert("VORTEX".to_string(), Arc::new(DefaultTableFactory::new()));
+
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE numbers (n BIGINT, p INT) STORED AS VORTEX LOCATION '{}/numbers/'",
+            temp_dir.path().display()
+        ))
+        .await
+        .unwrap();
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE parts (n BIGINT, p INT) STORED AS VORTEX PARTITIONED BY (p) LOCATION '{}/parts/'",
+            temp_dir.path().display()
+        ))
+        .await
+        .unwrap();
+
+        for query in [
+            "INSERT OVERWRITE numbers VALUES (1, 1)",
+            "INSERT INTO parts VALUES (1, 1)",
+        ] {
+            let err = ctx
+                .sql(query)
+                .await
+                .unwrap()
+                .create_physical_plan()
+                .await
+                .unwrap_err();
+            assert!(matches!(err, DataFusionError::Plan(_)), "{query}: {err}");
+        }
+    }
+}
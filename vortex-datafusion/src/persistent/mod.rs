@@ -1,4 +1,7 @@
 pub mod config;
 pub mod execution;
+pub mod format;
 pub mod opener;
 pub mod provider;
+pub mod sink;
+mod statistics;
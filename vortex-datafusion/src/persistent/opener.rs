@@ -3,17 +3,21 @@ use std::sync::Arc;
 use arrow_array::RecordBatch;
 use arrow_schema::SchemaRef;
 use datafusion::datasource::physical_plan::{FileMeta, FileOpenFuture, FileOpener};
+use datafusion::physical_optimizer::pruning::PruningPredicate;
 use datafusion_common::Result as DFResult;
 use datafusion_physical_expr::PhysicalExpr;
-use futures::{FutureExt as _, TryStreamExt};
+use datafusion_physical_plan::metrics::{Count, ExecutionPlanMetricsSet, MetricBuilder, Time};
+use futures::{stream, FutureExt as _, StreamExt as _};
 use object_store::ObjectStore;
 use vortex::Context;
 use vortex_expr::datafusion::convert_expr_to_vortex;
 use vortex_serde::io::ObjectStoreReadAt;
 use vortex_serde::layouts::{
-    LayoutContext, LayoutDeserializer, LayoutReaderBuilder, Projection, RowFilter,
+    LayoutContext, LayoutDeserializer, LayoutReaderBuilder, Projection, ReadMetrics, RowFilter,
 };
 
+use crate::persistent::statistics::ChunkStatistics;
+
 pub struct VortexFileOpener {
     pub ctx: Arc<Context>,
     pub object_store: Arc<dyn ObjectStore>,
@@ -21,6 +25,8 @@ pub struct VortexFileOpener {
     pub projection: Option<Vec<usize>>,
     pub predicate: Option<Arc<dyn PhysicalExpr>>,
     pub arrow_schema: SchemaRef,
+    pub metrics: ExecutionPlanMetricsSet,
+    pub partition: usize,
 }
 
 impl FileOpener for VortexFileOpener {
@@ -31,7 +37,8 @@ impl FileOpener for VortexFileOpener {
         let mut builder = LayoutReaderBuilder::new(
             read_at,
             LayoutDeserializer::new(self.ctx.clone(), Arc::new(LayoutContext::default())),
-        );
+        )
+        .with_length(file_meta.object_meta.size as u64);
 
         if let Some(batch_size) = self.batch_size {
             builder = builder.with_batch_size(batch_size);
@@ -50,15 +57,123 @@ impl FileOpener for VortexFileOpener {
             builder = builder.with_projection(Projection::new(projection))
         }
 
-        Ok(async {
-            Ok(Box::pin(
-                builder
-                    .build()
-                    .await?
-                    .map_ok(RecordBatch::from)
-                    .map_err(|e| e.into()),
-            ) as _)
+        let read_metrics = ReadMetrics::default();
+        builder = builder.with_metrics(read_metrics.clone());
+        let scan_metrics = FileScanMetrics::new(read_metrics, &self.metrics, self.partition);
+
+        let pruning_predicate = self
+            .predicate
+            .clone()
+            .map(|predicate| PruningPredicate::try_new(predicate, self.arrow_schema.clone()))
+            .transpose()?
+            .filter(|p| !p.always_true());
+        let chunks_pruned =
+            MetricBuilder::new(&self.metrics).counter("chunks_pruned", self.partition);
+        let arrow_schema = self.arrow_schema.clone();
+
+        Ok(async move {
+            if let Some(pruning_predicate) = pruning_predicate {
+                match prune_chunks(
+                    &mut builder,
+                    &pruning_predicate,
+                    arrow_schema,
+                    &chunks_pruned,
+                )
+                .await?
+                {
+                    ChunkSelection::All => {}
+                    ChunkSelection::None => return Ok(stream::empty().boxed()),
+                    ChunkSelection::Chunks(keep) => builder = builder.with_chunks(keep),
+                }
+            }
+
+            Ok(Box::pin(builder.build().await?.map(move |batch| {
+                // Keep the metrics alive for as long as the stream, they're published on drop.
+                let _ = &scan_metrics;
+                batch.map(RecordBatch::from).map_err(|e| e.into())
+            })) as _)
         }
         .boxed())
     }
 }
+
+/// DataFusion metrics of a single file scan.
+///
+/// The [`ReadMetrics`] of the file reader are published into the plan metrics once the scan is
+/// dropped, which also covers scans that are cut short, e.g. by a `LIMIT`.
+struct FileScanMetrics {
+    read_metrics: ReadMetrics,
+    bytes_read: Count,
+    read_requests: Count,
+    batches_decoded: Count,
+    chunks_decoded: Count,
+    chunks_skipped: Count,
+    rows_filtered: Count,
+    io_time: Time,
+    decode_time: Time,
+}
+
+impl FileScanMetrics {
+    fn new(read_metrics: ReadMetrics, metrics: &ExecutionPlanMetricsSet, partition: usize) -> Self {
+        Self {
+            read_metrics,
+            bytes_read: MetricBuilder::new(metrics).counter("bytes_read", partition),
+            read_requests: MetricBuilder::new(metrics).counter("read_requests", partition),
+            batches_decoded: MetricBuilder::new(metrics).counter("batches_decoded", partition),
+            chunks_decoded: MetricBuilder::new(metrics).counter("chunks_decoded", partition),
+            chunks_skipped: MetricBuilder::new(metrics).counter("chunks_skipped", partition),
+            rows_filtered: MetricBuilder::new(metrics).counter("rows_filtered", partition),
+            io_time: MetricBuilder::new(metrics).subset_time("io_time", partition),
+            decode_time: MetricBuilder::new(metrics).subset_time("decode_time", partition),
+        }
+    }
+}
+
+impl Drop for FileScanMetrics {
+    fn drop(&mut self) {
+        self.bytes_read.add(self.read_metrics.bytes_read() as usize);
+        self.read_requests
+            .add(self.read_metrics.read_requests() as usize);
+        self.batches_decoded
+            .add(self.read_metrics.batches_decoded() as usize);
+        self.chunks_decoded
+            .add(self.read_metrics.chunks_decoded() as usize);
+        self.chunks_skipped
+            .add(self.read_metrics.chunks_skipped() as usize);
+        self.rows_filtered
+            .add(self.read_metrics.rows_filtered() as usize);
+        self.io_time.add_duration(self.read_metrics.io_time());
+        self.decode_time
+            .add_duration(self.read_metrics.decode_time());
+    }
+}
+
+/// Chunks of a file that can contain rows matching the pruning predicate.
+enum ChunkSelection {
+    All,
+    None,
+    Chunks(Vec<bool>),
+}
+
+async fn prune_chunks(
+    builder: &mut LayoutReaderBuilder<ObjectStoreReadAt>,
+    pruning_predicate: &PruningPredicate,
+    arrow_schema: SchemaRef,
+    chunks_pruned: &Count,
+) -> DFResult<ChunkSelection> {
+    let metadata = builder.read_metadata().await?;
+    let Some(statistics) = ChunkStatistics::try_new(&metadata, arrow_schema) else {
+        return Ok(ChunkSelection::All);
+    };
+    let keep = pruning_predicate.prune(&statistics)?;
+    let pruned = keep.iter().filter(|k| !**k).count();
+    chunks_pruned.add(pruned);
+
+    Ok(if pruned == 0 {
+        ChunkSelection::All
+    } else if pruned == keep.len() {
+        ChunkSelection::None
+    } else {
+        ChunkSelection::Chunks(keep)
+    })
+}
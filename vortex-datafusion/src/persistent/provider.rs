@@ -1,30 +1,38 @@
 use std::any::Any;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 use arrow_schema::SchemaRef;
 use async_trait::async_trait;
 use datafusion::catalog::Session;
-use datafusion::datasource::physical_plan::FileScanConfig;
+use datafusion::datasource::physical_plan::{FileScanConfig, FileSinkConfig};
 use datafusion::datasource::TableProvider;
+use datafusion::physical_optimizer::pruning::PruningPredicate;
 use datafusion_common::{
-    project_schema, DataFusionError, Result as DFResult, Statistics, ToDFSchema,
+    internal_datafusion_err, plan_err, project_schema, DataFusionError, Result as DFResult,
+    SchemaExt, Statistics, ToDFSchema,
 };
 use datafusion_execution::object_store::ObjectStoreUrl;
-use datafusion_expr::utils::conjunction;
 use datafusion_expr::{Expr, TableProviderFilterPushDown, TableType};
+use datafusion_physical_expr::PhysicalExpr;
 use datafusion_physical_plan::empty::EmptyExec;
-use datafusion_physical_plan::metrics::ExecutionPlanMetricsSet;
+use datafusion_physical_plan::insert::DataSinkExec;
+use datafusion_physical_plan::metrics::{ExecutionPlanMetricsSet, MetricBuilder};
 use datafusion_physical_plan::ExecutionPlan;
-use itertools::Itertools;
+use vortex_serde::io::ObjectStoreReadAt;
+use vortex_serde::layouts::{LayoutContext, LayoutDeserializer, LayoutReaderBuilder};
 
-use super::config::VortexTableOptions;
-use crate::can_be_pushed_down;
+use super::config::{VortexFile, VortexTableOptions};
 use crate::persistent::execution::VortexExec;
+use crate::persistent::sink::VortexSink;
+use crate::persistent::statistics::FileStatistics;
+use crate::{filter_pushdown, pushdown_conjunction};
 
 pub struct VortexFileTableProvider {
     schema_ref: SchemaRef,
     object_store_url: ObjectStoreUrl,
     config: VortexTableOptions,
+    /// Files backing the table, including any written through `INSERT INTO`.
+    data_files: Arc<RwLock<Vec<VortexFile>>>,
 }
 
 impl VortexFileTableProvider {
@@ -35,9 +43,60 @@ impl VortexFileTableProvider {
                 .clone()
                 .ok_or_else(|| DataFusionError::Configuration("Missing schema".to_string()))?,
             object_store_url,
+            data_files: Arc::new(RwLock::new(config.data_files.clone())),
             config,
         })
     }
+
+    /// Drop the files whose statistics show that no row can match `predicate`.
+    async fn prune_files(
+        &self,
+        state: &dyn Session,
+        predicate: &Arc<dyn PhysicalExpr>,
+        data_files: Vec<VortexFile>,
+        metrics: &ExecutionPlanMetricsSet,
+    ) -> DFResult<Vec<VortexFile>> {
+        let pruning_predicate = PruningPredicate::try_new(predicate.clone(), self.schema())?;
+        if pruning_predicate.always_true() {
+            return Ok(data_files);
+        }
+
+        let object_store = state.runtime_env().object_store(&self.object_store_url)?;
+        let mut file_metadata = Vec::with_capacity(data_files.len());
+        for file in data_files.iter() {
+            let metadata = LayoutReaderBuilder::new(
+                ObjectStoreReadAt::new(object_store.clone(), file.object_meta.location.clone()),
+                LayoutDeserializer::new(
+                    self.config.ctx.clone(),
+                    Arc::new(LayoutContext::default()),
+                ),
+            )
+            .with_length(file.object_meta.size as u64)
+            .read_metadata()
+            .await?;
+            file_metadata.push(metadata);
+        }
+
+        let keep = pruning_predicate.prune(&FileStatistics::new(&file_metadata, self.schema()))?;
+        let kept = data_files
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(file, keep)| keep.then_some(file))
+            .collect::<Vec<_>>();
+
+        MetricBuilder::new(metrics)
+            .global_counter("files_pruned")
+            .add(file_metadata.len() - kept.len());
+
+        Ok(kept)
+    }
+
+    fn data_files(&self) -> DFResult<Vec<VortexFile>> {
+        self.data_files
+            .read()
+            .map(|files| files.clone())
+            .map_err(|_| internal_datafusion_err!("data files lock poisoned"))
+    }
 }
 
 #[async_trait]
@@ -61,29 +120,31 @@ impl TableProvider for VortexFileTableProvider {
         filters: &[Expr],
         _limit: Option<usize>,
     ) -> DFResult<Arc<dyn ExecutionPlan>> {
-        if self.config.data_files.is_empty() {
+        let data_files = self.data_files()?;
+        if data_files.is_empty() {
             let projected_schema = project_schema(&self.schema(), projection)?;
             return Ok(Arc::new(EmptyExec::new(projected_schema)));
         }
 
         let df_schema = self.schema().to_dfschema()?;
-        let predicate = conjunction(filters.to_vec());
+        let predicate = pushdown_conjunction(filters, self.schema().as_ref());
         let predicate = predicate
             .map(|predicate| state.create_physical_expr(predicate, &df_schema))
             .transpose()?;
 
         let metrics = ExecutionPlanMetricsSet::new();
 
+        let data_files = match predicate.as_ref() {
+            Some(predicate) => {
+                self.prune_files(state, predicate, data_files, &metrics)
+                    .await?
+            }
+            None => data_files,
+        };
+
         // TODO: Point at some files and/or ranges
         let file_scan_config = FileScanConfig::new(self.object_store_url.clone(), self.schema())
-            .with_file_group(
-                self.config
-                    .data_files
-                    .iter()
-                    .cloned()
-                    .map(|f| f.into())
-                    .collect(),
-            )
+            .with_file_group(data_files.into_iter().map(|f| f.into()).collect())
             .with_projection(projection.cloned());
 
         let exec = VortexExec::try_new(
@@ -102,19 +163,159 @@ impl TableProvider for VortexFileTableProvider {
         &self,
         filters: &[&Expr],
     ) -> DFResult<Vec<TableProviderFilterPushDown>> {
-        filters
+        Ok(filters
             .iter()
-            .map(|expr| {
-                if can_be_pushed_down(expr, self.schema().as_ref()) {
-                    Ok(TableProviderFilterPushDown::Exact)
-                } else {
-                    Ok(TableProviderFilterPushDown::Unsupported)
-                }
-            })
-            .try_collect()
+            .map(|expr| filter_pushdown(expr, self.schema().as_ref()))
+            .collect())
     }
 
     fn statistics(&self) -> Option<Statistics> {
         None
     }
+
+    async fn insert_into(
+        &self,
+        _state: &dyn Session,
+        input: Arc<dyn ExecutionPlan>,
+        overwrite: bool,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        if !self
+            .schema()
+            .logically_equivalent_names_and_types(&input.schema())
+        {
+            return plan_err!("Inserting query must have the same schema with the table.");
+        }
+
+        if overwrite {
+            return plan_err!("Overwrites are not supported for Vortex tables");
+        }
+
+        let Some(insert_path) = self.config.insert_path.clone() else {
+            return plan_err!("Vortex table has no insert path configured");
+        };
+
+        let sink_config = FileSinkConfig {
+            object_store_url: self.object_store_url.clone(),
+            file_groups: vec![],
+            table_paths: vec![insert_path],
+            output_schema: self.schema(),
+            table_partition_cols: vec![],
+            overwrite,
+            keep_partition_by_columns: false,
+        };
+        let sink = VortexSink::new(sink_config).with_written_files(self.data_files.clone());
+
+        Ok(Arc::new(DataSinkExec::new(
+            input,
+            Arc::new(sink),
+            self.schema(),
+            None,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path as StdPath;
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray as _;
+    use arrow_array::types::Int64Type;
+    use arrow_schema::{DataType, Field, Schema};
+    use datafusion::prelude::SessionContext;
+    use datafusion_execution::object_store::ObjectStoreUrl;
+    use datafusion_physical_plan::{collect, ExecutionPlan};
+    use object_store::path::Path;
+    use tempfile::tempdir;
+    use vortex::array::{ChunkedArray, PrimitiveArray, StructArray};
+    use vortex::IntoArray;
+    use vortex_serde::layouts::LayoutWriter;
+
+    use crate::persistent::config::{VortexFile, VortexTableOptions};
+    use crate::persistent::execution::VortexExec;
+    use crate::SessionContextExt as _;
+
+    async fn write_file(path: &StdPath, chunks: Vec<Vec<i64>>) -> VortexFile {
+        let numbers = ChunkedArray::from_iter(
+            chunks
+                .into_iter()
+                .map(|chunk| PrimitiveArray::from(chunk).into_array()),
+        )
+        .into_array();
+        let st = StructArray::from_fields(&[("n", numbers)]);
+
+        let written = LayoutWriter::new(Vec::new())
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap()
+            .finalize()
+            .await
+            .unwrap();
+        std::fs::write(path, &written).unwrap();
+
+        VortexFile::new(
+            Path::from_filesystem_path(path).unwrap(),
+            written.len() as u64,
+        )
+    }
+
+    fn find_vortex_exec(plan: &Arc<dyn ExecutionPlan>) -> Option<&VortexExec> {
+        plan.as_any()
+            .downcast_ref::<VortexExec>()
+            .or_else(|| plan.children().into_iter().find_map(find_vortex_exec))
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_prune_files_and_chunks_metrics() {
+        let temp_dir = tempdir().unwrap();
+        let files = vec![
+            write_file(
+                &temp_dir.path().join("a.vortex"),
+                vec![vec![1, 2, 3], vec![4, 5, 6]],
+            )
+            .await,
+            write_file(&temp_dir.path().join("b.vortex"), vec![vec![10, 11, 12]]).await,
+        ];
+
+        let ctx = SessionContext::new();
+        ctx.register_disk_vortex_opts(
+            "numbers",
+            ObjectStoreUrl::local_filesystem(),
+            VortexTableOptions::new(
+                Arc::new(Schema::new(vec![Field::new("n", DataType::Int64, false)])),
+                files,
+                Arc::new(Default::default()),
+            ),
+        )
+        .unwrap();
+
+        let plan = ctx
+            .sql("SELECT n FROM numbers WHERE n >= 5 AND n <= 6")
+            .await
+            .unwrap()
+            .create_physical_plan()
+            .await
+            .unwrap();
+        let batches = collect(plan.clone(), ctx.task_ctx()).await.unwrap();
+
+        let values = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int64Type>().values().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![5, 6]);
+
+        let metrics = find_vortex_exec(&plan).unwrap().metrics().unwrap();
+        assert_eq!(metrics.sum_by_name("files_pruned").unwrap().as_usize(), 1);
+        assert_eq!(metrics.sum_by_name("chunks_pruned").unwrap().as_usize(), 1);
+        assert_eq!(
+            metrics.sum_by_name("batches_decoded").unwrap().as_usize(),
+            1
+        );
+        assert_eq!(metrics.sum_by_name("chunks_decoded").unwrap().as_usize(), 1);
+        assert_eq!(metrics.sum_by_name("chunks_skipped").unwrap().as_usize(), 1);
+        assert_eq!(metrics.sum_by_name("rows_filtered").unwrap().as_usize(), 1);
+        assert!(metrics.sum_by_name("bytes_read").unwrap().as_usize() > 0);
+        assert!(metrics.sum_by_name("read_requests").unwrap().as_usize() > 0);
+    }
 }
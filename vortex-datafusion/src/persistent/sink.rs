@@ -0,0 +1,226 @@
+use std::any::Any;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use arrow_array::RecordBatch;
+use arrow_schema::DataType;
+use async_trait::async_trait;
+use datafusion::datasource::physical_plan::FileSinkConfig;
+use datafusion_common::{
+    internal_datafusion_err, not_impl_err, DataFusionError, Result as DFResult,
+};
+use datafusion_execution::{SendableRecordBatchStream, TaskContext};
+use datafusion_physical_plan::insert::DataSink;
+use datafusion_physical_plan::metrics::{ExecutionPlanMetricsSet, MetricBuilder, MetricsSet};
+use datafusion_physical_plan::{DisplayAs, DisplayFormatType};
+use futures::TryStreamExt;
+use object_store::path::Path;
+use object_store::WriteMultipart;
+use uuid::Uuid;
+use vortex::array::{ExtensionArray, StructArray};
+use vortex::arrow::decimal::MAX_DECIMAL_PRECISION;
+use vortex::arrow::FromArrowArray;
+use vortex::stats::{ArrayStatistics, Stat};
+use vortex::validity::Validity;
+use vortex::{Array, IntoArray};
+use vortex_sampling_compressor::SamplingCompressor;
+use vortex_serde::io::{ObjectStoreWriter, VortexWrite};
+use vortex_serde::layouts::LayoutWriter;
+
+use crate::datatype::infer_extension_dtype;
+use crate::persistent::config::VortexFile;
+
+/// File extension used for Vortex files written by DataFusion.
+pub const VORTEX_EXTENSION: &str = "vortex";
+
+const WRITE_CHUNK_SIZE: usize = 10 * 1024 * 1024;
+
+/// A [`DataSink`] that writes a stream of [`RecordBatch`]es into a single Vortex file.
+///
+/// Every incoming batch is compressed with the default [`SamplingCompressor`] and appended as a
+/// new chunk of each column through a [`LayoutWriter`].
+pub struct VortexSink {
+    config: FileSinkConfig,
+    compressor: SamplingCompressor<'static>,
+    written_files: Option<Arc<RwLock<Vec<VortexFile>>>>,
+    metrics: ExecutionPlanMetricsSet,
+}
+
+impl VortexSink {
+    pub fn new(config: FileSinkConfig) -> Self {
+        Self {
+            config,
+            compressor: SamplingCompressor::default(),
+            written_files: None,
+            metrics: ExecutionPlanMetricsSet::new(),
+        }
+    }
+
+    /// Register every file written by this sink into `files`, so that a table provider can pick
+    /// up the newly written data.
+    pub(crate) fn with_written_files(mut self, files: Arc<RwLock<Vec<VortexFile>>>) -> Self {
+        self.written_files = Some(files);
+        self
+    }
+
+    /// Resolve the location of the output file.
+    ///
+    /// Table paths that point at a single file are written directly, directories receive a new
+    /// `part-<uuid>.vortex` file so that repeated writes never replace existing data.
+    fn output_path(&self) -> DFResult<Path> {
+        let table_path = self
+            .config
+            .table_paths
+            .first()
+            .ok_or_else(|| internal_datafusion_err!("VortexSink requires an output path"))?;
+
+        if !table_path.is_collection() {
+            return Ok(table_path.prefix().clone());
+        }
+
+        Ok(table_path
+            .prefix()
+            .child(format!("part-{}.{VORTEX_EXTENSION}", Uuid::new_v4())))
+    }
+}
+
+impl fmt::Debug for VortexSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VortexSink")
+            .field("table_paths", &self.config.table_paths)
+            .finish()
+    }
+}
+
+impl DisplayAs for VortexSink {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter) -> fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(f, "VortexSink(file_groups=")?;
+                for (idx, path) in self.config.table_paths.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", path.as_str())?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl DataSink for VortexSink {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+
+    async fn write_all(
+        &self,
+        mut data: SendableRecordBatchStream,
+        context: &Arc<TaskContext>,
+    ) -> DFResult<u64> {
+        let object_store = context
+            .runtime_env()
+            .object_store(&self.config.object_store_url)?;
+        let location = self.output_path()?;
+        let output_rows = MetricBuilder::new(&self.metrics).output_rows(0);
+        let bytes_written = MetricBuilder::new(&self.metrics).counter("bytes_written", 0);
+
+        let mut writer =
+            LayoutWriter::new(ObjectStoreWriter::new(WriteMultipart::new_with_chunk_size(
+                object_store.put_multipart(&location).await?,
+                WRITE_CHUNK_SIZE,
+            )));
+        let mut row_count = 0u64;
+        let mut wrote_batch = false;
+
+        while let Some(batch) = data.try_next().await? {
+            row_count += batch.num_rows() as u64;
+            output_rows.add(batch.num_rows());
+            let compressed = self
+                .compressor
+                .compress(&record_batch_to_array(batch)?, None)?
+                .into_array();
+            writer = writer.write_array_columns(compressed).await?;
+            wrote_batch = true;
+        }
+
+        // The file always carries a schema, even when the input produced no rows.
+        if !wrote_batch {
+            let empty = RecordBatch::new_empty(self.config.output_schema().clone());
+            writer = writer
+                .write_array_columns(record_batch_to_array(empty)?)
+                .await?;
+        }
+
+        let mut write = writer.finalize().await?;
+        write.shutdown().await?;
+
+        let size = object_store.head(&location).await?.size;
+        bytes_written.add(size);
+
+        if let Some(files) = self.written_files.as_ref() {
+            files
+                .write()
+                .map_err(|_| internal_datafusion_err!("written files lock poisoned"))?
+                .push(VortexFile::new(location.clone(), size as u64));
+        }
+
+        Ok(row_count)
+    }
+}
+
+/// Convert a [`RecordBatch`] into a non-nullable Vortex struct array, matching the top-level
+/// schema expected by [`infer_schema`](crate::datatype::infer_schema).
+///
+/// Columns annotated with Arrow extension type metadata are stored as Vortex extension arrays.
+/// The chunk statistics stored by the [`LayoutWriter`] are computed here, as the compressed
+/// encodings cannot compute them and the compressor carries them over.
+fn record_batch_to_array(batch: RecordBatch) -> DFResult<Array> {
+    let schema = batch.schema();
+    for field in schema.fields() {
+        if let DataType::Decimal128(precision, _) = field.data_type() {
+            if *precision > MAX_DECIMAL_PRECISION {
+                return not_impl_err!(
+                    "Column \"{}\" has decimal precision {}, the maximum supported is {}",
+                    field.name(),
+                    precision,
+                    MAX_DECIMAL_PRECISION
+                );
+            }
+        }
+    }
+    StructArray::try_new(
+        schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str().into())
+            .collect::<Vec<_>>()
+            .into(),
+        batch
+            .columns()
+            .iter()
+            .zip(schema.fields())
+            .map(|(array, field)| {
+                let array = Array::from_arrow(array.clone(), field.is_nullable());
+                let array = match infer_extension_dtype(field) {
+                    Some(ext_dtype) => ExtensionArray::new(ext_dtype, array).into_array(),
+                    None => array,
+                };
+                for stat in [Stat::Min, Stat::Max, Stat::NullCount] {
+                    array.statistics().compute(stat);
+                }
+                array
+            })
+            .collect(),
+        batch.num_rows(),
+        Validity::NonNullable,
+    )
+    .map(IntoArray::into_array)
+    .map_err(DataFusionError::from)
+}
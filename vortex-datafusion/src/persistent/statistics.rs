@@ -0,0 +1,225 @@
+//! [`PruningStatistics`] over the chunk metadata tables of Vortex files.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use arrow_array::{ArrayRef, BooleanArray};
+use arrow_schema::{DataType, SchemaRef};
+use datafusion::arrow::compute::cast;
+use datafusion::physical_optimizer::pruning::PruningStatistics;
+use datafusion_common::{Column, ScalarValue};
+use vortex::IntoCanonical;
+use vortex_dtype::DType;
+use vortex_serde::layouts::FileMetadata;
+
+const MIN: &str = "min";
+const MAX: &str = "max";
+const NULL_COUNT: &str = "null_count";
+const ROW_COUNT: &str = "row_count";
+const ROW_OFFSET: &str = "row_offset";
+
+/// Statistics where every container is a single chunk of one Vortex file.
+///
+/// Chunks are only comparable across columns when every column was chunked at the same row
+/// offsets, see [`ChunkStatistics::try_new`].
+pub(crate) struct ChunkStatistics<'a> {
+    metadata: &'a FileMetadata,
+    schema: SchemaRef,
+    num_chunks: usize,
+}
+
+impl<'a> ChunkStatistics<'a> {
+    /// Build chunk statistics for `metadata`, returning `None` if the columns of the file are not
+    /// chunked uniformly.
+    pub(crate) fn try_new(metadata: &'a FileMetadata, schema: SchemaRef) -> Option<Self> {
+        let DType::Struct(struct_dtype, _) = metadata.dtype() else {
+            return None;
+        };
+
+        let mut row_offsets: Option<ArrayRef> = None;
+        for idx in 0..struct_dtype.names().len() {
+            let offsets = metadata
+                .column_statistic(idx, ROW_OFFSET)?
+                .into_canonical()
+                .ok()?
+                .into_arrow();
+            match &row_offsets {
+                None => row_offsets = Some(offsets),
+                Some(expected) if expected == &offsets => {}
+                Some(_) => return None,
+            }
+        }
+
+        Some(Self {
+            metadata,
+            schema,
+            num_chunks: row_offsets?.len(),
+        })
+    }
+
+    fn column_statistic(&self, column: &Column, name: &str) -> Option<ArrayRef> {
+        let DType::Struct(struct_dtype, _) = self.metadata.dtype() else {
+            return None;
+        };
+        let idx = struct_dtype.find_name(column.name())?;
+        let data_type = match name {
+            MIN | MAX => self
+                .schema
+                .field_with_name(column.name())
+                .ok()?
+                .data_type()
+                .clone(),
+            _ => DataType::UInt64,
+        };
+
+        self.column_statistic_by_idx(idx, name, &data_type)
+    }
+
+    fn column_statistic_by_idx(
+        &self,
+        idx: usize,
+        name: &str,
+        data_type: &DataType,
+    ) -> Option<ArrayRef> {
+        let array = self
+            .metadata
+            .column_statistic(idx, name)?
+            .into_canonical()
+            .ok()?
+            .into_arrow();
+
+        cast(&array, data_type).ok()
+    }
+}
+
+impl PruningStatistics for ChunkStatistics<'_> {
+    fn min_values(&self, column: &Column) -> Option<ArrayRef> {
+        self.column_statistic(column, MIN)
+    }
+
+    fn max_values(&self, column: &Column) -> Option<ArrayRef> {
+        self.column_statistic(column, MAX)
+    }
+
+    fn num_containers(&self) -> usize {
+        self.num_chunks
+    }
+
+    fn null_counts(&self, column: &Column) -> Option<ArrayRef> {
+        self.column_statistic(column, NULL_COUNT)
+    }
+
+    fn row_counts(&self, column: &Column) -> Option<ArrayRef> {
+        self.column_statistic(column, ROW_COUNT)
+    }
+
+    fn contained(&self, _column: &Column, _values: &HashSet<ScalarValue>) -> Option<BooleanArray> {
+        None
+    }
+}
+
+/// Statistics where every container is a whole Vortex file, aggregated from its chunks.
+pub(crate) struct FileStatistics<'a> {
+    files: &'a [FileMetadata],
+    schema: SchemaRef,
+}
+
+impl<'a> FileStatistics<'a> {
+    pub(crate) fn new(files: &'a [FileMetadata], schema: SchemaRef) -> Self {
+        Self { files, schema }
+    }
+
+    /// Aggregate the chunk statistic `name` of `column` for every file with `combine`.
+    ///
+    /// A file whose statistic is unknown for any of its chunks has an unknown aggregate.
+    fn aggregate(
+        &self,
+        column: &Column,
+        name: &str,
+        combine: impl Fn(ScalarValue, ScalarValue) -> Option<ScalarValue>,
+    ) -> Option<ArrayRef> {
+        let data_type = match name {
+            MIN | MAX => self
+                .schema
+                .field_with_name(column.name())
+                .ok()?
+                .data_type()
+                .clone(),
+            _ => DataType::UInt64,
+        };
+        let unknown = ScalarValue::try_from(&data_type).ok()?;
+
+        let values = self
+            .files
+            .iter()
+            .map(|file| {
+                let chunks = file_column_statistic(file, column, name, &data_type)?;
+                let mut acc: Option<ScalarValue> = None;
+                for idx in 0..chunks.len() {
+                    let value = ScalarValue::try_from_array(&chunks, idx).ok()?;
+                    if value.is_null() {
+                        return None;
+                    }
+                    acc = match acc {
+                        None => Some(value),
+                        Some(prev) => Some(combine(prev, value)?),
+                    };
+                }
+                acc
+            })
+            .map(|value| value.unwrap_or_else(|| unknown.clone()))
+            .collect::<Vec<_>>();
+
+        ScalarValue::iter_to_array(values).ok()
+    }
+}
+
+fn file_column_statistic(
+    file: &FileMetadata,
+    column: &Column,
+    name: &str,
+    data_type: &DataType,
+) -> Option<ArrayRef> {
+    let DType::Struct(struct_dtype, _) = file.dtype() else {
+        return None;
+    };
+    let idx = struct_dtype.find_name(column.name())?;
+    let array = file
+        .column_statistic(idx, name)?
+        .into_canonical()
+        .ok()?
+        .into_arrow();
+    cast(&array, data_type).ok()
+}
+
+impl PruningStatistics for FileStatistics<'_> {
+    fn min_values(&self, column: &Column) -> Option<ArrayRef> {
+        self.aggregate(column, MIN, |a, b| match a.partial_cmp(&b)? {
+            Ordering::Greater => Some(b),
+            _ => Some(a),
+        })
+    }
+
+    fn max_values(&self, column: &Column) -> Option<ArrayRef> {
+        self.aggregate(column, MAX, |a, b| match a.partial_cmp(&b)? {
+            Ordering::Less => Some(b),
+            _ => Some(a),
+        })
+    }
+
+    fn num_containers(&self) -> usize {
+        self.files.len()
+    }
+
+    fn null_counts(&self, column: &Column) -> Option<ArrayRef> {
+        self.aggregate(column, NULL_COUNT, |a, b| a.add(b).ok())
+    }
+
+    fn row_counts(&self, column: &Column) -> Option<ArrayRef> {
+        self.aggregate(column, ROW_COUNT, |a, b| a.add(b).ok())
+    }
+
+    fn contained(&self, _column: &Column, _values: &HashSet<ScalarValue>) -> Option<BooleanArray> {
+        None
+    }
+}
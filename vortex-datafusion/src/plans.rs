@@ -1,7 +1,9 @@
 //! Physical operators needed to implement scanning of Vortex arrays with pushdown.
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
+use std::iter;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
@@ -22,14 +24,19 @@ use pin_project::pin_project;
 use vortex::array::ChunkedArray;
 use vortex::arrow::FromArrowArray;
 use vortex::compute::take;
+use vortex::stats::ArrayStatistics;
+use vortex::variants::StructArrayTrait;
 use vortex::{Array, AsArray as _, IntoArray, IntoArrayVariant, IntoCanonical};
 use vortex_dtype::field::Field;
 use vortex_error::vortex_err;
-use vortex_expr::VortexExpr;
+use vortex_expr::{can_prune, PruneResult, VortexExpr};
 
 /// Physical plan operator that applies a set of [filters][Expr] against the input, producing a
 /// row mask that can be used downstream to force a take against the corresponding struct array
 /// chunks but for different columns.
+///
+/// Chunks whose column stats already decide the filter are skipped or selected in full without
+/// evaluating it, see [`can_prune`].
 pub(crate) struct RowSelectorExec {
     filter_expr: Arc<dyn VortexExpr>,
     /// cached PlanProperties object. We do not make use of this.
@@ -165,22 +172,38 @@ impl Stream for RowIndicesStream {
             .project(&this.filter_projection)
             .expect("projection should succeed");
 
-        let selection = this
-            .conjunction_expr
-            .evaluate(vortex_struct.as_array_ref())
-            .map_err(|e| DataFusionError::External(e.into()))?
-            .into_canonical()
-            .unwrap()
-            .into_arrow();
-
-        // Convert the `selection` BooleanArray into a UInt64Array of indices.
-        let selection_indices = selection
-            .as_boolean()
-            .values()
-            .set_indices()
-            .map(|idx| idx as u64);
+        // Consult the stats the chunk's columns already carry before evaluating the filter.
+        let stats = vortex_struct
+            .names()
+            .iter()
+            .zip(vortex_struct.children())
+            .map(|(name, column)| (Field::from(name.as_ref()), column.statistics().to_set()))
+            .collect::<HashMap<_, _>>();
+
+        let indices = match can_prune(this.conjunction_expr.as_ref(), &stats) {
+            PruneResult::AllFalse => UInt64Array::from_iter_values(iter::empty()),
+            PruneResult::AllTrue => UInt64Array::from_iter_values(0..vortex_struct.len() as u64),
+            PruneResult::Unknown => {
+                let selection = this
+                    .conjunction_expr
+                    .evaluate(vortex_struct.as_array_ref())
+                    .map_err(|e| DataFusionError::External(e.into()))?
+                    .into_canonical()
+                    .unwrap()
+                    .into_arrow();
+
+                // Convert the `selection` BooleanArray into a UInt64Array of indices.
+                let selection_indices = selection
+                    .as_boolean()
+                    .values()
+                    .set_indices()
+                    .map(|idx| idx as u64);
+
+                UInt64Array::from_iter_values(selection_indices)
+            }
+        };
 
-        let indices = Arc::new(UInt64Array::from_iter_values(selection_indices)) as ArrayRef;
+        let indices = Arc::new(indices) as ArrayRef;
         let indices_batch = RecordBatch::try_new(ROW_SELECTOR_SCHEMA_REF.clone(), vec![indices])?;
 
         Poll::Ready(Some(Ok(indices_batch)))
@@ -389,6 +412,8 @@ where
 mod test {
     use std::sync::Arc;
 
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt64Type;
     use arrow_array::{RecordBatch, UInt64Array};
     use datafusion_common::ToDFSchema;
     use datafusion_expr::execution_props::ExecutionProps;
@@ -396,6 +421,7 @@ mod test {
     use datafusion_physical_expr::create_physical_expr;
     use itertools::Itertools;
     use vortex::array::{BoolArray, ChunkedArray, PrimitiveArray, StructArray};
+    use vortex::stats::{ArrayStatistics, Stat};
     use vortex::validity::Validity;
     use vortex::{ArrayDType, IntoArray};
     use vortex_dtype::field::Field;
@@ -424,7 +450,7 @@ mod test {
         let chunked_array =
             ChunkedArray::try_new(vec![chunk.clone(), chunk.clone()], dtype).unwrap();
 
-        let schema = infer_schema(chunk.dtype());
+        let schema = infer_schema(chunk.dtype()).unwrap();
         let logical_expr = and((col("a")).eq(lit(2u64)), col("b").eq(lit(true)));
         let df_expr = create_physical_expr(
             &logical_expr,
@@ -466,4 +492,99 @@ mod test {
             .unwrap()
         );
     }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_filtering_stream_skips_chunks() {
+        let chunk = |values: Vec<u64>| {
+            let column = PrimitiveArray::from(values).into_array();
+            column.statistics().compute(Stat::Min);
+            StructArray::from_fields(&[("a", column)]).into_array()
+        };
+        let chunks = vec![
+            chunk(vec![0, 1, 2]),
+            chunk(vec![10, 11, 12]),
+            chunk(vec![4, 5, 6]),
+        ];
+        let dtype = chunks[0].dtype().clone();
+        let chunked_array = ChunkedArray::try_new(chunks, dtype).unwrap();
+
+        let schema = infer_schema(chunked_array.dtype()).unwrap();
+        let df_expr = create_physical_expr(
+            &col("a").gt(lit(5u64)),
+            &schema.to_dfschema().unwrap(),
+            &ExecutionProps::new(),
+        )
+        .unwrap();
+
+        let filtering_stream = RowIndicesStream {
+            chunked_array,
+            chunk_idx: 0,
+            conjunction_expr: convert_expr_to_vortex(df_expr).unwrap(),
+            filter_projection: vec![Field::from("a")],
+        };
+
+        let rows: Vec<RecordBatch> = futures::executor::block_on_stream(filtering_stream)
+            .try_collect()
+            .unwrap();
+
+        let indices = |values: Vec<u64>| {
+            RecordBatch::try_new(
+                ROW_SELECTOR_SCHEMA_REF.clone(),
+                vec![Arc::new(UInt64Array::from(values))],
+            )
+            .unwrap()
+        };
+        assert_eq!(
+            rows,
+            vec![indices(vec![]), indices(vec![0, 1, 2]), indices(vec![2])]
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn test_filtering_stream_with_nans() {
+        let chunk = |values: Vec<f64>| {
+            let column = PrimitiveArray::from(values).into_array();
+            column.statistics().compute(Stat::Min);
+            StructArray::from_fields(&[("a", column)]).into_array()
+        };
+        let chunks = vec![chunk(vec![1.0, f64::NAN, 3.0]), chunk(vec![1.0, 3.0])];
+        let dtype = chunks[0].dtype().clone();
+        let chunked_array = ChunkedArray::try_new(chunks, dtype).unwrap();
+        let schema = infer_schema(chunked_array.dtype()).unwrap();
+
+        let select = |expr| {
+            let df_expr = create_physical_expr(
+                &expr,
+                &schema.clone().to_dfschema().unwrap(),
+                &ExecutionProps::new(),
+            )
+            .unwrap();
+            let filtering_stream = RowIndicesStream {
+                chunked_array: chunked_array.clone(),
+                chunk_idx: 0,
+                conjunction_expr: convert_expr_to_vortex(df_expr).unwrap(),
+                filter_projection: vec![Field::from("a")],
+            };
+            futures::executor::block_on_stream(filtering_stream)
+                .map(|batch| {
+                    batch
+                        .unwrap()
+                        .column(0)
+                        .as_primitive::<UInt64Type>()
+                        .values()
+                        .to_vec()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // NaN lies outside the min and max of its chunk, so that chunk is left to the filter,
+        // which matches NaN with neither comparison.
+        assert_eq!(select(col("a").gt(lit(100.0))), vec![Vec::<u64>::new(); 2]);
+        assert_eq!(
+            select(col("a").lt(lit(100.0))),
+            vec![vec![0, 2], vec![0, 1]]
+        );
+    }
 }
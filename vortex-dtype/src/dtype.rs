@@ -42,6 +42,9 @@ impl DType {
         self.is_nullable().into()
     }
 
+    /// Whether the values of this type may be null.
+    ///
+    /// For a struct this is the nullability of the struct itself, independent of its fields.
     pub fn is_nullable(&self) -> bool {
         use crate::nullability::Nullability::*;
 
@@ -51,7 +54,7 @@ impl DType {
             Primitive(_, n) => matches!(n, Nullable),
             Utf8(n) => matches!(n, Nullable),
             Binary(n) => matches!(n, Nullable),
-            Struct(st, _) => st.dtypes().iter().all(|f| f.is_nullable()),
+            Struct(_, n) => matches!(n, Nullable),
             List(_, n) => matches!(n, Nullable),
             Extension(_, n) => matches!(n, Nullable),
         }
@@ -1,6 +1,8 @@
 use std::fmt::{Display, Formatter};
 use std::sync::Arc;
 
+use crate::DType;
+
 #[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct ExtID(Arc<str>);
@@ -55,12 +57,40 @@ impl From<&[u8]> for ExtMetadata {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExtDType {
     id: ExtID,
+    // Kept behind a single pointer so that extension types do not grow `DType`.
+    details: Option<Arc<ExtDetails>>,
+}
+
+#[derive(Debug, PartialOrd, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct ExtDetails {
     metadata: Option<ExtMetadata>,
+    storage_dtype: Option<DType>,
 }
 
 impl ExtDType {
     pub fn new(id: ExtID, metadata: Option<ExtMetadata>) -> Self {
-        Self { id, metadata }
+        Self {
+            id,
+            details: metadata.map(|metadata| {
+                Arc::new(ExtDetails {
+                    metadata: Some(metadata),
+                    storage_dtype: None,
+                })
+            }),
+        }
+    }
+
+    /// Record the type of the arrays storing values of this extension type, for consumers that
+    /// only see the dtype and do not know the extension.
+    pub fn with_storage_dtype(self, storage_dtype: DType) -> Self {
+        Self {
+            details: Some(Arc::new(ExtDetails {
+                metadata: self.metadata().cloned(),
+                storage_dtype: Some(storage_dtype),
+            })),
+            ..self
+        }
     }
 
     #[inline]
@@ -70,6 +100,11 @@ impl ExtDType {
 
     #[inline]
     pub fn metadata(&self) -> Option<&ExtMetadata> {
-        self.metadata.as_ref()
+        self.details.as_ref()?.metadata.as_ref()
+    }
+
+    #[inline]
+    pub fn storage_dtype(&self) -> Option<&DType> {
+        self.details.as_ref()?.storage_dtype.as_ref()
     }
 }
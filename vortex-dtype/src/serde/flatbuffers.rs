@@ -83,10 +83,11 @@ impl TryFrom<fb::DType<'_>> for DType {
                         vortex_err!("failed to parse extension id from flatbuffer")
                     })?);
                 let metadata = fb_ext.metadata().map(|m| ExtMetadata::from(m.bytes()));
-                Ok(Self::Extension(
-                    ExtDType::new(id, metadata),
-                    fb_ext.nullable().into(),
-                ))
+                let mut ext_dtype = ExtDType::new(id, metadata);
+                if let Some(storage_dtype) = fb_ext.storage_dtype() {
+                    ext_dtype = ext_dtype.with_storage_dtype(Self::try_from(storage_dtype)?);
+                }
+                Ok(Self::Extension(ext_dtype, fb_ext.nullable().into()))
             }
             _ => Err(vortex_err!("Unknown DType variant")),
         }
@@ -171,12 +172,14 @@ impl WriteFlatBuffer for DType {
             Self::Extension(ext, n) => {
                 let id = Some(fbb.create_string(ext.id().as_ref()));
                 let metadata = ext.metadata().map(|m| fbb.create_vector(m.as_ref()));
+                let storage_dtype = ext.storage_dtype().map(|d| d.write_flatbuffer(fbb));
                 fb::Extension::create(
                     fbb,
                     &fb::ExtensionArgs {
                         id,
                         metadata,
                         nullable: (*n).into(),
+                        storage_dtype,
                     },
                 )
                 .as_union_value()
@@ -251,7 +254,7 @@ mod test {
     use vortex_flatbuffers::FlatBufferToBytes;
 
     use crate::nullability::Nullability;
-    use crate::{flatbuffers as fb, DType, PType, StructDType};
+    use crate::{flatbuffers as fb, DType, ExtDType, ExtID, ExtMetadata, PType, StructDType};
 
     fn roundtrip_dtype(dtype: DType) {
         let bytes = dtype.with_flatbuffer_bytes(|bytes| bytes.to_vec());
@@ -279,6 +282,14 @@ mod test {
                 ],
             ),
             Nullability::NonNullable,
-        ))
+        ));
+        roundtrip_dtype(DType::Extension(
+            ExtDType::new(
+                ExtID::from("example.ipv4"),
+                Some(ExtMetadata::from(&b"v1"[..])),
+            )
+            .with_storage_dtype(DType::Primitive(PType::U32, Nullability::Nullable)),
+            Nullability::Nullable,
+        ));
     }
 }
@@ -45,13 +45,16 @@ impl TryFrom<&pb::DType> for DType {
                     nullable,
                 ))
             }
-            DtypeType::Extension(e) => Ok(Self::Extension(
-                ExtDType::new(
+            DtypeType::Extension(e) => {
+                let mut ext_dtype = ExtDType::new(
                     ExtID::from(e.id.as_str()),
                     e.metadata.as_ref().map(|m| ExtMetadata::from(m.as_ref())),
-                ),
-                e.nullable.into(),
-            )),
+                );
+                if let Some(storage_dtype) = e.storage_dtype.as_ref() {
+                    ext_dtype = ext_dtype.with_storage_dtype(storage_dtype.as_ref().try_into()?);
+                }
+                Ok(Self::Extension(ext_dtype, e.nullable.into()))
+            }
         }
     }
 }
@@ -87,6 +90,7 @@ impl From<&DType> for pb::DType {
                     id: e.id().as_ref().into(),
                     metadata: e.metadata().map(|m| m.as_ref().into()),
                     nullable: (*n).into(),
+                    storage_dtype: e.storage_dtype().map(|d| Box::new(d.into())),
                 }),
             }),
         }
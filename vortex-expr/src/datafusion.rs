@@ -2,12 +2,14 @@
 
 use std::sync::Arc;
 
+use datafusion_common::ScalarValue;
 use datafusion_expr::Operator as DFOperator;
 use datafusion_physical_expr::PhysicalExpr;
+use vortex_dtype::field::Field;
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 use vortex_scalar::Scalar;
 
-use crate::expr::{Literal, NoOp, VortexExpr};
+use crate::expr::{GetField, Like, Literal, NoOp, VortexExpr};
 use crate::{BinaryExpr, Column, Operator};
 
 pub fn convert_expr_to_vortex(
@@ -24,6 +26,26 @@ pub fn convert_expr_to_vortex(
         return Ok(Arc::new(BinaryExpr::new(left, operator.try_into()?, right)) as _);
     }
 
+    if let Some(like_expr) = physical_expr
+        .as_any()
+        .downcast_ref::<datafusion_physical_expr::expressions::LikeExpr>()
+    {
+        if like_expr.negated() || like_expr.case_insensitive() {
+            vortex_bail!("Only case sensitive, non-negated LIKE is supported");
+        }
+        let Some(ScalarValue::Utf8(Some(pattern))) = like_expr
+            .pattern()
+            .as_any()
+            .downcast_ref::<datafusion_physical_expr::expressions::Literal>()
+            .map(|lit| lit.value())
+        else {
+            vortex_bail!("LIKE requires a string literal pattern");
+        };
+        let child = convert_expr_to_vortex(like_expr.expr().clone())?;
+
+        return Ok(Arc::new(Like::new(child, pattern.clone())) as _);
+    }
+
     if let Some(col_expr) = physical_expr
         .as_any()
         .downcast_ref::<datafusion_physical_expr::expressions::Column>()
@@ -33,6 +55,24 @@ pub fn convert_expr_to_vortex(
         return Ok(Arc::new(expr) as _);
     }
 
+    if let Some(scalar_fn) = physical_expr
+        .as_any()
+        .downcast_ref::<datafusion_physical_expr::ScalarFunctionExpr>()
+    {
+        if let ("get_field", [child, name]) = (scalar_fn.name(), scalar_fn.args()) {
+            let Some(ScalarValue::Utf8(Some(name))) = name
+                .as_any()
+                .downcast_ref::<datafusion_physical_expr::expressions::Literal>()
+                .map(|lit| lit.value())
+            else {
+                vortex_bail!("get_field requires a string literal field name");
+            };
+            let child = convert_expr_to_vortex(child.clone())?;
+
+            return Ok(Arc::new(GetField::new(child, Field::from(name.as_str()))) as _);
+        }
+    }
+
     if let Some(lit) = physical_expr
         .as_any()
         .downcast_ref::<datafusion_physical_expr::expressions::Literal>()
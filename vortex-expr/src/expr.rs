@@ -1,9 +1,10 @@
+use std::any::Any;
 use std::collections::HashSet;
 use std::fmt::Debug;
 use std::sync::Arc;
 
 use vortex::array::{ConstantArray, StructArray};
-use vortex::compute::{compare, Operator as ArrayOperator};
+use vortex::compute::{compare, like, Operator as ArrayOperator};
 use vortex::variants::StructArrayTrait;
 use vortex::{Array, IntoArray};
 use vortex_dtype::field::Field;
@@ -12,12 +13,23 @@ use vortex_scalar::Scalar;
 
 use crate::Operator;
 
-pub trait VortexExpr: Debug + Send + Sync {
+pub trait VortexExpr: Debug + Send + Sync + AsAny {
     fn evaluate(&self, array: &Array) -> VortexResult<Array>;
 
     fn references(&self) -> HashSet<Field>;
 }
 
+/// Downcasting of expressions to their concrete type, implemented for every [`VortexExpr`].
+pub trait AsAny {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: VortexExpr + 'static> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 #[derive(Debug)]
 pub struct NoOp;
 
@@ -36,6 +48,18 @@ impl BinaryExpr {
             operator,
         }
     }
+
+    pub fn lhs(&self) -> &Arc<dyn VortexExpr> {
+        &self.left
+    }
+
+    pub fn rhs(&self) -> &Arc<dyn VortexExpr> {
+        &self.right
+    }
+
+    pub fn op(&self) -> Operator {
+        self.operator
+    }
 }
 
 #[derive(Debug)]
@@ -49,6 +73,10 @@ impl Column {
             field: Field::from(field),
         }
     }
+
+    pub fn field(&self) -> &Field {
+        &self.field
+    }
 }
 
 impl VortexExpr for Column {
@@ -68,6 +96,67 @@ impl VortexExpr for Column {
     }
 }
 
+/// Access a named field of a struct-typed child expression.
+#[derive(Debug)]
+pub struct GetField {
+    child: Arc<dyn VortexExpr>,
+    field: Field,
+}
+
+impl GetField {
+    pub fn new(child: Arc<dyn VortexExpr>, field: Field) -> Self {
+        Self { child, field }
+    }
+}
+
+impl VortexExpr for GetField {
+    fn evaluate(&self, array: &Array) -> VortexResult<Array> {
+        let child = self.child.evaluate(array)?;
+        let s = StructArray::try_from(child)?;
+
+        match &self.field {
+            Field::Name(n) => s.field_by_name(n),
+            Field::Index(i) => s.field(*i),
+        }
+        .ok_or_else(|| vortex_err!("Array doesn't contain child array {}", self.field))
+    }
+
+    fn references(&self) -> HashSet<Field> {
+        self.child.references()
+    }
+}
+
+/// Match a string-typed child expression against a SQL `LIKE` pattern.
+#[derive(Debug)]
+pub struct Like {
+    child: Arc<dyn VortexExpr>,
+    pattern: String,
+}
+
+impl Like {
+    pub fn new(child: Arc<dyn VortexExpr>, pattern: String) -> Self {
+        Self { child, pattern }
+    }
+
+    pub fn child(&self) -> &Arc<dyn VortexExpr> {
+        &self.child
+    }
+
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+}
+
+impl VortexExpr for Like {
+    fn evaluate(&self, array: &Array) -> VortexResult<Array> {
+        like(&self.child.evaluate(array)?, &self.pattern)
+    }
+
+    fn references(&self) -> HashSet<Field> {
+        self.child.references()
+    }
+}
+
 #[derive(Debug)]
 pub struct Literal {
     value: Scalar,
@@ -77,6 +166,10 @@ impl Literal {
     pub fn new(value: Scalar) -> Self {
         Self { value }
     }
+
+    pub fn value(&self) -> &Scalar {
+        &self.value
+    }
 }
 
 impl VortexExpr for Literal {
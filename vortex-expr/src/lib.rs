@@ -3,6 +3,8 @@
 pub mod datafusion;
 mod expr;
 mod operators;
+mod pruning;
 
 pub use expr::*;
 pub use operators::*;
+pub use pruning::*;
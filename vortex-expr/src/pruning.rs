@@ -0,0 +1,418 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use vortex::stats::{Stat, StatsSet};
+use vortex_dtype::field::Field;
+use vortex_dtype::DType;
+use vortex_scalar::{PValue, Scalar};
+
+use crate::{BinaryExpr, Column, Literal, Operator, VortexExpr};
+
+/// What the stats of an array tell about the rows matching a predicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneResult {
+    /// The predicate is true for every row.
+    AllTrue,
+    /// The predicate is false or null for every row, so no row matches it.
+    AllFalse,
+    /// The stats do not decide the predicate.
+    Unknown,
+}
+
+impl PruneResult {
+    fn and(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::AllFalse, _) | (_, Self::AllFalse) => Self::AllFalse,
+            (Self::AllTrue, Self::AllTrue) => Self::AllTrue,
+            _ => Self::Unknown,
+        }
+    }
+
+    fn or(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::AllTrue, _) | (_, Self::AllTrue) => Self::AllTrue,
+            (Self::AllFalse, Self::AllFalse) => Self::AllFalse,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Decide `predicate` for all rows of a struct array at once, using only the stats of its
+/// columns.
+///
+/// Comparisons with a null are null, and so never match. Columns missing from `stats`, or
+/// missing their min and max, are never decided, and neither are floating point columns unless
+/// their stats show they hold no NaN, as NaN is left out of the min and max.
+pub fn can_prune(predicate: &dyn VortexExpr, stats: &HashMap<Field, StatsSet>) -> PruneResult {
+    if let Some(binary) = predicate.as_any().downcast_ref::<BinaryExpr>() {
+        return match binary.op() {
+            Operator::And => {
+                can_prune(binary.lhs().as_ref(), stats).and(can_prune(binary.rhs().as_ref(), stats))
+            }
+            Operator::Or => {
+                can_prune(binary.lhs().as_ref(), stats).or(can_prune(binary.rhs().as_ref(), stats))
+            }
+            op => match (
+                Bounds::of(binary.lhs().as_ref(), stats),
+                Bounds::of(binary.rhs().as_ref(), stats),
+            ) {
+                (Some(lhs), Some(rhs)) => compare_bounds(&lhs, op, &rhs),
+                _ => PruneResult::Unknown,
+            },
+        };
+    }
+
+    match Bounds::of(predicate, stats) {
+        Some(Bounds::AllNull) => PruneResult::AllFalse,
+        Some(Bounds::Range {
+            min,
+            max,
+            has_nulls,
+            ..
+        }) => match (bool::try_from(&min), bool::try_from(&max)) {
+            (_, Ok(false)) => PruneResult::AllFalse,
+            (Ok(true), _) if !has_nulls => PruneResult::AllTrue,
+            _ => PruneResult::Unknown,
+        },
+        None => PruneResult::Unknown,
+    }
+}
+
+/// The values an expression can take over the rows of an array.
+enum Bounds {
+    AllNull,
+    Range {
+        min: Scalar,
+        max: Scalar,
+        has_nulls: bool,
+        /// Whether there may be NaN values, which lie outside of `min` and `max`.
+        has_nans: bool,
+    },
+}
+
+impl Bounds {
+    fn of(expr: &dyn VortexExpr, stats: &HashMap<Field, StatsSet>) -> Option<Self> {
+        if let Some(literal) = expr.as_any().downcast_ref::<Literal>() {
+            let value = literal.value();
+            return Some(if value.is_null() {
+                Self::AllNull
+            } else {
+                Self::Range {
+                    min: value.clone(),
+                    max: value.clone(),
+                    has_nulls: false,
+                    has_nans: false,
+                }
+            });
+        }
+
+        let column = expr.as_any().downcast_ref::<Column>()?;
+        let stats = stats.get(column.field())?;
+        let min = stats.get(Stat::Min)?;
+        let max = stats.get(Stat::Max)?;
+        if min.is_null() || max.is_null() {
+            return Some(Self::AllNull);
+        }
+        let has_nulls = stats
+            .get(Stat::NullCount)
+            .and_then(|null_count| usize::try_from(null_count).ok())
+            .map_or(true, |null_count| null_count > 0);
+        let has_nans = match min.dtype() {
+            DType::Primitive(ptype, _) if ptype.is_float() => stats
+                .get(Stat::NanCount)
+                .and_then(|nan_count| usize::try_from(nan_count).ok())
+                .map_or(true, |nan_count| nan_count > 0),
+            _ => false,
+        };
+        Some(Self::Range {
+            min: min.clone(),
+            max: max.clone(),
+            has_nulls,
+            has_nans,
+        })
+    }
+}
+
+fn compare_bounds(lhs: &Bounds, op: Operator, rhs: &Bounds) -> PruneResult {
+    let (
+        Bounds::Range {
+            min: lmin,
+            max: lmax,
+            has_nulls: lnulls,
+            has_nans: lnans,
+        },
+        Bounds::Range {
+            min: rmin,
+            max: rmax,
+            has_nulls: rnulls,
+            has_nans: rnans,
+        },
+    ) = (lhs, rhs)
+    else {
+        return PruneResult::AllFalse;
+    };
+    if *lnans || *rnans {
+        return PruneResult::Unknown;
+    }
+
+    let lt = |a: &Scalar, b: &Scalar| compare(a, b) == Some(Ordering::Less);
+    let le =
+        |a: &Scalar, b: &Scalar| matches!(compare(a, b), Some(Ordering::Less | Ordering::Equal));
+    let all_equal = || {
+        compare(lmin, lmax) == Some(Ordering::Equal)
+            && compare(lmax, rmin) == Some(Ordering::Equal)
+            && compare(rmin, rmax) == Some(Ordering::Equal)
+    };
+    let disjoint = || lt(lmax, rmin) || lt(rmax, lmin);
+
+    let (all_true, all_false) = match op {
+        Operator::Eq => (all_equal(), disjoint()),
+        Operator::NotEq => (disjoint(), all_equal()),
+        Operator::Lt => (lt(lmax, rmin), le(rmax, lmin)),
+        Operator::Lte => (le(lmax, rmin), lt(rmax, lmin)),
+        Operator::Gt => (lt(rmax, lmin), le(lmax, rmin)),
+        Operator::Gte => (le(rmax, lmin), lt(lmax, rmin)),
+        Operator::And | Operator::Or => (false, false),
+    };
+
+    if all_false {
+        PruneResult::AllFalse
+    } else if all_true && !lnulls && !rnulls {
+        PruneResult::AllTrue
+    } else {
+        PruneResult::Unknown
+    }
+}
+
+/// Order two non-null scalars, e.g. the min of a column and a literal, or `None` if they cannot
+/// be compared.
+fn compare(lhs: &Scalar, rhs: &Scalar) -> Option<Ordering> {
+    match (lhs.dtype(), rhs.dtype()) {
+        (DType::Primitive(..), DType::Primitive(..)) => compare_pvalues(
+            lhs.value().as_pvalue().ok()??,
+            rhs.value().as_pvalue().ok()??,
+        ),
+        (DType::Utf8(_), DType::Utf8(_)) => {
+            let lhs = lhs.value().as_buffer_string().ok()??;
+            let rhs = rhs.value().as_buffer_string().ok()??;
+            lhs.partial_cmp(&rhs)
+        }
+        (DType::Binary(_), DType::Binary(_)) => {
+            let lhs = lhs.value().as_buffer().ok()??;
+            let rhs = rhs.value().as_buffer().ok()??;
+            lhs.partial_cmp(&rhs)
+        }
+        (DType::Bool(_), DType::Bool(_)) => {
+            let lhs = lhs.value().as_bool().ok()??;
+            let rhs = rhs.value().as_bool().ok()??;
+            lhs.partial_cmp(&rhs)
+        }
+        _ => None,
+    }
+}
+
+/// Compare primitives of any type without losing precision: integers as `i128`, and otherwise as
+/// `f64` if both convert to it exactly.
+///
+/// The type of each value may differ from its dtype, as values may have been serialized with a
+/// narrower type.
+fn compare_pvalues(lhs: PValue, rhs: PValue) -> Option<Ordering> {
+    match (pvalue_as_i128(lhs), pvalue_as_i128(rhs)) {
+        (Some(lhs), Some(rhs)) => Some(lhs.cmp(&rhs)),
+        _ => pvalue_as_f64(lhs)?.partial_cmp(&pvalue_as_f64(rhs)?),
+    }
+}
+
+fn pvalue_as_i128(value: PValue) -> Option<i128> {
+    match value {
+        PValue::U8(v) => Some(v.into()),
+        PValue::U16(v) => Some(v.into()),
+        PValue::U32(v) => Some(v.into()),
+        PValue::U64(v) => Some(v.into()),
+        PValue::I8(v) => Some(v.into()),
+        PValue::I16(v) => Some(v.into()),
+        PValue::I32(v) => Some(v.into()),
+        PValue::I64(v) => Some(v.into()),
+        PValue::F16(_) | PValue::F32(_) | PValue::F64(_) => None,
+    }
+}
+
+fn pvalue_as_f64(value: PValue) -> Option<f64> {
+    match value {
+        PValue::F16(v) => Some(v.to_f64()),
+        PValue::F32(v) => Some(v.into()),
+        PValue::F64(v) => Some(v),
+        _ => {
+            let int = pvalue_as_i128(value)?;
+            let float = int as f64;
+            (float as i128 == int).then_some(float)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use vortex::array::PrimitiveArray;
+    use vortex::stats::{ArrayStatistics, Stat, StatsSet};
+    use vortex_dtype::field::Field;
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::{can_prune, BinaryExpr, Column, Literal, Operator, PruneResult, VortexExpr};
+
+    fn stats(values: Vec<Option<i32>>) -> HashMap<Field, StatsSet> {
+        let array = PrimitiveArray::from_nullable_vec(values);
+        array.statistics().compute(Stat::Min);
+        HashMap::from([(Field::from("a"), array.statistics().to_set())])
+    }
+
+    fn compare(op: Operator, value: impl Into<Scalar>) -> Arc<dyn VortexExpr> {
+        Arc::new(BinaryExpr::new(
+            Arc::new(Column::new("a".to_string())),
+            op,
+            Arc::new(Literal::new(value.into())),
+        ))
+    }
+
+    #[test]
+    fn comparisons() {
+        let stats = stats(vec![Some(10), Some(20), Some(30)]);
+        let prune = |op, value: i32| can_prune(compare(op, value).as_ref(), &stats);
+
+        assert_eq!(prune(Operator::Gt, 30), PruneResult::AllFalse);
+        assert_eq!(prune(Operator::Gt, 9), PruneResult::AllTrue);
+        assert_eq!(prune(Operator::Gt, 20), PruneResult::Unknown);
+        assert_eq!(prune(Operator::Gte, 30), PruneResult::Unknown);
+        assert_eq!(prune(Operator::Lt, 10), PruneResult::AllFalse);
+        assert_eq!(prune(Operator::Lte, 30), PruneResult::AllTrue);
+        assert_eq!(prune(Operator::Eq, 40), PruneResult::AllFalse);
+        assert_eq!(prune(Operator::Eq, 20), PruneResult::Unknown);
+        assert_eq!(prune(Operator::NotEq, 5), PruneResult::AllTrue);
+    }
+
+    #[test]
+    fn literal_on_the_left() {
+        let stats = stats(vec![Some(10), Some(20)]);
+        let expr = BinaryExpr::new(
+            Arc::new(Literal::new(5i32.into())),
+            Operator::Gt,
+            Arc::new(Column::new("a".to_string())),
+        );
+        assert_eq!(can_prune(&expr, &stats), PruneResult::AllFalse);
+    }
+
+    #[test]
+    fn literal_of_other_width() {
+        let stats = stats(vec![Some(10), Some(20)]);
+        let expr = compare(Operator::Gt, 100i64);
+        assert_eq!(can_prune(expr.as_ref(), &stats), PruneResult::AllFalse);
+    }
+
+    #[test]
+    fn fractional_literal() {
+        let stats = stats(vec![Some(10), Some(20)]);
+        let prune = |op, value: f64| can_prune(compare(op, value).as_ref(), &stats);
+
+        assert_eq!(prune(Operator::Lt, 10.5), PruneResult::Unknown);
+        assert_eq!(prune(Operator::Lt, 9.5), PruneResult::AllFalse);
+        assert_eq!(prune(Operator::Gt, 9.5), PruneResult::AllTrue);
+        assert_eq!(prune(Operator::Eq, 10.5), PruneResult::Unknown);
+        assert_eq!(prune(Operator::Eq, 20.5), PruneResult::AllFalse);
+    }
+
+    #[test]
+    fn float_nans() {
+        let float_stats = |values: Vec<f64>| {
+            let array = PrimitiveArray::from(values);
+            array.statistics().compute(Stat::Min);
+            HashMap::from([(Field::from("a"), array.statistics().to_set())])
+        };
+
+        let with_nan = float_stats(vec![1.0, f64::NAN, 3.0]);
+        for (op, value) in [(Operator::Lt, 100.0), (Operator::Gt, 100.0)] {
+            assert_eq!(
+                can_prune(compare(op, value).as_ref(), &with_nan),
+                PruneResult::Unknown
+            );
+        }
+
+        let without_nan = float_stats(vec![1.0, 3.0]);
+        assert_eq!(
+            can_prune(compare(Operator::Lt, 100.0).as_ref(), &without_nan),
+            PruneResult::AllTrue
+        );
+        assert_eq!(
+            can_prune(compare(Operator::Gt, 100.0).as_ref(), &without_nan),
+            PruneResult::AllFalse
+        );
+
+        // Without a NaN count, NaN cannot be ruled out.
+        let mut unknown_nans = StatsSet::new();
+        unknown_nans.set(Stat::Min, 1.0.into());
+        unknown_nans.set(Stat::Max, 3.0.into());
+        unknown_nans.set(Stat::NullCount, 0.into());
+        let unknown_nans = HashMap::from([(Field::from("a"), unknown_nans)]);
+        assert_eq!(
+            can_prune(compare(Operator::Gt, 100.0).as_ref(), &unknown_nans),
+            PruneResult::Unknown
+        );
+    }
+
+    #[test]
+    fn nulls() {
+        let with_nulls = stats(vec![Some(10), None, Some(20)]);
+        assert_eq!(
+            can_prune(compare(Operator::Gt, 5).as_ref(), &with_nulls),
+            PruneResult::Unknown
+        );
+        assert_eq!(
+            can_prune(compare(Operator::Gt, 20).as_ref(), &with_nulls),
+            PruneResult::AllFalse
+        );
+
+        let all_null = stats(vec![None, None]);
+        assert_eq!(
+            can_prune(compare(Operator::NotEq, 5).as_ref(), &all_null),
+            PruneResult::AllFalse
+        );
+
+        let null_literal = compare(
+            Operator::Eq,
+            Scalar::null(DType::Primitive(PType::I32, Nullability::Nullable)),
+        );
+        assert_eq!(
+            can_prune(null_literal.as_ref(), &stats(vec![Some(1)])),
+            PruneResult::AllFalse
+        );
+    }
+
+    #[test]
+    fn conjunctions() {
+        let stats = stats(vec![Some(10), Some(20), Some(30)]);
+        let and = |lhs, rhs| BinaryExpr::new(lhs, Operator::And, rhs);
+        let or = |lhs, rhs| BinaryExpr::new(lhs, Operator::Or, rhs);
+
+        let expr = and(compare(Operator::Gt, 15), compare(Operator::Gt, 40));
+        assert_eq!(can_prune(&expr, &stats), PruneResult::AllFalse);
+        let expr = and(compare(Operator::Gt, 0), compare(Operator::Lt, 40));
+        assert_eq!(can_prune(&expr, &stats), PruneResult::AllTrue);
+        let expr = or(compare(Operator::Gt, 15), compare(Operator::Gt, 40));
+        assert_eq!(can_prune(&expr, &stats), PruneResult::Unknown);
+        let expr = or(compare(Operator::Gt, 40), compare(Operator::Lt, 0));
+        assert_eq!(can_prune(&expr, &stats), PruneResult::AllFalse);
+    }
+
+    #[test]
+    fn missing_stats() {
+        let expr = compare(Operator::Gt, 5);
+        assert_eq!(
+            can_prune(expr.as_ref(), &HashMap::new()),
+            PruneResult::Unknown
+        );
+        let min_only = HashMap::from([(Field::from("a"), StatsSet::of(Stat::Min, 10.into()))]);
+        assert_eq!(can_prune(expr.as_ref(), &min_only), PruneResult::Unknown);
+    }
+}
@@ -1130,6 +1130,7 @@ impl<'a> Extension<'a> {
   pub const VT_ID: flatbuffers::VOffsetT = 4;
   pub const VT_METADATA: flatbuffers::VOffsetT = 6;
   pub const VT_NULLABLE: flatbuffers::VOffsetT = 8;
+  pub const VT_STORAGE_DTYPE: flatbuffers::VOffsetT = 10;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -1141,6 +1142,7 @@ impl<'a> Extension<'a> {
     args: &'args ExtensionArgs<'args>
   ) -> flatbuffers::WIPOffset<Extension<'bldr>> {
     let mut builder = ExtensionBuilder::new(_fbb);
+    if let Some(x) = args.storage_dtype { builder.add_storage_dtype(x); }
     if let Some(x) = args.metadata { builder.add_metadata(x); }
     if let Some(x) = args.id { builder.add_id(x); }
     builder.add_nullable(args.nullable);
@@ -1169,6 +1171,13 @@ impl<'a> Extension<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<bool>(Extension::VT_NULLABLE, Some(false)).unwrap()}
   }
+  #[inline]
+  pub fn storage_dtype(&self) -> Option<DType<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<DType>>(Extension::VT_STORAGE_DTYPE, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for Extension<'_> {
@@ -1181,6 +1190,7 @@ impl flatbuffers::Verifiable for Extension<'_> {
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("metadata", Self::VT_METADATA, false)?
      .visit_field::<bool>("nullable", Self::VT_NULLABLE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<DType>>("storage_dtype", Self::VT_STORAGE_DTYPE, false)?
      .finish();
     Ok(())
   }
@@ -1189,6 +1199,7 @@ pub struct ExtensionArgs<'a> {
     pub id: Option<flatbuffers::WIPOffset<&'a str>>,
     pub metadata: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
     pub nullable: bool,
+    pub storage_dtype: Option<flatbuffers::WIPOffset<DType<'a>>>,
 }
 impl<'a> Default for ExtensionArgs<'a> {
   #[inline]
@@ -1197,6 +1208,7 @@ impl<'a> Default for ExtensionArgs<'a> {
       id: None,
       metadata: None,
       nullable: false,
+      storage_dtype: None,
     }
   }
 }
@@ -1219,6 +1231,10 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ExtensionBuilder<'a, 'b, A> {
     self.fbb_.push_slot::<bool>(Extension::VT_NULLABLE, nullable, false);
   }
   #[inline]
+  pub fn add_storage_dtype(&mut self, storage_dtype: flatbuffers::WIPOffset<DType<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<DType>>(Extension::VT_STORAGE_DTYPE, storage_dtype);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ExtensionBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     ExtensionBuilder {
@@ -1239,6 +1255,7 @@ impl core::fmt::Debug for Extension<'_> {
       ds.field("id", &self.id());
       ds.field("metadata", &self.metadata());
       ds.field("nullable", &self.nullable());
+      ds.field("storage_dtype", &self.storage_dtype());
       ds.finish()
   }
 }
@@ -65,6 +65,8 @@ pub struct Extension {
     pub metadata: ::core::option::Option<::prost::alloc::vec::Vec<u8>>,
     #[prost(bool, tag = "3")]
     pub nullable: bool,
+    #[prost(message, optional, boxed, tag = "4")]
+    pub storage_dtype: ::core::option::Option<::prost::alloc::boxed::Box<DType>>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1,23 +1,41 @@
+use std::any::Any;
 use std::collections::HashSet;
+use std::sync::Arc;
 
-use vortex::array::PrimitiveArray;
+use vortex::array::{PrimitiveArray, SparseArray};
 use vortex::encoding::EncodingRef;
-use vortex::{Array, ArrayDef, IntoArray};
-use vortex_alp::{alp_encode_components, match_each_alp_float_ptype, ALPArray, ALPEncoding, ALP};
+use vortex::{Array, ArrayDType, ArrayDef, IntoArray};
+use vortex_alp::{
+    alp_encode_components, match_each_alp_float_ptype, ALPArray, ALPEncoding, ALPRDArray,
+    ALPRDEncoding, RDEncoder, ALP,
+};
 use vortex_dtype::PType;
-use vortex_error::VortexResult;
+use vortex_error::{vortex_err, VortexResult};
 
-use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
+use crate::compressors::{CompressedArray, CompressionTree, EncoderMetadata, EncodingCompressor};
 use crate::SamplingCompressor;
 
+/// The fraction of values that ALP must patch above which ALP-RD is used instead.
+const ALP_RD_EXCEPTION_THRESHOLD: f64 = 0.1;
+
 #[derive(Debug)]
 pub struct ALPCompressor;
 
+impl EncoderMetadata for RDEncoder {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 impl EncodingCompressor for ALPCompressor {
     fn id(&self) -> &str {
         ALP::ID.as_ref()
     }
 
+    fn decode_throughput(&self) -> f32 {
+        4.0
+    }
+
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
         // Only support primitive arrays
         let parray = PrimitiveArray::try_from(array).ok()?;
@@ -39,11 +57,32 @@ impl EncodingCompressor for ALPCompressor {
         // TODO(robert): Fill forward nulls?
         let parray = array.as_primitive();
 
+        // Arrays compressed like an ALP-RD array reuse its split and dictionary.
+        if let Some(encoder) = like
+            .as_ref()
+            .and_then(|l| l.metadata())
+            .filter(|m| m.as_any().is::<RDEncoder>())
+        {
+            return self.compress_rd(&parray, encoder.clone(), like, ctx);
+        }
+
         let (exponents, encoded, patches) = match_each_alp_float_ptype!(
             parray.ptype(), |$T| {
             alp_encode_components::<$T>(&parray, None)
         });
 
+        let exceptions = patches
+            .as_ref()
+            .map(|p| SparseArray::try_from(p).map(|s| s.indices().len()))
+            .transpose()?
+            .unwrap_or(0);
+        if like.is_none() && exceptions as f64 > parray.len() as f64 * ALP_RD_EXCEPTION_THRESHOLD {
+            let encoder = match_each_alp_float_ptype!(parray.ptype(), |$T| {
+                RDEncoder::new(parray.maybe_null_slice::<$T>())
+            });
+            return self.compress_rd(&parray, Arc::new(encoder), None, ctx);
+        }
+
         let compressed_encoded = ctx
             .named("packed")
             .excluding(self)
@@ -75,6 +114,60 @@ impl EncodingCompressor for ALPCompressor {
     }
 
     fn used_encodings(&self) -> HashSet<EncodingRef> {
-        HashSet::from([&ALPEncoding as EncodingRef])
+        HashSet::from([&ALPEncoding as EncodingRef, &ALPRDEncoding])
+    }
+}
+
+impl ALPCompressor {
+    fn compress_rd<'a>(
+        &'a self,
+        parray: &PrimitiveArray,
+        encoder: Arc<dyn EncoderMetadata>,
+        like: Option<CompressionTree<'a>>,
+        ctx: SamplingCompressor<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        let rd_array = encoder
+            .as_any()
+            .downcast_ref::<RDEncoder>()
+            .ok_or_else(|| vortex_err!("ALP-RD metadata must be an RDEncoder"))?
+            .encode(parray)?;
+
+        let left_parts = ctx.named("left_parts").excluding(self).compress(
+            &rd_array.left_parts(),
+            like.as_ref().and_then(|l| l.child(0)),
+        )?;
+        let right_parts = ctx.named("right_parts").excluding(self).compress(
+            &rd_array.right_parts(),
+            like.as_ref().and_then(|l| l.child(1)),
+        )?;
+        let exceptions = rd_array
+            .exceptions()
+            .map(|e| {
+                ctx.auxiliary("exceptions")
+                    .excluding(self)
+                    .compress(&e, like.as_ref().and_then(|l| l.child(2)))
+            })
+            .transpose()?;
+
+        Ok(CompressedArray::new(
+            ALPRDArray::try_new(
+                rd_array.dtype().clone(),
+                left_parts.array,
+                rd_array.dict().to_vec(),
+                right_parts.array,
+                rd_array.right_bit_width(),
+                exceptions.as_ref().map(|e| e.array.clone()),
+            )?
+            .into_array(),
+            Some(CompressionTree::new_with_metadata(
+                self,
+                vec![
+                    left_parts.path,
+                    right_parts.path,
+                    exceptions.and_then(|e| e.path),
+                ],
+                encoder,
+            )),
+        ))
     }
 }
@@ -20,6 +20,10 @@ impl EncodingCompressor for DateTimePartsCompressor {
         DateTimeParts::ID.as_ref()
     }
 
+    fn decode_throughput(&self) -> f32 {
+        2.0
+    }
+
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
         if let Ok(temporal_array) = TemporalArray::try_from(array) {
             match temporal_array.temporal_metadata() {
@@ -17,6 +17,10 @@ impl EncodingCompressor for DeltaCompressor {
         Delta::ID.as_ref()
     }
 
+    fn decode_throughput(&self) -> f32 {
+        8.0
+    }
+
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
         // Only support primitive arrays
         let parray = PrimitiveArray::try_from(array).ok()?;
@@ -1,10 +1,10 @@
 use std::collections::HashSet;
 
-use vortex::array::{Primitive, PrimitiveArray, VarBin, VarBinArray};
+use vortex::array::{Bool, Primitive, VarBin, VarBinView};
 use vortex::encoding::EncodingRef;
 use vortex::stats::ArrayStatistics;
-use vortex::{Array, ArrayDef, IntoArray};
-use vortex_dict::{dict_encode_primitive, dict_encode_varbin, Dict, DictArray, DictEncoding};
+use vortex::{Array, ArrayDType, ArrayDef, IntoArray};
+use vortex_dict::{dict_encode, Dict, DictArray, DictEncoding};
 use vortex_error::VortexResult;
 
 use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
@@ -18,10 +18,16 @@ impl EncodingCompressor for DictCompressor {
         Dict::ID.as_ref()
     }
 
+    fn decode_throughput(&self) -> f32 {
+        2.0
+    }
+
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
-        // TODO(robert): Add support for VarBinView
-        if array.encoding().id() != Primitive::ID && array.encoding().id() != VarBin::ID {
-            return None;
+        match array.encoding().id() {
+            Primitive::ID | VarBin::ID | VarBinView::ID => {}
+            // Booleans only benefit from a dictionary when the codes can replace their validity.
+            Bool::ID if array.dtype().is_nullable() => {}
+            _ => return None,
         };
 
         // No point dictionary coding if the array is unique.
@@ -43,28 +49,15 @@ impl EncodingCompressor for DictCompressor {
         like: Option<CompressionTree<'a>>,
         ctx: SamplingCompressor<'a>,
     ) -> VortexResult<CompressedArray<'a>> {
-        let (codes, values) = match array.encoding().id() {
-            Primitive::ID => {
-                let p = PrimitiveArray::try_from(array)?;
-                let (codes, values) = dict_encode_primitive(&p);
-                (codes.into_array(), values.into_array())
-            }
-            VarBin::ID => {
-                let vb = VarBinArray::try_from(array)?;
-                let (codes, values) = dict_encode_varbin(&vb);
-                (codes.into_array(), values.into_array())
-            }
-
-            _ => unreachable!("This array kind should have been filtered out"),
-        };
+        let dict = dict_encode(array)?;
 
         let (codes, values) = (
             ctx.auxiliary("codes")
                 .excluding(self)
-                .compress(&codes, like.as_ref().and_then(|l| l.child(0)))?,
+                .compress(&dict.codes(), like.as_ref().and_then(|l| l.child(0)))?,
             ctx.named("values")
                 .excluding(self)
-                .compress(&values, like.as_ref().and_then(|l| l.child(1)))?,
+                .compress(&dict.values(), like.as_ref().and_then(|l| l.child(1)))?,
         );
 
         Ok(CompressedArray::new(
@@ -20,6 +20,10 @@ impl EncodingCompressor for FoRCompressor {
         FoR::ID.as_ref()
     }
 
+    fn decode_throughput(&self) -> f32 {
+        16.0
+    }
+
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
         // Only support primitive arrays
         let parray = PrimitiveArray::try_from(array).ok()?;
@@ -0,0 +1,101 @@
+use std::any::Any;
+use std::collections::HashSet;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use fsst::Compressor;
+use vortex::array::{VarBin, VarBinView};
+use vortex::encoding::EncodingRef;
+use vortex::{Array, ArrayDType, ArrayDef, IntoArray};
+use vortex_dtype::DType;
+use vortex_error::{vortex_err, VortexResult};
+use vortex_fsst::{fsst_compress, fsst_train_compressor, FSSTArray, FSSTEncoding, FSST};
+
+use crate::compressors::{CompressedArray, CompressionTree, EncoderMetadata, EncodingCompressor};
+use crate::SamplingCompressor;
+
+#[derive(Debug)]
+pub struct FSSTCompressor;
+
+/// The symbol table trained for a [`CompressionTree`] of the [`FSSTCompressor`].
+///
+/// Training is the expensive part of FSST, so the table trained on the samples of an array is
+/// reused to compress the full array and any array compressed like it.
+struct SymbolTable(Compressor);
+
+impl Debug for SymbolTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SymbolTable")
+            .field("len", &self.0.symbol_table().len())
+            .finish()
+    }
+}
+
+impl EncoderMetadata for SymbolTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl EncodingCompressor for FSSTCompressor {
+    fn id(&self) -> &str {
+        FSST::ID.as_ref()
+    }
+
+    fn decode_throughput(&self) -> f32 {
+        1.0
+    }
+
+    fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
+        if !matches!(array.dtype(), DType::Utf8(_) | DType::Binary(_)) {
+            return None;
+        }
+
+        if array.encoding().id() != VarBin::ID && array.encoding().id() != VarBinView::ID {
+            return None;
+        }
+
+        Some(self)
+    }
+
+    fn compress<'a>(
+        &'a self,
+        array: &Array,
+        like: Option<CompressionTree<'a>>,
+        ctx: SamplingCompressor<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        let symbol_table = match like
+            .as_ref()
+            .and_then(|l| l.metadata())
+            .filter(|m| m.as_any().is::<SymbolTable>())
+        {
+            Some(symbol_table) => symbol_table.clone(),
+            None => Arc::new(SymbolTable(fsst_train_compressor(array)?)),
+        };
+        let compressor = &symbol_table
+            .as_any()
+            .downcast_ref::<SymbolTable>()
+            .ok_or_else(|| vortex_err!("FSST metadata must be a SymbolTable"))?
+            .0;
+
+        let fsst_array = fsst_compress(array, compressor)?;
+        let codes = ctx
+            .auxiliary("codes")
+            .excluding(self)
+            .compress(&fsst_array.codes(), like.as_ref().and_then(|l| l.child(0)))?;
+
+        Ok(CompressedArray::new(
+            FSSTArray::try_new(array.dtype().clone(), fsst_array.symbols(), codes.array)?
+                .into_array(),
+            Some(CompressionTree::new_with_metadata(
+                self,
+                vec![codes.path],
+                symbol_table,
+            )),
+        ))
+    }
+
+    fn used_encodings(&self) -> HashSet<EncodingRef> {
+        HashSet::from([&FSSTEncoding as EncodingRef])
+    }
+}
@@ -1,6 +1,9 @@
+use std::any::Any;
 use std::collections::HashSet;
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
 
 use vortex::encoding::EncodingRef;
 use vortex::Array;
@@ -15,6 +18,7 @@ pub mod date_time_parts;
 pub mod delta;
 pub mod dict;
 pub mod r#for;
+pub mod fsst;
 pub mod roaring_bool;
 pub mod roaring_int;
 pub mod runend;
@@ -28,6 +32,12 @@ pub trait EncodingCompressor: Sync + Send + Debug {
         1
     }
 
+    /// A rough estimate of how many bytes of canonical output this compressor's encoding decodes
+    /// per nanosecond, used to estimate the decode time of a [`CompressionTree`].
+    fn decode_throughput(&self) -> f32 {
+        1.0
+    }
+
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor>;
 
     fn compress<'a>(
@@ -54,10 +64,17 @@ impl Hash for dyn EncodingCompressor + '_ {
     }
 }
 
+/// Encoder state that is expensive to derive, such as a trained symbol table, kept in a
+/// [`CompressionTree`] so that arrays compressed like it can reuse the state.
+pub trait EncoderMetadata: Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+}
+
 #[derive(Debug, Clone)]
 pub struct CompressionTree<'a> {
     compressor: &'a dyn EncodingCompressor,
     children: Vec<Option<CompressionTree<'a>>>,
+    metadata: Option<Arc<dyn EncoderMetadata>>,
 }
 
 impl Display for CompressionTree<'_> {
@@ -78,9 +95,47 @@ impl<'a> CompressionTree<'a> {
         Self {
             compressor,
             children,
+            metadata: None,
+        }
+    }
+
+    pub fn new_with_metadata(
+        compressor: &'a dyn EncodingCompressor,
+        children: Vec<Option<CompressionTree<'a>>>,
+        metadata: Arc<dyn EncoderMetadata>,
+    ) -> Self {
+        Self {
+            compressor,
+            children,
+            metadata: Some(metadata),
         }
     }
 
+    /// Encoder state recorded when this tree was built, if any.
+    pub fn metadata(&self) -> Option<&Arc<dyn EncoderMetadata>> {
+        self.metadata.as_ref()
+    }
+
+    /// Estimate the time to decode `nbytes` of data compressed with this tree, assuming that every
+    /// encoding in the tree decodes about that many bytes.
+    pub fn estimated_decode_time(&self, nbytes: usize) -> Duration {
+        let nanos = nbytes as f32 / self.compressor.decode_throughput();
+        self.children
+            .iter()
+            .flatten()
+            .map(|child| child.estimated_decode_time(nbytes))
+            .sum::<Duration>()
+            + Duration::from_nanos(nanos as u64)
+    }
+
+    pub fn compressor(&self) -> &'a dyn EncodingCompressor {
+        self.compressor
+    }
+
+    pub fn children(&self) -> &[Option<CompressionTree<'a>>] {
+        &self.children
+    }
+
     pub fn child(&self, idx: usize) -> Option<&CompressionTree<'a>> {
         self.children[idx].as_ref()
     }
@@ -135,7 +190,7 @@ impl<'a> CompressedArray<'a> {
     }
 
     #[inline]
-    pub fn path(&self) -> &Option<CompressionTree> {
+    pub fn path(&self) -> &Option<CompressionTree<'a>> {
         &self.path
     }
 
@@ -1,9 +1,8 @@
 use std::collections::HashSet;
 
+use vortex::array::Bool;
 use vortex::encoding::EncodingRef;
-use vortex::{Array, ArrayDType, ArrayDef, IntoArray, IntoArrayVariant};
-use vortex_dtype::DType;
-use vortex_dtype::Nullability::NonNullable;
+use vortex::{Array, ArrayDef, IntoArray, IntoArrayVariant};
 use vortex_error::VortexResult;
 use vortex_roaring::{roaring_bool_encode, RoaringBool, RoaringBoolEncoding};
 
@@ -18,14 +17,13 @@ impl EncodingCompressor for RoaringBoolCompressor {
         RoaringBool::ID.as_ref()
     }
 
+    fn decode_throughput(&self) -> f32 {
+        0.5
+    }
+
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
         // Only support bool enc arrays
-        if array.encoding().id() != RoaringBool::ID {
-            return None;
-        }
-
-        // Only support non-nullable bool arrays
-        if array.dtype() != &DType::Bool(NonNullable) {
+        if array.encoding().id() != Bool::ID {
             return None;
         }
 
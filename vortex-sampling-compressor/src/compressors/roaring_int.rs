@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use vortex::array::Primitive;
 use vortex::encoding::EncodingRef;
 use vortex::stats::ArrayStatistics;
 use vortex::{Array, ArrayDType, ArrayDef, IntoArray, IntoArrayVariant};
@@ -17,14 +18,18 @@ impl EncodingCompressor for RoaringIntCompressor {
         RoaringInt::ID.as_ref()
     }
 
+    fn decode_throughput(&self) -> f32 {
+        0.5
+    }
+
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
         // Only support primitive enc arrays
-        if array.encoding().id() != RoaringInt::ID {
+        if array.encoding().id() != Primitive::ID {
             return None;
         }
 
-        // Only support non-nullable uint arrays
-        if !array.dtype().is_unsigned_int() || array.dtype().is_nullable() {
+        // Only support uint arrays
+        if !array.dtype().is_unsigned_int() {
             return None;
         }
 
@@ -27,6 +27,10 @@ impl EncodingCompressor for RunEndCompressor {
         1
     }
 
+    fn decode_throughput(&self) -> f32 {
+        4.0
+    }
+
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
         if array.encoding().id() != Primitive::ID {
             return None;
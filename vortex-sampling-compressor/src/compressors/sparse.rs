@@ -20,6 +20,10 @@ impl EncodingCompressor for SparseCompressor {
         0
     }
 
+    fn decode_throughput(&self) -> f32 {
+        2.0
+    }
+
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
         (array.encoding().id() == Sparse::ID).then_some(self)
     }
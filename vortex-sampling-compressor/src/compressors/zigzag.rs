@@ -22,6 +22,10 @@ impl EncodingCompressor for ZigZagCompressor {
         0
     }
 
+    fn decode_throughput(&self) -> f32 {
+        16.0
+    }
+
     fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
         // Only support primitive arrays
         let parray = PrimitiveArray::try_from(array).ok()?;
@@ -47,8 +51,9 @@ impl EncodingCompressor for ZigZagCompressor {
         ctx: SamplingCompressor<'a>,
     ) -> VortexResult<CompressedArray<'a>> {
         let encoded = zigzag_encode(&array.as_primitive())?;
-        let compressed =
-            ctx.compress(&encoded.encoded(), like.as_ref().and_then(|l| l.child(0)))?;
+        let compressed = ctx
+            .named("encoded")
+            .compress(&encoded.encoded(), like.as_ref().and_then(|l| l.child(0)))?;
         Ok(CompressedArray::new(
             ZigZagArray::new(compressed.array).into_array(),
             Some(CompressionTree::new(self, vec![compressed.path])),
@@ -0,0 +1,249 @@
+use std::collections::{HashMap, HashSet};
+
+use vortex_dtype::field::FieldPath;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::objective::Objective;
+
+#[derive(Debug, Clone)]
+pub struct CompressConfig {
+    /// Arrays longer than this are split into chunks of this length before compression.
+    block_size: u32,
+    sample_size: u16,
+    sample_count: u16,
+    max_depth: u8,
+    /// How much worse, relative to the ratio achieved on the chunk it was found for, the
+    /// compression of a chunk like the previous chunk may be before the chunk is sampled again.
+    reuse_drift_threshold: f32,
+    /// Compress the fields of struct arrays and the chunks of chunked arrays concurrently. Only
+    /// takes effect with the `rayon` feature enabled.
+    parallel: bool,
+    objective: Objective,
+    /// Seeds the choice of the samples of each array, making compression deterministic. Samples
+    /// are chosen at random if `None`.
+    rng_seed: Option<u64>,
+    column_overrides: HashMap<FieldPath, ColumnOverride>,
+}
+
+impl Default for CompressConfig {
+    fn default() -> Self {
+        Self {
+            block_size: 65_536,
+            // Sample length should always be multiple of 1024
+            sample_size: 128,
+            sample_count: 8,
+            max_depth: 3,
+            reuse_drift_threshold: 0.1,
+            parallel: false,
+            objective: Objective::default(),
+            rng_seed: Some(0),
+            column_overrides: HashMap::new(),
+        }
+    }
+}
+
+impl CompressConfig {
+    pub fn builder() -> CompressConfigBuilder {
+        CompressConfigBuilder::default()
+    }
+
+    pub fn block_size(&self) -> u32 {
+        self.block_size
+    }
+
+    pub fn sample_size(&self) -> u16 {
+        self.sample_size
+    }
+
+    pub fn sample_count(&self) -> u16 {
+        self.sample_count
+    }
+
+    pub fn max_depth(&self) -> u8 {
+        self.max_depth
+    }
+
+    pub fn reuse_drift_threshold(&self) -> f32 {
+        self.reuse_drift_threshold
+    }
+
+    pub fn parallel(&self) -> bool {
+        self.parallel
+    }
+
+    pub fn objective(&self) -> &Objective {
+        &self.objective
+    }
+
+    pub fn rng_seed(&self) -> Option<u64> {
+        self.rng_seed
+    }
+
+    /// The override for the column at `path`, if any.
+    pub fn column_override(&self, path: &FieldPath) -> Option<&ColumnOverride> {
+        self.column_overrides.get(path)
+    }
+}
+
+/// Builds a [`CompressConfig`], starting from the defaults.
+#[derive(Debug, Clone, Default)]
+pub struct CompressConfigBuilder {
+    config: CompressConfig,
+}
+
+impl CompressConfigBuilder {
+    pub fn block_size(mut self, block_size: u32) -> Self {
+        self.config.block_size = block_size;
+        self
+    }
+
+    pub fn sample_size(mut self, sample_size: u16) -> Self {
+        self.config.sample_size = sample_size;
+        self
+    }
+
+    pub fn sample_count(mut self, sample_count: u16) -> Self {
+        self.config.sample_count = sample_count;
+        self
+    }
+
+    pub fn max_depth(mut self, max_depth: u8) -> Self {
+        self.config.max_depth = max_depth;
+        self
+    }
+
+    pub fn reuse_drift_threshold(mut self, reuse_drift_threshold: f32) -> Self {
+        self.config.reuse_drift_threshold = reuse_drift_threshold;
+        self
+    }
+
+    pub fn parallel(mut self, parallel: bool) -> Self {
+        self.config.parallel = parallel;
+        self
+    }
+
+    pub fn objective(mut self, objective: Objective) -> Self {
+        self.config.objective = objective;
+        self
+    }
+
+    pub fn rng_seed(mut self, rng_seed: Option<u64>) -> Self {
+        self.config.rng_seed = rng_seed;
+        self
+    }
+
+    /// Override how the column at `path` of a struct array is compressed, replacing any previous
+    /// override of that column.
+    pub fn column_override(mut self, path: FieldPath, column: ColumnOverride) -> Self {
+        self.config.column_overrides.insert(path, column);
+        self
+    }
+
+    pub fn build(self) -> VortexResult<CompressConfig> {
+        let config = self.config;
+        if config.block_size == 0 || config.sample_size == 0 || config.sample_count == 0 {
+            vortex_bail!(InvalidArgument: "block_size, sample_size and sample_count must be positive");
+        }
+        if config.sample_size as u32 * config.sample_count as u32 > config.block_size {
+            vortex_bail!(
+                InvalidArgument: "sample_size {} * sample_count {} exceeds block_size {}",
+                config.sample_size,
+                config.sample_count,
+                config.block_size
+            );
+        }
+        if config.reuse_drift_threshold.is_nan() || config.reuse_drift_threshold < 0.0 {
+            vortex_bail!(
+                InvalidArgument: "reuse_drift_threshold must not be negative, got {}",
+                config.reuse_drift_threshold
+            );
+        }
+        for (path, column) in &config.column_overrides {
+            if let Some(forced) = &column.forced {
+                if column.disabled.contains(forced) {
+                    vortex_bail!(
+                        InvalidArgument: "column {} both forces and disables {}",
+                        path,
+                        forced
+                    );
+                }
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Compressor choices for a single column, identified by compressor id.
+///
+/// Disabled compressors are not used anywhere within the column, while a forced compressor is
+/// used for the column itself whenever it can compress it, without sampling.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnOverride {
+    forced: Option<String>,
+    disabled: HashSet<String>,
+}
+
+impl ColumnOverride {
+    pub fn with_forced(mut self, compressor_id: &str) -> Self {
+        self.forced = Some(compressor_id.to_string());
+        self
+    }
+
+    pub fn with_disabled(mut self, compressor_id: &str) -> Self {
+        self.disabled.insert(compressor_id.to_string());
+        self
+    }
+
+    pub fn forced(&self) -> Option<&str> {
+        self.forced.as_deref()
+    }
+
+    pub fn is_disabled(&self, compressor_id: &str) -> bool {
+        self.disabled.contains(compressor_id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::field::{Field, FieldPath};
+
+    use crate::config::{ColumnOverride, CompressConfig};
+
+    #[test]
+    pub fn builder_defaults() {
+        let config = CompressConfig::builder().build().unwrap();
+        assert_eq!(config.block_size(), CompressConfig::default().block_size());
+        assert!(config
+            .column_override(&FieldPath::from(Field::from("a")))
+            .is_none());
+    }
+
+    #[test]
+    pub fn builder_rejects_invalid_config() {
+        assert!(CompressConfig::builder().block_size(0).build().is_err());
+        assert!(CompressConfig::builder().sample_count(0).build().is_err());
+        assert!(CompressConfig::builder()
+            .block_size(1024)
+            .sample_size(128)
+            .sample_count(16)
+            .build()
+            .is_err());
+        assert!(CompressConfig::builder()
+            .reuse_drift_threshold(-0.5)
+            .build()
+            .is_err());
+        assert!(CompressConfig::builder()
+            .reuse_drift_threshold(f32::NAN)
+            .build()
+            .is_err());
+        assert!(CompressConfig::builder()
+            .column_override(
+                FieldPath::from(Field::from("a")),
+                ColumnOverride::default()
+                    .with_forced("vortex.dict")
+                    .with_disabled("vortex.dict"),
+            )
+            .build()
+            .is_err());
+    }
+}
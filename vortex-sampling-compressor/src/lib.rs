@@ -1,55 +1,49 @@
 use std::collections::HashSet;
 use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hasher;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use log::{debug, info, warn};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use twox_hash::XxHash64;
 use vortex::array::{Chunked, ChunkedArray, Constant, Struct, StructArray};
 use vortex::compress::{check_dtype_unchanged, check_validity_unchanged, CompressionStrategy};
 use vortex::compute::slice;
 use vortex::encoding::EncodingRef;
+use vortex::stats::ArrayStatistics;
 use vortex::validity::Validity;
 use vortex::variants::StructArrayTrait;
 use vortex::{Array, ArrayDType, ArrayDef, IntoArray, IntoCanonical};
+use vortex_dtype::field::{Field, FieldPath};
 use vortex_error::VortexResult;
 
 use crate::compressors::alp::ALPCompressor;
 use crate::compressors::bitpacked::BitPackedCompressor;
 use crate::compressors::constant::ConstantCompressor;
 use crate::compressors::date_time_parts::DateTimePartsCompressor;
+use crate::compressors::delta::DeltaCompressor;
 use crate::compressors::dict::DictCompressor;
+use crate::compressors::fsst::FSSTCompressor;
 use crate::compressors::r#for::FoRCompressor;
-use crate::compressors::roaring_bool::RoaringBoolCompressor;
-use crate::compressors::roaring_int::RoaringIntCompressor;
 use crate::compressors::runend::DEFAULT_RUN_END_COMPRESSOR;
 use crate::compressors::sparse::SparseCompressor;
 use crate::compressors::zigzag::ZigZagCompressor;
 use crate::compressors::{CompressedArray, CompressionTree, CompressorRef, EncodingCompressor};
+pub use crate::config::{ColumnOverride, CompressConfig, CompressConfigBuilder};
+pub use crate::objective::{CandidateStats, Objective};
+use crate::report::ReportCollector;
+pub use crate::report::{ColumnReport, CompressionReport, SampleReport, TreeReport};
 use crate::sampling::stratified_slices;
 
 pub mod compressors;
+mod config;
+mod objective;
+mod report;
 mod sampling;
 
-#[derive(Debug, Clone)]
-pub struct CompressConfig {
-    #[allow(dead_code)]
-    block_size: u32,
-    sample_size: u16,
-    sample_count: u16,
-    max_depth: u8,
-}
-
-impl Default for CompressConfig {
-    fn default() -> Self {
-        // TODO(ngates): we should ensure that sample_size * sample_count <= block_size
-        Self {
-            block_size: 65_536,
-            // Sample length should always be multiple of 1024
-            sample_size: 128,
-            sample_count: 8,
-            max_depth: 3,
-        }
-    }
-}
-
 #[derive(Debug, Clone)]
 pub struct SamplingCompressor<'a> {
     compressors: HashSet<CompressorRef<'a>>,
@@ -59,6 +53,26 @@ pub struct SamplingCompressor<'a> {
     depth: u8,
     /// A set of encodings disabled for this ctx.
     disabled_compressors: HashSet<CompressorRef<'a>>,
+    /// The path of the struct field being compressed.
+    field_path: FieldPath,
+    /// A compressor to use for the field without sampling, see [`ColumnOverride`].
+    forced: Option<CompressorRef<'a>>,
+    tree_reuse: Arc<TreeReuseCounts>,
+    report: Option<Arc<ReportCollector>>,
+}
+
+/// The number of chunks compressed like the chunk before them, and the number compressed after
+/// searching for the best compression from a sample.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TreeReuse {
+    pub reused: usize,
+    pub sampled: usize,
+}
+
+#[derive(Debug, Default)]
+struct TreeReuseCounts {
+    reused: AtomicUsize,
+    sampled: AtomicUsize,
 }
 
 impl Display for SamplingCompressor<'_> {
@@ -86,13 +100,13 @@ impl Default for SamplingCompressor<'_> {
         Self::new(HashSet::from([
             &ALPCompressor as CompressorRef,
             &BitPackedCompressor,
-            // TODO(robert): Implement minimal compute for DeltaArrays - scalar_at and slice
-            // &DeltaCompressor,
+            &DeltaCompressor,
             &DictCompressor,
             &FoRCompressor,
+            &FSSTCompressor,
             &DateTimePartsCompressor,
-            &RoaringBoolCompressor,
-            &RoaringIntCompressor,
+            // The roaring compressors are opt-in until their size and decode time have been
+            // benchmarked against the other encodings.
             &DEFAULT_RUN_END_COMPRESSOR,
             &SparseCompressor,
             &ZigZagCompressor,
@@ -115,12 +129,17 @@ impl<'a> SamplingCompressor<'a> {
             path: Vec::new(),
             depth: 0,
             disabled_compressors: HashSet::new(),
+            field_path: FieldPath::root(),
+            forced: None,
+            tree_reuse: Arc::default(),
+            report: None,
         }
     }
 
     pub fn named(&self, name: &str) -> Self {
         let mut cloned = self.clone();
         cloned.path.push(name.into());
+        cloned.forced = None;
         cloned
     }
 
@@ -130,6 +149,39 @@ impl<'a> SamplingCompressor<'a> {
         let mut cloned = self.clone();
         cloned.path.push(name.into());
         cloned.disabled_compressors = HashSet::new();
+        cloned.forced = None;
+        cloned
+    }
+
+    /// Returns a new ctx for compressing the field `name` of a struct array, applying any
+    /// [`ColumnOverride`] configured for it.
+    fn for_field(&self, name: &str) -> Self {
+        let mut cloned = self.clone();
+        cloned.field_path = self
+            .field_path
+            .path()
+            .iter()
+            .cloned()
+            .chain([Field::from(name)])
+            .collect();
+        cloned.forced = None;
+        if let Some(report) = &self.report {
+            report.add_column(&cloned.field_path);
+        }
+
+        if let Some(column) = self.options.column_override(&cloned.field_path) {
+            cloned.compressors.retain(|c| !column.is_disabled(c.id()));
+            cloned.forced = column.forced().and_then(|id| {
+                let forced = cloned.compressors.iter().find(|c| c.id() == id).copied();
+                if forced.is_none() {
+                    warn!(
+                        "{} cannot force unknown compressor {} for {}",
+                        self, id, cloned.field_path
+                    );
+                }
+                forced
+            });
+        }
         cloned
     }
 
@@ -144,6 +196,15 @@ impl<'a> SamplingCompressor<'a> {
         &self.options
     }
 
+    /// How often chunks of chunked arrays compressed by this compressor, or any context derived
+    /// from it, reused the compression tree of the previous chunk.
+    pub fn tree_reuse(&self) -> TreeReuse {
+        TreeReuse {
+            reused: self.tree_reuse.reused.load(Ordering::Relaxed),
+            sampled: self.tree_reuse.sampled.load(Ordering::Relaxed),
+        }
+    }
+
     pub fn excluding(&self, compressor: CompressorRef<'a>) -> Self {
         let mut cloned = self.clone();
         cloned.disabled_compressors.insert(compressor);
@@ -177,16 +238,35 @@ impl<'a> SamplingCompressor<'a> {
         }
 
         // Otherwise, attempt to compress the array
-        let compressed = self.compress_array(arr)?;
+        let compressed = if self.path.is_empty() {
+            self.compress_column(arr)?
+        } else {
+            self.compress_array(arr)?
+        };
 
         check_validity_unchanged(arr, compressed.array());
         check_dtype_unchanged(arr, compressed.array());
         Ok(compressed)
     }
 
+    /// Compress `array` like [`SamplingCompressor::compress`], also reporting what was done to
+    /// each of its columns.
+    pub fn compress_with_report(
+        &self,
+        array: &Array,
+    ) -> VortexResult<(CompressedArray<'a>, CompressionReport)> {
+        let mut ctx = self.clone();
+        let report = Arc::new(ReportCollector::default());
+        ctx.report = Some(report.clone());
+        let compressed = ctx.compress(array, None)?;
+        Ok((compressed, report.finish()))
+    }
+
     pub fn compress_validity(&self, validity: Validity) -> VortexResult<Validity> {
         match validity {
-            Validity::Array(a) => Ok(Validity::Array(self.compress(&a, None)?.into_array())),
+            Validity::Array(a) => Ok(Validity::Array(
+                self.named("validity").compress(&a, None)?.into_array(),
+            )),
             a => Ok(a),
         }
     }
@@ -194,14 +274,39 @@ impl<'a> SamplingCompressor<'a> {
     fn compress_array(&self, arr: &Array) -> VortexResult<CompressedArray<'a>> {
         match arr.encoding().id() {
             Chunked::ID => {
-                // For chunked arrays, we compress each chunk individually
+                // For chunked arrays, we compress each chunk individually. Chunks tend to be alike,
+                // so each chunk is first compressed like the previous one.
                 let chunked = ChunkedArray::try_from(arr)?;
-                let compressed_chunks = chunked
-                    .chunks()
-                    .map(|chunk| self.compress_array(&chunk).map(|a| a.into_array()))
-                    .collect::<VortexResult<Vec<_>>>()?;
+                let chunks = self.split_blocks(chunked.chunks())?;
+                let mut previous = PreviousChunk::None;
+                let compressed_chunks = if self.parallel() {
+                    // Chunks compressed concurrently cannot follow the chunk before them, so all
+                    // of them start from the tree of the first chunk.
+                    let mut chunks = chunks.into_iter();
+                    let first = chunks
+                        .next()
+                        .map(|chunk| self.compress_chunk(&chunk, &mut previous))
+                        .transpose()?;
+                    let rest = self.map_fields_or_chunks(chunks.collect(), |chunk| {
+                        self.compress_chunk(&chunk, &mut previous.clone())
+                    })?;
+                    first.into_iter().chain(rest).collect()
+                } else {
+                    chunks
+                        .iter()
+                        .map(|chunk| self.compress_chunk(chunk, &mut previous))
+                        .collect::<VortexResult<Vec<_>>>()?
+                };
+                self.report_trees(compressed_chunks.iter().map(|c| c.path().as_ref()));
                 Ok(CompressedArray::uncompressed(
-                    ChunkedArray::try_new(compressed_chunks, chunked.dtype().clone())?.into_array(),
+                    ChunkedArray::try_new(
+                        compressed_chunks
+                            .into_iter()
+                            .map(|c| c.into_array())
+                            .collect(),
+                        chunked.dtype().clone(),
+                    )?
+                    .into_array(),
                 ))
             }
             Constant::ID => {
@@ -211,10 +316,15 @@ impl<'a> SamplingCompressor<'a> {
             Struct::ID => {
                 // For struct arrays, we compress each field individually
                 let strct = StructArray::try_from(arr)?;
-                let compressed_fields = strct
-                    .children()
-                    .map(|field| self.compress_array(&field).map(|a| a.into_array()))
-                    .collect::<VortexResult<Vec<_>>>()?;
+                let fields = strct
+                    .names()
+                    .iter()
+                    .map(|name| self.for_field(name))
+                    .zip(strct.children())
+                    .collect();
+                let compressed_fields = self.map_fields_or_chunks(fields, |(ctx, field)| {
+                    ctx.compress_column(&field).map(|a| a.into_array())
+                })?;
                 let validity = self.compress_validity(strct.validity())?;
                 Ok(CompressedArray::uncompressed(
                     StructArray::try_new(
@@ -226,6 +336,12 @@ impl<'a> SamplingCompressor<'a> {
                     .into_array(),
                 ))
             }
+            _ if self.path.is_empty() && arr.len() > self.options.block_size() as usize => {
+                // Columns longer than a block are compressed one block at a time
+                self.compress_array(
+                    &ChunkedArray::try_new(vec![arr.clone()], arr.dtype().clone())?.into_array(),
+                )
+            }
             _ => {
                 // Otherwise, we run sampled compression over pluggable encodings
                 let sampled = sampled_compression(arr, self)?;
@@ -233,12 +349,241 @@ impl<'a> SamplingCompressor<'a> {
             }
         }
     }
+
+    /// Compress a column, i.e. a field of a struct array or an array that is not part of one,
+    /// recording its totals in the report.
+    fn compress_column(&self, arr: &Array) -> VortexResult<CompressedArray<'a>> {
+        self.report_column(arr, || self.compress_array(arr))
+    }
+
+    /// Compress a column with `compress`, recording its totals in the report.
+    fn report_column(
+        &self,
+        arr: &Array,
+        compress: impl FnOnce() -> VortexResult<CompressedArray<'a>>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        let Some(report) = self
+            .report
+            .as_ref()
+            .filter(|_| arr.encoding().id() != Struct::ID)
+        else {
+            let compressed = compress()?;
+            compressed.array().inherit_statistics(arr.statistics());
+            return Ok(compressed);
+        };
+
+        let start = Instant::now();
+        let compressed = compress()?;
+        compressed.array().inherit_statistics(arr.statistics());
+        // The trees of chunked columns are reported for each chunk.
+        if compressed.array().encoding().id() != Chunked::ID {
+            self.report_trees([compressed.path().as_ref()]);
+        }
+        report.add_totals(
+            &self.field_path,
+            arr.nbytes(),
+            compressed.nbytes(),
+            start.elapsed(),
+        );
+        Ok(compressed)
+    }
+
+    /// Record the trees a column, or each of its chunks, was compressed with.
+    fn report_trees<'t>(&self, trees: impl IntoIterator<Item = Option<&'t CompressionTree<'t>>>) {
+        if let Some(report) = self.report.as_ref().filter(|_| self.path.is_empty()) {
+            report.add_trees(&self.field_path, trees);
+        }
+    }
+
+    /// The random number generator to choose samples with. Seeded RNGs depend on the position of
+    /// the array being compressed rather than on the order arrays are compressed in, so that the
+    /// samples do not change with parallel compression. Both the hash and the RNG algorithm are
+    /// fixed, so the samples do not change across platforms or Rust versions either.
+    fn sampling_rng(&self) -> ChaCha8Rng {
+        match self.options.rng_seed() {
+            Some(seed) => {
+                let mut hasher = XxHash64::with_seed(0);
+                hasher.write(&seed.to_le_bytes());
+                hasher.write(self.field_path.to_string().as_bytes());
+                for compressor in &self.path {
+                    hasher.write(&[0xff]);
+                    hasher.write(compressor.as_bytes());
+                }
+                ChaCha8Rng::seed_from_u64(hasher.finish())
+            }
+            None => ChaCha8Rng::from_entropy(),
+        }
+    }
+
+    fn parallel(&self) -> bool {
+        cfg!(feature = "rayon") && self.options.parallel()
+    }
+
+    /// Apply `f` to each of `items`, concurrently if parallel compression is enabled, keeping the
+    /// results in the order of the items.
+    fn map_fields_or_chunks<T, R, F>(&self, items: Vec<T>, f: F) -> VortexResult<Vec<R>>
+    where
+        T: Send,
+        R: Send,
+        F: Fn(T) -> VortexResult<R> + Send + Sync,
+    {
+        #[cfg(feature = "rayon")]
+        if self.parallel() {
+            use rayon::prelude::*;
+
+            return items.into_par_iter().map(f).collect();
+        }
+
+        items.into_iter().map(f).collect()
+    }
+
+    /// Split chunks of columns that are longer than the block size into blocks.
+    fn split_blocks(&self, chunks: impl Iterator<Item = Array>) -> VortexResult<Vec<Array>> {
+        let block_size = self.options.block_size() as usize;
+        if !self.path.is_empty() {
+            return Ok(chunks.collect());
+        }
+
+        let mut blocks = Vec::new();
+        for chunk in chunks {
+            if chunk.len() <= block_size {
+                blocks.push(chunk);
+                continue;
+            }
+            for start in (0..chunk.len()).step_by(block_size) {
+                blocks.push(slice(&chunk, start, (start + block_size).min(chunk.len()))?);
+            }
+        }
+        Ok(blocks)
+    }
+
+    /// Compress a chunk of a chunked array, like the previous chunk if it compresses about as well,
+    /// otherwise by sampling it and recording its tree in `previous` for the next chunk.
+    fn compress_chunk(
+        &self,
+        chunk: &Array,
+        previous: &mut PreviousChunk<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        if chunk.is_empty() {
+            return Ok(CompressedArray::uncompressed(chunk.clone()));
+        }
+        if chunk.encoding().id() == Struct::ID {
+            return self.compress_struct_chunk(chunk, previous);
+        }
+        if let PreviousChunk::Tree(tree, ratio) = previous {
+            if let Some(compressed) = self.compress_like(chunk, tree, *ratio)? {
+                self.tree_reuse.reused.fetch_add(1, Ordering::Relaxed);
+                compressed.array().inherit_statistics(chunk.statistics());
+                return Ok(compressed);
+            }
+        }
+
+        let compressed = self.compress_array(chunk)?;
+        compressed.array().inherit_statistics(chunk.statistics());
+        self.tree_reuse.sampled.fetch_add(1, Ordering::Relaxed);
+        let ratio = compressed.nbytes() as f32 / chunk.nbytes() as f32;
+        *previous = compressed
+            .path()
+            .clone()
+            .map_or(PreviousChunk::None, |tree| PreviousChunk::Tree(tree, ratio));
+        Ok(compressed)
+    }
+
+    /// Compress each field of a struct chunk like the same field of the previous chunk, see
+    /// [`SamplingCompressor::compress_chunk`].
+    fn compress_struct_chunk(
+        &self,
+        chunk: &Array,
+        previous: &mut PreviousChunk<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        let strct = StructArray::try_from(chunk)?;
+        let previous_fields = match previous {
+            PreviousChunk::Fields(fields) if fields.len() == strct.nfields() => fields,
+            _ => {
+                *previous = PreviousChunk::Fields(vec![PreviousChunk::None; strct.nfields()]);
+                let PreviousChunk::Fields(fields) = previous else {
+                    unreachable!("previous chunk was just set to fields")
+                };
+                fields
+            }
+        };
+
+        let fields = strct
+            .names()
+            .iter()
+            .map(|name| self.for_field(name))
+            .zip(strct.children())
+            .zip(previous_fields.iter_mut())
+            .collect();
+        let compressed_fields =
+            self.map_fields_or_chunks(fields, |((ctx, field), previous_field)| {
+                ctx.report_column(&field, || ctx.compress_chunk(&field, previous_field))
+                    .map(|a| a.into_array())
+            })?;
+        let validity = self.compress_validity(strct.validity())?;
+        Ok(CompressedArray::uncompressed(
+            StructArray::try_new(
+                strct.names().clone(),
+                compressed_fields,
+                strct.len(),
+                validity,
+            )?
+            .into_array(),
+        ))
+    }
+
+    /// Compress `chunk` like the previous chunk, returning `None` if the tree does not apply or
+    /// compresses noticeably worse than the `ratio` it achieved on the chunk it was found for.
+    fn compress_like(
+        &self,
+        chunk: &Array,
+        tree: &CompressionTree<'a>,
+        ratio: f32,
+    ) -> VortexResult<Option<CompressedArray<'a>>> {
+        let Some(compressed) = tree.compress(chunk, self) else {
+            return Ok(None);
+        };
+        let compressed = compressed?;
+
+        let chunk_ratio = compressed.nbytes() as f32 / chunk.nbytes() as f32;
+        if chunk_ratio > ratio * (1.0 + self.options.reuse_drift_threshold()) {
+            debug!(
+                "{} ratio of {} like previous chunk drifted from {} to {}",
+                self, tree, ratio, chunk_ratio
+            );
+            return Ok(None);
+        }
+
+        check_validity_unchanged(chunk, compressed.array());
+        check_dtype_unchanged(chunk, compressed.array());
+        Ok(Some(compressed))
+    }
+}
+
+/// How the previous chunk of a chunked array was compressed, for the next chunk to follow.
+#[derive(Debug, Clone)]
+enum PreviousChunk<'a> {
+    None,
+    /// The tree of the chunk and the compression ratio it achieved.
+    Tree(CompressionTree<'a>, f32),
+    /// How each field of a struct chunk was compressed.
+    Fields(Vec<PreviousChunk<'a>>),
 }
 
 fn sampled_compression<'a>(
     array: &Array,
     compressor: &SamplingCompressor<'a>,
 ) -> VortexResult<Option<CompressedArray<'a>>> {
+    // A compressor forced for the column takes precedence over any other.
+    if let Some(forced) = compressor
+        .forced
+        .and_then(|forced| forced.can_compress(array))
+    {
+        return forced
+            .compress(array, None, compressor.for_compressor(forced))
+            .map(Some);
+    }
+
     // First, we try constant compression and shortcut any sampling.
     if let Some(cc) = ConstantCompressor.can_compress(array) {
         return cc.compress(array, None, compressor.clone()).map(Some);
@@ -250,7 +595,7 @@ fn sampled_compression<'a>(
         .filter(|&encoding| !compressor.disabled_compressors.contains(encoding))
         .filter(|compression| {
             if compression.can_compress(array).is_some() {
-                if compressor.depth + compression.cost() > compressor.options.max_depth {
+                if compressor.depth + compression.cost() > compressor.options.max_depth() {
                     debug!(
                         "{} skipping encoding {} due to depth",
                         compressor,
@@ -289,7 +634,7 @@ fn sampled_compression<'a>(
     }
 
     if array.len()
-        <= (compressor.options.sample_size as usize * compressor.options.sample_count as usize)
+        <= (compressor.options.sample_size() as usize * compressor.options.sample_count() as usize)
     {
         // We're either already within a sample, or we're operating over a sufficiently small array.
         return find_best_compression(candidates, array, compressor).map(Some);
@@ -299,8 +644,9 @@ fn sampled_compression<'a>(
     let sample = ChunkedArray::try_new(
         stratified_slices(
             array.len(),
-            compressor.options.sample_size,
-            compressor.options.sample_count,
+            compressor.options.sample_size(),
+            compressor.options.sample_count(),
+            &mut compressor.sampling_rng(),
         )
         .into_iter()
         .map(|(start, stop)| slice(array, start, stop))
@@ -324,8 +670,15 @@ fn find_best_compression<'a>(
     sample: &Array,
     ctx: &SamplingCompressor<'a>,
 ) -> VortexResult<CompressedArray<'a>> {
-    let mut best = None;
-    let mut best_ratio = 1.0;
+    let objective = ctx.options.objective();
+    let mut best: Option<CompressedArray<'a>> = None;
+    let mut best_score = objective.score(&CandidateStats {
+        tree: None,
+        uncompressed_bytes: sample.nbytes(),
+        compressed_bytes: sample.nbytes(),
+        decode_time: Duration::ZERO,
+        estimated_decode_time: Duration::ZERO,
+    });
     for compression in candidates {
         debug!(
             "{} trying candidate {} for {}",
@@ -338,10 +691,45 @@ fn find_best_compression<'a>(
         }
         let compressed_sample =
             compression.compress(sample, None, ctx.for_compressor(compression))?;
-        let ratio = compressed_sample.nbytes() as f32 / sample.nbytes() as f32;
-        debug!("{} ratio for {}: {}", ctx, compression.id(), ratio);
-        if ratio < best_ratio {
-            best_ratio = ratio;
+        let decode_time = if objective.measures_decode_time() {
+            let start = Instant::now();
+            compressed_sample.array().clone().into_canonical()?;
+            start.elapsed()
+        } else {
+            Duration::ZERO
+        };
+        let score = objective.score(&CandidateStats {
+            tree: compressed_sample.path().as_ref(),
+            uncompressed_bytes: sample.nbytes(),
+            compressed_bytes: compressed_sample.nbytes(),
+            decode_time,
+            estimated_decode_time: compressed_sample
+                .path()
+                .as_ref()
+                .map(|tree| tree.estimated_decode_time(sample.nbytes()))
+                .unwrap_or_default(),
+        });
+        debug!("{} score for {}: {}", ctx, compression.id(), score);
+        if let Some(report) = &ctx.report {
+            report.add_sample(
+                &ctx.field_path,
+                SampleReport {
+                    path: ctx.path.join("."),
+                    tree: compressed_sample.path().as_ref().map(TreeReport::from),
+                    ratio: compressed_sample.nbytes() as f64 / sample.nbytes() as f64,
+                    score,
+                },
+            );
+        }
+        // Break ties by compressor id, so the choice does not depend on the order of candidates.
+        let better = score < best_score
+            || (score == best_score
+                && best
+                    .as_ref()
+                    .and_then(|b| b.path().as_ref())
+                    .is_some_and(|tree| compression.id() < tree.compressor().id()));
+        if better {
+            best_score = score;
             best = Some(compressed_sample)
         }
     }
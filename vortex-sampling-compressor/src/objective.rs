@@ -0,0 +1,83 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::compressors::CompressionTree;
+
+/// What the sampling compressor minimises when choosing between compressions of a sample.
+///
+/// Leaving the sample uncompressed is scored as well, and a compression is only chosen if it
+/// scores lower.
+#[derive(Clone, Default)]
+pub enum Objective {
+    /// The compressed size of the sample relative to its uncompressed size.
+    #[default]
+    MinSize,
+    /// The relative compressed size, plus `decode_weight` times the nanoseconds spent decoding the
+    /// compressed sample per uncompressed byte.
+    ///
+    /// The decode time is measured, so the choice may vary between runs. Use
+    /// [`Objective::SizeAndEstimatedDecodeTime`] where the output must be reproducible.
+    SizeAndDecodeTime { decode_weight: f64 },
+    /// Like [`Objective::SizeAndDecodeTime`], but with the decode time estimated from the
+    /// throughput of the compressors in the tree, so the choice is deterministic.
+    SizeAndEstimatedDecodeTime { decode_weight: f64 },
+    /// A score computed by the caller.
+    Custom(Arc<dyn Fn(&CandidateStats) -> f64 + Send + Sync>),
+}
+
+impl Debug for Objective {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MinSize => write!(f, "MinSize"),
+            Self::SizeAndDecodeTime { decode_weight } => f
+                .debug_struct("SizeAndDecodeTime")
+                .field("decode_weight", decode_weight)
+                .finish(),
+            Self::SizeAndEstimatedDecodeTime { decode_weight } => f
+                .debug_struct("SizeAndEstimatedDecodeTime")
+                .field("decode_weight", decode_weight)
+                .finish(),
+            Self::Custom(_) => write!(f, "Custom"),
+        }
+    }
+}
+
+impl Objective {
+    /// Whether scoring needs the time taken to decode each candidate.
+    pub(crate) fn measures_decode_time(&self) -> bool {
+        matches!(self, Self::SizeAndDecodeTime { .. } | Self::Custom(_))
+    }
+
+    pub(crate) fn score(&self, stats: &CandidateStats) -> f64 {
+        let relative_size = stats.compressed_bytes as f64 / stats.uncompressed_bytes as f64;
+        match self {
+            Self::MinSize => relative_size,
+            Self::SizeAndDecodeTime { decode_weight } => {
+                relative_size
+                    + decode_weight * stats.decode_time.as_nanos() as f64
+                        / stats.uncompressed_bytes as f64
+            }
+            Self::SizeAndEstimatedDecodeTime { decode_weight } => {
+                relative_size
+                    + decode_weight * stats.estimated_decode_time.as_nanos() as f64
+                        / stats.uncompressed_bytes as f64
+            }
+            Self::Custom(score) => score(stats),
+        }
+    }
+}
+
+/// A candidate compression of a sample, as seen by an [`Objective`].
+#[derive(Debug)]
+pub struct CandidateStats<'a> {
+    /// The tree the sample was compressed with, or `None` if it is left uncompressed.
+    pub tree: Option<&'a CompressionTree<'a>>,
+    pub uncompressed_bytes: usize,
+    pub compressed_bytes: usize,
+    /// The wall-clock time taken to decode the compressed sample, which varies between runs. Only
+    /// measured for [`Objective::SizeAndDecodeTime`] and [`Objective::Custom`].
+    pub decode_time: Duration,
+    /// The decode time estimated from the throughput of the compressors in the tree.
+    pub estimated_decode_time: Duration,
+}
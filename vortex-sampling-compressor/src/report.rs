@@ -0,0 +1,138 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use vortex_dtype::field::FieldPath;
+
+use crate::compressors::CompressionTree;
+
+/// What the sampling compressor did to each column of an array, see
+/// [`SamplingCompressor::compress_with_report`](crate::SamplingCompressor::compress_with_report).
+///
+/// Columns are the fields of struct arrays, in schema order, or the array itself if it is not a
+/// struct array.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CompressionReport {
+    pub columns: Vec<ColumnReport>,
+}
+
+impl CompressionReport {
+    /// The report of the column at `field_path`, if it was compressed.
+    pub fn column(&self, field_path: &FieldPath) -> Option<&ColumnReport> {
+        let field_path = field_path.to_string();
+        self.columns.iter().find(|c| c.field_path == field_path)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ColumnReport {
+    pub field_path: String,
+    /// The tree chosen for each chunk of the column, or `None` for chunks left uncompressed. Columns
+    /// that are not chunked have a single tree.
+    pub trees: Vec<Option<TreeReport>>,
+    pub input_bytes: usize,
+    pub output_bytes: usize,
+    /// The compressions tried on samples of the column and of the arrays it was encoded into.
+    pub samples: Vec<SampleReport>,
+    pub elapsed: Duration,
+}
+
+/// A serializable [`CompressionTree`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TreeReport {
+    pub compressor: String,
+    pub children: Vec<Option<TreeReport>>,
+}
+
+impl From<&CompressionTree<'_>> for TreeReport {
+    fn from(tree: &CompressionTree<'_>) -> Self {
+        Self {
+            compressor: tree.compressor().id().to_string(),
+            children: tree
+                .children()
+                .iter()
+                .map(|child| child.as_ref().map(Self::from))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SampleReport {
+    /// The path of the sampled array within the column, e.g. `dict.codes`.
+    pub path: String,
+    pub tree: Option<TreeReport>,
+    /// The compressed size of the sample relative to its uncompressed size.
+    pub ratio: f64,
+    /// The score of the compression under the configured [`Objective`](crate::Objective).
+    pub score: f64,
+}
+
+/// Collects the [`ColumnReport`]s of a compression, from any thread compressing its columns.
+#[derive(Debug, Default)]
+pub(crate) struct ReportCollector {
+    columns: Mutex<Vec<ColumnReport>>,
+}
+
+impl ReportCollector {
+    /// Add the column at `field_path` to the report, if it is not part of it yet.
+    pub fn add_column(&self, field_path: &FieldPath) {
+        self.with_column(field_path, |_| {});
+    }
+
+    pub fn add_trees<'a>(
+        &self,
+        field_path: &FieldPath,
+        trees: impl IntoIterator<Item = Option<&'a CompressionTree<'a>>>,
+    ) {
+        let trees = trees
+            .into_iter()
+            .map(|tree| tree.map(TreeReport::from))
+            .collect::<Vec<_>>();
+        self.with_column(field_path, |column| column.trees.extend(trees));
+    }
+
+    pub fn add_sample(&self, field_path: &FieldPath, sample: SampleReport) {
+        self.with_column(field_path, |column| column.samples.push(sample));
+    }
+
+    pub fn add_totals(
+        &self,
+        field_path: &FieldPath,
+        input_bytes: usize,
+        output_bytes: usize,
+        elapsed: Duration,
+    ) {
+        self.with_column(field_path, |column| {
+            column.input_bytes += input_bytes;
+            column.output_bytes += output_bytes;
+            column.elapsed += elapsed;
+        });
+    }
+
+    pub fn finish(&self) -> CompressionReport {
+        CompressionReport {
+            columns: self
+                .columns
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone(),
+        }
+    }
+
+    fn with_column(&self, field_path: &FieldPath, f: impl FnOnce(&mut ColumnReport)) {
+        let field_path = field_path.to_string();
+        let mut columns = self.columns.lock().unwrap_or_else(|e| e.into_inner());
+        let index = match columns.iter().position(|c| c.field_path == field_path) {
+            Some(index) => index,
+            None => {
+                columns.push(ColumnReport {
+                    field_path,
+                    ..Default::default()
+                });
+                columns.len() - 1
+            }
+        };
+        f(&mut columns[index]);
+    }
+}
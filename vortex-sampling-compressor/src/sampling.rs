@@ -4,6 +4,7 @@ pub fn stratified_slices(
     length: usize,
     sample_size: u16,
     sample_count: u16,
+    rng: &mut impl Rng,
 ) -> Vec<(usize, usize)> {
     let total_num_samples: usize = (sample_count * sample_size) as usize;
     if total_num_samples >= length {
@@ -24,7 +25,7 @@ pub fn stratified_slices(
                 stop - start >= size,
                 "Slices must be bigger than their sampled size"
             );
-            let random_start = rand::thread_rng().gen_range(start..=(stop - size));
+            let random_start = rng.gen_range(start..=(stop - size));
             (random_start, random_start + size)
         })
         .collect()
@@ -51,7 +52,23 @@ pub fn partition_indices(length: usize, num_partitions: u16) -> Vec<(usize, usiz
 
 #[cfg(test)]
 mod test {
-    use crate::sampling::partition_indices;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::sampling::{partition_indices, stratified_slices};
+
+    #[test]
+    pub fn seeded_slices_are_deterministic() {
+        let slices = |seed| stratified_slices(100_000, 128, 8, &mut StdRng::seed_from_u64(seed));
+        assert_eq!(slices(1), slices(1));
+        assert_ne!(slices(1), slices(2));
+        for ((start, stop), (partition_start, partition_stop)) in
+            slices(1).into_iter().zip(partition_indices(100_000, 8))
+        {
+            assert_eq!(stop - start, 128);
+            assert!(start >= partition_start && stop <= partition_stop);
+        }
+    }
 
     #[test]
     pub fn partitioning_non_even() {
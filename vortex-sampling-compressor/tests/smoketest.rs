@@ -3,26 +3,41 @@ use std::ops::Add;
 
 use chrono::TimeDelta;
 use vortex::array::builder::VarBinBuilder;
-use vortex::array::{BoolArray, PrimitiveArray, StructArray, TemporalArray};
+use vortex::array::{BoolArray, ChunkedArray, PrimitiveArray, StructArray, TemporalArray};
 use vortex::validity::Validity;
 use vortex::{Array, ArrayDType, IntoArray};
+use vortex_dtype::field::{Field, FieldPath};
 use vortex_dtype::{DType, FieldName, FieldNames, Nullability};
 use vortex_sampling_compressor::compressors::alp::ALPCompressor;
 use vortex_sampling_compressor::compressors::bitpacked::BitPackedCompressor;
 use vortex_sampling_compressor::compressors::date_time_parts::DateTimePartsCompressor;
+use vortex_sampling_compressor::compressors::delta::DeltaCompressor;
 use vortex_sampling_compressor::compressors::dict::DictCompressor;
+use vortex_sampling_compressor::compressors::fsst::FSSTCompressor;
 use vortex_sampling_compressor::compressors::r#for::FoRCompressor;
 use vortex_sampling_compressor::compressors::roaring_bool::RoaringBoolCompressor;
 use vortex_sampling_compressor::compressors::roaring_int::RoaringIntCompressor;
 use vortex_sampling_compressor::compressors::runend::DEFAULT_RUN_END_COMPRESSOR;
 use vortex_sampling_compressor::compressors::sparse::SparseCompressor;
 use vortex_sampling_compressor::compressors::zigzag::ZigZagCompressor;
-use vortex_sampling_compressor::compressors::CompressorRef;
-use vortex_sampling_compressor::{CompressConfig, SamplingCompressor};
+use vortex_sampling_compressor::compressors::{CompressorRef, EncodingCompressor};
+use vortex_sampling_compressor::{
+    ColumnOverride, CompressConfig, CompressionReport, Objective, SamplingCompressor, TreeReuse,
+};
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
+    use vortex::accessor::ArrayAccessor;
+    use vortex::array::{Primitive, VarBinArray};
+    use vortex::compute::slice;
+    use vortex::{ArrayDef, IntoCanonical};
+    use vortex_alp::ALPRD;
     use vortex_datetime_dtype::TimeUnit;
+    use vortex_dict::Dict;
+    use vortex_fsst::{FSSTArray, FSST};
+    use vortex_runend::RunEnd;
 
     use super::*;
 
@@ -33,10 +48,10 @@ mod tests {
             HashSet::from([
                 &ALPCompressor as CompressorRef,
                 &BitPackedCompressor,
-                // TODO(robert): Implement minimal compute for DeltaArrays - scalar_at and slice
-                // &DeltaCompressor,
+                &DeltaCompressor,
                 &DictCompressor,
                 &FoRCompressor,
+                &FSSTCompressor,
                 &DateTimePartsCompressor,
                 &RoaringBoolCompressor,
                 &RoaringIntCompressor,
@@ -77,6 +92,541 @@ mod tests {
         assert_eq!(compressed.dtype(), to_compress.dtype());
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn fsst_compressor_round_trip() {
+        let compressor = SamplingCompressor::new(HashSet::from([&FSSTCompressor as CompressorRef]));
+        let strings = make_log_column(4096);
+
+        let compressed = compressor.compress(&strings, None).unwrap();
+        assert_eq!(compressed.array().encoding().id(), FSST::ID);
+        assert!(compressed.nbytes() < strings.nbytes());
+        let decoded = compressed
+            .array()
+            .clone()
+            .into_canonical()
+            .unwrap()
+            .into_varbin()
+            .unwrap();
+        assert_eq!(
+            to_strings(&decoded),
+            to_strings(&VarBinArray::try_from(&strings).unwrap())
+        );
+
+        // Compressing like the first array reuses its symbol table rather than training a new one.
+        let path = compressed.path().clone().unwrap();
+        let recompressed = compressor
+            .compress(&make_log_column(1024), Some(&path))
+            .unwrap();
+        let symbols = |array: &Array| {
+            FSSTArray::try_from(array)
+                .unwrap()
+                .symbols()
+                .into_canonical()
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>()
+                .to_vec()
+        };
+        assert_eq!(symbols(recompressed.array()), symbols(compressed.array()));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn alp_rd_compressor_round_trip() {
+        let compressor = SamplingCompressor::new(HashSet::from([
+            &ALPCompressor as CompressorRef,
+            &BitPackedCompressor,
+        ]));
+        // Readings that are not decimals leave most values as ALP exceptions.
+        let values: Vec<f64> = (0..4096)
+            .map(|i| 0.05 + (i as f64 * 0.731).sin() / 700.0)
+            .collect();
+        let array = PrimitiveArray::from(values.clone()).into_array();
+
+        let compressed = compressor.compress(&array, None).unwrap();
+        assert_eq!(compressed.array().encoding().id(), ALPRD::ID);
+        assert!(compressed.nbytes() < array.nbytes());
+        let decoded = compressed
+            .array()
+            .clone()
+            .into_canonical()
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(decoded.maybe_null_slice::<f64>(), values.as_slice());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn chunked_tree_reuse() {
+        let compressor = SamplingCompressor::new(HashSet::from([
+            &BitPackedCompressor as CompressorRef,
+            &DEFAULT_RUN_END_COMPRESSOR,
+        ]));
+        let chunk = |values: Vec<u32>| PrimitiveArray::from(values).into_array();
+        // Runs of 64 equal values, so every chunk run-end encodes equally well.
+        let runs = |offset: u32| chunk((0..8192).map(|i| offset + i / 64).collect());
+
+        let chunked = ChunkedArray::try_new(
+            vec![runs(0), runs(100), runs(200), runs(300)],
+            DType::from(vortex_dtype::PType::U32),
+        )
+        .unwrap()
+        .into_array();
+        compressor.compress(&chunked, None).unwrap();
+        assert_eq!(
+            compressor.tree_reuse(),
+            TreeReuse {
+                reused: 3,
+                sampled: 1
+            }
+        );
+
+        // Distinct values in the last chunk would make run-end encoding worse than sampling again.
+        let drifted = ChunkedArray::try_new(
+            vec![runs(0), chunk((0..8192).collect())],
+            DType::from(vortex_dtype::PType::U32),
+        )
+        .unwrap()
+        .into_array();
+        let compressed = compressor.compress(&drifted, None).unwrap();
+        assert_eq!(
+            compressor.tree_reuse(),
+            TreeReuse {
+                reused: 3,
+                sampled: 3
+            }
+        );
+        let decoded = compressed
+            .into_array()
+            .into_canonical()
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(decoded.maybe_null_slice::<u32>()[8192 + 5000], 5000);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn chunked_struct_tree_reuse() {
+        use vortex::variants::StructArrayTrait;
+
+        let compressor = SamplingCompressor::new(HashSet::from([
+            &BitPackedCompressor as CompressorRef,
+            &DEFAULT_RUN_END_COMPRESSOR,
+        ]));
+        let runs = |offset: u32| {
+            PrimitiveArray::from((0..8192).map(|i| offset + i / 64).collect::<Vec<u32>>())
+                .into_array()
+        };
+        let chunk = |offset: u32| {
+            StructArray::from_fields(&[("a", runs(offset)), ("b", runs(offset + 1000))])
+                .into_array()
+        };
+
+        let chunks = vec![chunk(0), chunk(100), chunk(200)];
+        let dtype = chunks[0].dtype().clone();
+        let chunked = ChunkedArray::try_new(chunks, dtype).unwrap().into_array();
+        let compressed = compressor.compress(&chunked, None).unwrap();
+        assert_eq!(
+            compressor.tree_reuse(),
+            TreeReuse {
+                reused: 4,
+                sampled: 2
+            }
+        );
+
+        let decoded = ChunkedArray::try_from(compressed.into_array())
+            .unwrap()
+            .chunk(2)
+            .unwrap()
+            .into_canonical()
+            .unwrap()
+            .into_struct()
+            .unwrap();
+        let b = decoded
+            .field_by_name("b")
+            .unwrap()
+            .into_canonical()
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(b.maybe_null_slice::<u32>()[640], 1210);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(miri, ignore)]
+    pub fn parallel_compression_keeps_order() {
+        use vortex::variants::StructArrayTrait;
+
+        let compressor = SamplingCompressor::new_with_options(
+            HashSet::from([
+                &BitPackedCompressor as CompressorRef,
+                &DictCompressor,
+                &FoRCompressor,
+                &DEFAULT_RUN_END_COMPRESSOR,
+            ]),
+            CompressConfig::builder().parallel(true).build().unwrap(),
+        );
+
+        let chunked = |offset: u32| {
+            ChunkedArray::try_new(
+                (0..8)
+                    .map(|c| {
+                        PrimitiveArray::from(
+                            (0..4096)
+                                .map(|i| offset + c * 4096 + i / 16)
+                                .collect::<Vec<_>>(),
+                        )
+                        .into_array()
+                    })
+                    .collect(),
+                DType::from(vortex_dtype::PType::U32),
+            )
+            .unwrap()
+            .into_array()
+        };
+        let fields = (0..16).map(|f| chunked(f * 1_000_000)).collect::<Vec<_>>();
+        let names = FieldNames::from(
+            (0..16)
+                .map(|f| FieldName::from(format!("field_{f}")))
+                .collect::<Vec<_>>(),
+        );
+        let to_compress = StructArray::try_new(names, fields, 8 * 4096, Validity::NonNullable)
+            .unwrap()
+            .into_array();
+
+        let compressed = StructArray::try_from(
+            compressor
+                .compress(&to_compress, None)
+                .unwrap()
+                .into_array(),
+        )
+        .unwrap();
+        assert!(compressed.array().nbytes() < to_compress.nbytes());
+        for f in 0..16 {
+            let field = ChunkedArray::try_from(compressed.field(f).unwrap()).unwrap();
+            assert_eq!(field.nchunks(), 8);
+            let decoded = field
+                .chunk(7)
+                .unwrap()
+                .into_canonical()
+                .unwrap()
+                .into_primitive()
+                .unwrap();
+            assert_eq!(
+                decoded.maybe_null_slice::<u32>()[4095],
+                f as u32 * 1_000_000 + 7 * 4096 + 255
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn decode_time_objective() {
+        let array =
+            PrimitiveArray::from((0..8192u32).map(|i| i / 64).collect::<Vec<_>>()).into_array();
+        let compressors = HashSet::from([
+            &BitPackedCompressor as CompressorRef,
+            &DEFAULT_RUN_END_COMPRESSOR,
+        ]);
+
+        let smallest = SamplingCompressor::new(compressors.clone())
+            .compress(&array, None)
+            .unwrap();
+        assert_eq!(smallest.array().encoding().id(), RunEnd::ID);
+
+        // Weighing the estimated decode time heavily favours leaving the array uncompressed.
+        let fastest = SamplingCompressor::new_with_options(
+            compressors.clone(),
+            CompressConfig::builder()
+                .objective(Objective::Custom(Arc::new(|stats| {
+                    stats.compressed_bytes as f64 / stats.uncompressed_bytes as f64
+                        + stats.estimated_decode_time.as_nanos() as f64
+                })))
+                .build()
+                .unwrap(),
+        )
+        .compress(&array, None)
+        .unwrap();
+        assert!(fastest.path().is_none());
+        assert_eq!(fastest.array().encoding().id(), Primitive::ID);
+
+        // The built-in objectives weigh the estimated and the measured decode time.
+        for objective in [
+            Objective::SizeAndEstimatedDecodeTime {
+                decode_weight: 1_000.0,
+            },
+            Objective::SizeAndDecodeTime {
+                decode_weight: 1_000_000.0,
+            },
+        ] {
+            let weighted = SamplingCompressor::new_with_options(
+                compressors.clone(),
+                CompressConfig::builder()
+                    .objective(objective)
+                    .build()
+                    .unwrap(),
+            )
+            .compress(&array, None)
+            .unwrap();
+            assert!(weighted.path().is_none());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn score_ties_broken_by_compressor_id() {
+        let array =
+            PrimitiveArray::from((0..8192u32).map(|i| i / 64).collect::<Vec<_>>()).into_array();
+        let compressed = SamplingCompressor::new_with_options(
+            HashSet::from([
+                &DEFAULT_RUN_END_COMPRESSOR as CompressorRef,
+                &BitPackedCompressor,
+            ]),
+            CompressConfig::builder()
+                .objective(Objective::Custom(Arc::new(|stats| {
+                    if stats.tree.is_some() {
+                        0.0
+                    } else {
+                        1.0
+                    }
+                })))
+                .build()
+                .unwrap(),
+        )
+        .compress(&array, None)
+        .unwrap();
+        assert_eq!(
+            compressed.path().as_ref().unwrap().compressor().id(),
+            BitPackedCompressor.id()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn block_size_rechunks_columns() {
+        let compressor = SamplingCompressor::new_with_options(
+            HashSet::from([&BitPackedCompressor as CompressorRef, &FoRCompressor]),
+            CompressConfig::builder().block_size(4096).build().unwrap(),
+        );
+
+        let array = PrimitiveArray::from((0..10_000u32).collect::<Vec<_>>()).into_array();
+        let compressed =
+            ChunkedArray::try_from(compressor.compress(&array, None).unwrap().into_array())
+                .unwrap();
+        assert_eq!(
+            compressed.chunks().map(|c| c.len()).collect::<Vec<_>>(),
+            vec![4096, 4096, 1808]
+        );
+        let decoded = compressed
+            .into_canonical()
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(decoded.maybe_null_slice::<u32>()[9999], 9999);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn column_overrides() {
+        use vortex::variants::StructArrayTrait;
+
+        let compressor = SamplingCompressor::new_with_options(
+            HashSet::from([
+                &BitPackedCompressor as CompressorRef,
+                &DictCompressor,
+                &FoRCompressor,
+                &DEFAULT_RUN_END_COMPRESSOR,
+            ]),
+            CompressConfig::builder()
+                .column_override(
+                    FieldPath::from(Field::from("dict")),
+                    ColumnOverride::default().with_forced(DictCompressor.id()),
+                )
+                .column_override(
+                    FieldPath::from(Field::from("no_runs")),
+                    ColumnOverride::default().with_disabled(DEFAULT_RUN_END_COMPRESSOR.id()),
+                )
+                .build()
+                .unwrap(),
+        );
+
+        let runs =
+            || PrimitiveArray::from((0..8192u32).map(|i| i / 64).collect::<Vec<_>>()).into_array();
+        let to_compress = StructArray::try_new(
+            FieldNames::from(vec![
+                FieldName::from("runs"),
+                FieldName::from("dict"),
+                FieldName::from("no_runs"),
+            ]),
+            vec![runs(), runs(), runs()],
+            8192,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+
+        let compressed = StructArray::try_from(
+            compressor
+                .compress(&to_compress, None)
+                .unwrap()
+                .into_array(),
+        )
+        .unwrap();
+        assert_eq!(compressed.field(0).unwrap().encoding().id(), RunEnd::ID);
+        assert_eq!(compressed.field(1).unwrap().encoding().id(), Dict::ID);
+        assert_ne!(compressed.field(2).unwrap().encoding().id(), RunEnd::ID);
+        assert_eq!(
+            compressed
+                .field(2)
+                .unwrap()
+                .into_canonical()
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u32>()[8191],
+            127
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn compression_report() {
+        let compressor = SamplingCompressor::new(HashSet::from([
+            &BitPackedCompressor as CompressorRef,
+            &FoRCompressor,
+            &DEFAULT_RUN_END_COMPRESSOR,
+        ]));
+
+        let runs =
+            || PrimitiveArray::from((0..8192u32).map(|i| i / 64).collect::<Vec<_>>()).into_array();
+        let to_compress = StructArray::try_new(
+            FieldNames::from(vec![FieldName::from("runs"), FieldName::from("chunked")]),
+            vec![
+                runs(),
+                ChunkedArray::try_new(
+                    vec![
+                        slice(&runs(), 0, 4096).unwrap(),
+                        slice(&runs(), 4096, 8192).unwrap(),
+                    ],
+                    runs().dtype().clone(),
+                )
+                .unwrap()
+                .into_array(),
+            ],
+            8192,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+
+        let (compressed, report) = compressor.compress_with_report(&to_compress).unwrap();
+        assert_eq!(
+            report
+                .columns
+                .iter()
+                .map(|c| c.field_path.as_str())
+                .collect::<Vec<_>>(),
+            vec!["$runs", "$chunked"]
+        );
+
+        let runs_report = report
+            .column(&FieldPath::from(Field::from("runs")))
+            .unwrap();
+        assert_eq!(runs_report.trees.len(), 1);
+        assert_eq!(
+            runs_report.trees[0].as_ref().unwrap().compressor,
+            DEFAULT_RUN_END_COMPRESSOR.id()
+        );
+        assert_eq!(runs_report.input_bytes, runs().nbytes());
+        assert!(runs_report.output_bytes < runs_report.input_bytes);
+        assert!(runs_report
+            .samples
+            .iter()
+            .any(|s| s.path.is_empty() && s.ratio < 1.0));
+
+        let chunked_report = &report.columns[1];
+        assert_eq!(chunked_report.trees.len(), 2);
+        assert_eq!(
+            report.columns.iter().map(|c| c.output_bytes).sum::<usize>(),
+            compressed.nbytes()
+        );
+
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(
+            serde_json::from_str::<CompressionReport>(&json).unwrap(),
+            report
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn seeded_sampling_is_deterministic() {
+        // Values whose bit width varies along the array, so compressing different samples gives
+        // different ratios.
+        let array = PrimitiveArray::from(
+            (0..65_536u64)
+                .map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> (40 + i / 4096))
+                .collect::<Vec<_>>(),
+        )
+        .into_array();
+        let sampled = |seed| {
+            let (compressed, report) = SamplingCompressor::new_with_options(
+                HashSet::from([&BitPackedCompressor as CompressorRef, &FoRCompressor]),
+                CompressConfig::builder()
+                    .rng_seed(Some(seed))
+                    .build()
+                    .unwrap(),
+            )
+            .compress_with_report(&array)
+            .unwrap();
+            let samples = report.columns[0]
+                .samples
+                .iter()
+                .map(|s| (s.path.clone(), s.tree.clone(), s.ratio))
+                .collect::<Vec<_>>();
+            (
+                compressed.array().tree_display().to_string(),
+                report.columns[0].trees.clone(),
+                samples,
+            )
+        };
+
+        assert_eq!(sampled(7), sampled(7));
+        assert!((0..8).any(|seed| sampled(seed).2 != sampled(7).2));
+    }
+
+    fn to_strings(array: &VarBinArray) -> Vec<Option<Vec<u8>>> {
+        array
+            .with_iterator(|iter| iter.map(|v| v.map(<[u8]>::to_vec)).collect())
+            .unwrap()
+    }
+
+    fn make_log_column(count: usize) -> Array {
+        let levels = ["INFO", "WARN", "ERROR", "DEBUG"];
+        let mut builder = VarBinBuilder::<i32>::with_capacity(count);
+        for i in 0..count {
+            builder.push_value(
+                format!(
+                    "2024-07-{:02} {} request {} completed in {}ms",
+                    i % 28 + 1,
+                    levels[i % levels.len()],
+                    i,
+                    i % 97
+                )
+                .as_bytes(),
+            );
+        }
+
+        builder
+            .finish(DType::Utf8(Nullability::NonNullable))
+            .into_array()
+    }
+
     fn make_primitive_column(count: usize) -> Array {
         PrimitiveArray::from_vec(
             (0..count).map(|i| i as i64).collect::<Vec<i64>>(),
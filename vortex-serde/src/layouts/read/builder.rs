@@ -1,20 +1,26 @@
 use std::collections::HashSet;
 use std::sync::{Arc, RwLock};
+use std::time::Instant;
 
 use bytes::BytesMut;
+use vortex::array::StructArray;
 use vortex::{Array, ArrayDType};
 use vortex_dtype::field::Field;
-use vortex_error::{vortex_bail, VortexResult};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
 use crate::io::VortexReadAt;
 use crate::layouts::read::cache::{LayoutMessageCache, RelativeLayoutCache};
 use crate::layouts::read::context::LayoutDeserializer;
 use crate::layouts::read::filtering::RowFilter;
 use crate::layouts::read::footer::Footer;
+use crate::layouts::read::metadata::FileMetadata;
+use crate::layouts::read::metrics::ReadMetrics;
 use crate::layouts::read::projections::Projection;
 use crate::layouts::read::stream::LayoutBatchStream;
 use crate::layouts::read::{Scan, DEFAULT_BATCH_SIZE, FILE_POSTSCRIPT_SIZE, INITIAL_READ_SIZE};
 use crate::layouts::MAGIC_BYTES;
+use crate::stream_writer::ByteRange;
+use crate::MessageReader;
 
 pub struct LayoutReaderBuilder<R> {
     reader: R,
@@ -22,8 +28,10 @@ pub struct LayoutReaderBuilder<R> {
     projection: Option<Projection>,
     len: Option<u64>,
     indices: Option<Array>,
+    chunks: Option<Vec<bool>>,
     row_filter: Option<RowFilter>,
     batch_size: Option<usize>,
+    metrics: ReadMetrics,
 }
 
 impl<R: VortexReadAt> LayoutReaderBuilder<R> {
@@ -35,7 +43,9 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
             row_filter: None,
             len: None,
             indices: None,
+            chunks: None,
             batch_size: None,
+            metrics: ReadMetrics::default(),
         }
     }
 
@@ -59,6 +69,16 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
         self
     }
 
+    /// Only read the chunks whose entry in `chunks` is `true`.
+    ///
+    /// Skipped chunks are neither fetched nor decoded, and their rows are left out of the stream.
+    /// Requires every column of the file to be chunked at the same rows, and cannot be combined
+    /// with [`with_indices`](Self::with_indices).
+    pub fn with_chunks(mut self, chunks: Vec<bool>) -> Self {
+        self.chunks = Some(chunks);
+        self
+    }
+
     pub fn with_row_filter(mut self, row_filter: RowFilter) -> Self {
         self.row_filter = Some(row_filter);
         self
@@ -69,7 +89,17 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
         self
     }
 
+    /// Record I/O and decoding work of this reader into `metrics`.
+    pub fn with_metrics(mut self, metrics: ReadMetrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     pub async fn build(mut self) -> VortexResult<LayoutBatchStream<R>> {
+        if self.indices.is_some() && self.chunks.is_some() {
+            vortex_bail!("Cannot read a file with both row indices and a chunk selection")
+        }
+
         let footer = self.read_footer().await?;
 
         // TODO(robert): Don't leak filter references into read projection
@@ -111,8 +141,10 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
         let scan = Scan {
             projection: read_projection,
             indices: self.indices,
+            chunks: self.chunks.map(Arc::from),
             filter: self.row_filter,
             batch_size,
+            metrics: self.metrics.clone(),
         };
 
         let message_cache = Arc::new(RwLock::new(LayoutMessageCache::default()));
@@ -128,9 +160,43 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
             projected_dtype,
             scan,
             result_projection,
+            self.metrics,
         )
     }
 
+    /// Read the schema and per-chunk metadata tables of the file, without reading any column data.
+    pub async fn read_metadata(&mut self) -> VortexResult<FileMetadata> {
+        let footer = self.read_footer().await?;
+        let dtype = footer.dtype()?;
+
+        let mut columns = Vec::new();
+        for range in footer.chunk_metadata_ranges()? {
+            let table = match range {
+                Some(range) => Some(self.read_metadata_table(range).await?),
+                None => None,
+            };
+            columns.push(table);
+        }
+
+        Ok(FileMetadata::new(dtype, columns))
+    }
+
+    async fn read_metadata_table(&self, range: ByteRange) -> VortexResult<StructArray> {
+        let mut buf = BytesMut::with_capacity(range.len());
+        unsafe { buf.set_len(range.len()) }
+        let start = Instant::now();
+        let buf = self.reader.read_at_into(range.begin, buf).await?;
+        self.metrics.record_reads(1, buf.len(), start.elapsed());
+
+        let mut msgs = MessageReader::try_new(buf).await?;
+        let dtype = msgs.read_dtype().await?;
+        let table = msgs
+            .maybe_read_chunk(self.layout_serde.ctx(), dtype)
+            .await?
+            .ok_or_else(|| vortex_err!("Chunk metadata table is missing"))?;
+        StructArray::try_from(table)
+    }
+
     async fn len(&self) -> usize {
         let len = match self.len {
             Some(l) => l,
@@ -156,7 +222,9 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
         unsafe { buf.set_len(read_size) }
 
         let read_offset = (file_length - read_size) as u64;
+        let start = Instant::now();
         buf = self.reader.read_at_into(read_offset, buf).await?;
+        self.metrics.record_reads(1, buf.len(), start.elapsed());
 
         let magic_bytes_loc = read_size - MAGIC_BYTES.len();
 
@@ -67,6 +67,10 @@ impl LayoutDeserializer {
         Self { ctx, layout_ctx }
     }
 
+    pub(crate) fn ctx(&self) -> Arc<Context> {
+        self.ctx.clone()
+    }
+
     pub fn read_layout(
         &self,
         fb_bytes: Bytes,
@@ -89,6 +93,7 @@ impl LayoutDeserializer {
                     flat_layout.end(),
                     self.ctx.clone(),
                     message_cache,
+                    scan.metrics,
                 )))
             }
             LayoutVariant::NestedLayout => {
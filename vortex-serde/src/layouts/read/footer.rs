@@ -3,12 +3,13 @@ use flatbuffers::root;
 use vortex_dtype::field::Field;
 use vortex_dtype::{deserialize_and_project, resolve_field_references, DType};
 use vortex_error::{vortex_err, VortexResult};
-use vortex_flatbuffers::{message as fb, ReadFlatBuffer};
+use vortex_flatbuffers::{footer as footer_fb, message as fb, ReadFlatBuffer};
 
 use crate::layouts::read::cache::RelativeLayoutCache;
 use crate::layouts::read::context::LayoutDeserializer;
-use crate::layouts::read::{Layout, Scan, FILE_POSTSCRIPT_SIZE};
+use crate::layouts::read::{ChunkedLayoutSpec, Layout, Scan, FILE_POSTSCRIPT_SIZE};
 use crate::messages::IPCDType;
+use crate::stream_writer::ByteRange;
 use crate::FLATBUFFER_SIZE_LENGTH;
 
 /// Wrapper around serialized file footer. Provides handle on file schema and
@@ -57,19 +58,51 @@ impl Footer {
         scan: Scan,
         message_cache: RelativeLayoutCache,
     ) -> VortexResult<Box<dyn Layout>> {
+        let (footer_bytes, loc) = self.fb_layout_location()?;
+        self.layout_serde
+            .read_layout(footer_bytes, loc, scan, message_cache)
+    }
+
+    /// Byte ranges of the chunk metadata table of every top-level column, `None` for columns
+    /// that are not stored in a chunked layout.
+    pub(crate) fn chunk_metadata_ranges(&self) -> VortexResult<Vec<Option<ByteRange>>> {
+        let (footer_bytes, loc) = self.fb_layout_location()?;
+        let fb_layout = unsafe {
+            let tab = flatbuffers::Table::new(&footer_bytes, loc);
+            footer_fb::Layout::init_from_table(tab)
+        };
+        let columns = fb_layout
+            .layout_as_nested_layout()
+            .and_then(|l| l.children())
+            .ok_or_else(|| vortex_err!("Top-level layout must be a nested layout"))?;
+
+        Ok(columns
+            .iter()
+            .map(|column| {
+                column
+                    .layout_as_nested_layout()
+                    .filter(|l| l.encoding() == ChunkedLayoutSpec::ID.0)
+                    .and_then(|l| l.children())
+                    .filter(|children| !children.is_empty())
+                    .and_then(|children| children.get(0).layout_as_flat_layout())
+                    .map(|flat| ByteRange::new(flat.begin(), flat.end()))
+            })
+            .collect())
+    }
+
+    fn fb_layout_location(&self) -> VortexResult<(Bytes, usize)> {
         let start_offset = self.leftovers_footer_offset();
         let end_offset = self.leftovers.len() - FILE_POSTSCRIPT_SIZE;
         let footer_bytes = self
             .leftovers
             .slice(start_offset + FLATBUFFER_SIZE_LENGTH..end_offset);
-        let fb_footer = root::<vortex_flatbuffers::footer::Footer>(&footer_bytes)?;
+        let fb_footer = root::<footer_fb::Footer>(&footer_bytes)?;
 
         let fb_layout = fb_footer
             .layout()
             .ok_or_else(|| vortex_err!("Footer must contain a layout"))?;
         let loc = fb_layout._tab.loc();
-        self.layout_serde
-            .read_layout(footer_bytes, loc, scan, message_cache)
+        Ok((footer_bytes, loc))
     }
 
     pub fn dtype(&self) -> VortexResult<DType> {
@@ -1,5 +1,6 @@
 use std::collections::VecDeque;
 use std::sync::Arc;
+use std::time::Instant;
 
 use bytes::Bytes;
 use flatbuffers::{ForwardsUOffset, Vector};
@@ -14,7 +15,7 @@ use crate::layouts::read::batch::BatchReader;
 use crate::layouts::read::buffered::BufferedReader;
 use crate::layouts::read::cache::RelativeLayoutCache;
 use crate::layouts::read::context::{LayoutDeserializer, LayoutId, LayoutSpec};
-use crate::layouts::read::{Layout, ReadResult, Scan};
+use crate::layouts::read::{Layout, ReadMetrics, ReadResult, Scan};
 use crate::stream_writer::ByteRange;
 use crate::ArrayBufferReader;
 
@@ -30,15 +31,23 @@ pub struct FlatLayout {
     range: ByteRange,
     ctx: Arc<Context>,
     cache: RelativeLayoutCache,
+    metrics: ReadMetrics,
     state: FlatLayoutState,
 }
 
 impl FlatLayout {
-    pub fn new(begin: u64, end: u64, ctx: Arc<Context>, cache: RelativeLayoutCache) -> Self {
+    pub fn new(
+        begin: u64,
+        end: u64,
+        ctx: Arc<Context>,
+        cache: RelativeLayoutCache,
+        metrics: ReadMetrics,
+    ) -> Self {
         Self {
             range: ByteRange { begin, end },
             ctx,
             cache,
+            metrics,
             state: FlatLayoutState::Init,
         }
     }
@@ -68,6 +77,7 @@ impl Layout for FlatLayout {
                     )
                 })?;
 
+                let start = Instant::now();
                 let mut array_reader = ArrayBufferReader::new();
                 let mut read_buf = Bytes::new();
                 while let Some(u) = array_reader.read(read_buf)? {
@@ -75,6 +85,7 @@ impl Layout for FlatLayout {
                 }
 
                 let array = array_reader.into_array(self.ctx.clone(), self.cache.dtype())?;
+                self.metrics.record_decode(start.elapsed());
                 self.state = FlatLayoutState::Finished;
                 Ok(Some(ReadResult::Batch(array)))
             }
@@ -301,14 +312,38 @@ impl Layout for ChunkedLayout {
     fn read(&mut self) -> VortexResult<Option<ReadResult>> {
         match &mut self.state {
             ChunkedLayoutState::Init => {
-                let children = self
+                let fb_children = self
                     .flatbuffer()
                     .children()
-                    .ok_or_else(|| vortex_err!("Missing children"))?
+                    .ok_or_else(|| vortex_err!("Missing children"))?;
+                // The first child is the metadata table of this layout
+                let num_chunks = fb_children.len().saturating_sub(1);
+                if let Some(chunks) = &self.scan.chunks {
+                    if chunks.len() != num_chunks {
+                        vortex_bail!(
+                            "Chunk selection has {} entries but the layout has {} chunks",
+                            chunks.len(),
+                            num_chunks
+                        )
+                    }
+                }
+
+                if let Some(chunks) = &self.scan.chunks {
+                    self.scan
+                        .metrics
+                        .record_skipped(chunks.iter().filter(|keep| !**keep).count());
+                }
+
+                let children = fb_children
                     .iter()
                     .enumerate()
-                    // Skip over the metadata table of this layout
                     .skip(1)
+                    .filter(|(i, _)| {
+                        self.scan
+                            .chunks
+                            .as_ref()
+                            .map_or(true, |chunks| chunks[i - 1])
+                    })
                     .map(|(i, c)| {
                         self.layout_builder.read_layout(
                             self.fb_bytes.clone(),
@@ -0,0 +1,35 @@
+use vortex::array::StructArray;
+use vortex::variants::StructArrayTrait;
+use vortex::Array;
+use vortex_dtype::DType;
+
+/// Schema and per-chunk metadata of a file written by [`LayoutWriter`](crate::layouts::LayoutWriter).
+///
+/// Each top-level column stored in a chunked layout has a metadata table with one row per chunk,
+/// holding the `byte_offset`, `row_offset` and `row_count` of the chunk along with its
+/// `null_count` and, for orderable types, its `min` and `max`.
+#[derive(Debug, Clone)]
+pub struct FileMetadata {
+    dtype: DType,
+    columns: Vec<Option<StructArray>>,
+}
+
+impl FileMetadata {
+    pub fn new(dtype: DType, columns: Vec<Option<StructArray>>) -> Self {
+        Self { dtype, columns }
+    }
+
+    pub fn dtype(&self) -> &DType {
+        &self.dtype
+    }
+
+    /// Metadata table of the column at `idx`, if the column is chunked.
+    pub fn column(&self, idx: usize) -> Option<&StructArray> {
+        self.columns.get(idx).and_then(|c| c.as_ref())
+    }
+
+    /// Statistic `name` (e.g. `min` or `null_count`) of every chunk of the column at `idx`.
+    pub fn column_statistic(&self, idx: usize, name: &str) -> Option<Array> {
+        self.column(idx).and_then(|c| c.field_by_name(name))
+    }
+}
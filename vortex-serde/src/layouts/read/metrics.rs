@@ -0,0 +1,99 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Counters describing the work done while reading a Vortex file.
+///
+/// Clones share the same counters, so callers can keep a handle to the metrics passed to
+/// [`LayoutReaderBuilder::with_metrics`](crate::layouts::LayoutReaderBuilder::with_metrics) and
+/// inspect them while or after the file is read.
+#[derive(Debug, Default, Clone)]
+pub struct ReadMetrics(Arc<Counters>);
+
+#[derive(Debug, Default)]
+struct Counters {
+    bytes_read: AtomicU64,
+    read_requests: AtomicU64,
+    batches_decoded: AtomicU64,
+    chunks_decoded: AtomicU64,
+    chunks_skipped: AtomicU64,
+    rows_filtered: AtomicU64,
+    io_nanos: AtomicU64,
+    decode_nanos: AtomicU64,
+}
+
+impl ReadMetrics {
+    /// Total number of bytes fetched from the underlying reader.
+    pub fn bytes_read(&self) -> u64 {
+        self.0.bytes_read.load(Ordering::Relaxed)
+    }
+
+    /// Number of read requests issued to the underlying reader.
+    pub fn read_requests(&self) -> u64 {
+        self.0.read_requests.load(Ordering::Relaxed)
+    }
+
+    /// Number of batches produced by the file layout, before any filtering.
+    pub fn batches_decoded(&self) -> u64 {
+        self.0.batches_decoded.load(Ordering::Relaxed)
+    }
+
+    /// Number of column chunks fetched and decoded.
+    pub fn chunks_decoded(&self) -> u64 {
+        self.0.chunks_decoded.load(Ordering::Relaxed)
+    }
+
+    /// Number of column chunks left out by the chunk selection, which were neither fetched nor
+    /// decoded.
+    pub fn chunks_skipped(&self) -> u64 {
+        self.0.chunks_skipped.load(Ordering::Relaxed)
+    }
+
+    /// Number of rows removed by the row filter.
+    pub fn rows_filtered(&self) -> u64 {
+        self.0.rows_filtered.load(Ordering::Relaxed)
+    }
+
+    /// Wall-clock time spent waiting on the underlying reader.
+    pub fn io_time(&self) -> Duration {
+        Duration::from_nanos(self.0.io_nanos.load(Ordering::Relaxed))
+    }
+
+    /// Time spent decoding fetched column chunks into arrays.
+    pub fn decode_time(&self) -> Duration {
+        Duration::from_nanos(self.0.decode_nanos.load(Ordering::Relaxed))
+    }
+
+    pub(crate) fn record_reads(&self, requests: usize, bytes: usize, elapsed: Duration) {
+        self.0
+            .read_requests
+            .fetch_add(requests as u64, Ordering::Relaxed);
+        self.0.bytes_read.fetch_add(bytes as u64, Ordering::Relaxed);
+        self.0
+            .io_nanos
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_batch(&self) {
+        self.0.batches_decoded.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_skipped(&self, chunks: usize) {
+        self.0
+            .chunks_skipped
+            .fetch_add(chunks as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_filtered(&self, rows: usize) {
+        self.0
+            .rows_filtered
+            .fetch_add(rows as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_decode(&self, elapsed: Duration) {
+        self.0.chunks_decoded.fetch_add(1, Ordering::Relaxed);
+        self.0
+            .decode_nanos
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+}
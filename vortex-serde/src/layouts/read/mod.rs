@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::sync::Arc;
 
 pub use layouts::{ChunkedLayoutSpec, ColumnLayoutSpec};
 use vortex::Array;
@@ -12,6 +13,8 @@ mod context;
 mod filtering;
 mod footer;
 mod layouts;
+mod metadata;
+mod metrics;
 mod projections;
 mod schema;
 mod stream;
@@ -19,6 +22,8 @@ mod stream;
 pub use builder::LayoutReaderBuilder;
 pub use context::*;
 pub use filtering::RowFilter;
+pub use metadata::FileMetadata;
+pub use metrics::ReadMetrics;
 pub use projections::Projection;
 pub use schema::Schema;
 pub use stream::LayoutBatchStream;
@@ -34,9 +39,12 @@ const FILE_POSTSCRIPT_SIZE: usize = 20;
 #[derive(Debug, Clone)]
 pub struct Scan {
     indices: Option<Array>,
+    /// Whether to read each chunk of the chunked layouts, in file order.
+    chunks: Option<Arc<[bool]>>,
     projection: Projection,
     filter: Option<RowFilter>,
     batch_size: usize,
+    metrics: ReadMetrics,
 }
 
 /// Unique identifier for a message within a layout
@@ -7,6 +7,10 @@ use super::projections::Projection;
 pub struct Schema(pub(crate) DType);
 
 impl Schema {
+    pub fn dtype(&self) -> &DType {
+        &self.0
+    }
+
     pub fn project(&self, projection: Projection) -> VortexResult<Self> {
         match projection {
             Projection::All => Ok(self.clone()),
@@ -2,6 +2,7 @@ use std::mem;
 use std::pin::Pin;
 use std::sync::{Arc, RwLock};
 use std::task::{ready, Context, Poll};
+use std::time::Instant;
 
 use bytes::{Bytes, BytesMut};
 use futures::Stream;
@@ -18,6 +19,7 @@ use vortex_scalar::Scalar;
 
 use crate::io::VortexReadAt;
 use crate::layouts::read::cache::LayoutMessageCache;
+use crate::layouts::read::metrics::ReadMetrics;
 use crate::layouts::read::schema::Schema;
 use crate::layouts::read::{Layout, MessageId, ReadResult, Scan};
 use crate::layouts::Projection;
@@ -32,6 +34,7 @@ pub struct LayoutBatchStream<R> {
     dtype: DType,
     current_offset: usize,
     result_projection: Projection,
+    metrics: ReadMetrics,
 }
 
 impl<R: VortexReadAt> LayoutBatchStream<R> {
@@ -42,6 +45,7 @@ impl<R: VortexReadAt> LayoutBatchStream<R> {
         dtype: DType,
         scan: Scan,
         result_projection: Projection,
+        metrics: ReadMetrics,
     ) -> VortexResult<Self> {
         Ok(LayoutBatchStream {
             reader: Some(reader),
@@ -52,6 +56,7 @@ impl<R: VortexReadAt> LayoutBatchStream<R> {
             dtype,
             current_offset: 0,
             result_projection,
+            metrics,
         })
     }
 
@@ -105,10 +110,14 @@ impl<R: VortexReadAt + Unpin + Send + 'static> Stream for LayoutBatchStream<R> {
                             ReadResult::GetMsgs(messages) => {
                                 let reader =
                                     mem::take(&mut self.reader).expect("Invalid state transition");
-                                let read_future = read_ranges(reader, messages).boxed();
+                                let read_future =
+                                    read_ranges(reader, messages, self.metrics.clone()).boxed();
                                 self.state = StreamingState::Reading(read_future);
                             }
-                            ReadResult::Batch(a) => self.state = StreamingState::Decoding(a),
+                            ReadResult::Batch(a) => {
+                                self.metrics.record_batch();
+                                self.state = StreamingState::Decoding(a)
+                            }
                         }
                     } else {
                         return Poll::Ready(None);
@@ -123,7 +132,9 @@ impl<R: VortexReadAt + Unpin + Send + 'static> Stream for LayoutBatchStream<R> {
                     if let Some(row_filter) = &self.scan.filter {
                         let mask = row_filter.filter.evaluate(&batch)?;
                         let filter_array = null_as_false(mask.into_bool()?)?;
+                        let unfiltered_len = batch.len();
                         batch = filter(&batch, &filter_array)?;
+                        self.metrics.record_filtered(unfiltered_len - batch.len());
                     }
 
                     batch = match &self.result_projection {
@@ -174,8 +185,13 @@ fn null_as_false(array: BoolArray) -> VortexResult<Array> {
 async fn read_ranges<R: VortexReadAt>(
     reader: R,
     ranges: Vec<(MessageId, ByteRange)>,
+    metrics: ReadMetrics,
 ) -> VortexResult<(R, Vec<(MessageId, Bytes)>)> {
-    stream::iter(ranges.into_iter())
+    let start = Instant::now();
+    let requests = ranges.len();
+    let bytes = ranges.iter().map(|(_, range)| range.len()).sum();
+
+    let buffers = stream::iter(ranges.into_iter())
         .map(|(id, range)| {
             let mut buf = BytesMut::with_capacity(range.len());
             unsafe { buf.set_len(range.len()) }
@@ -190,8 +206,10 @@ async fn read_ranges<R: VortexReadAt>(
         })
         .buffered(10)
         .try_collect()
-        .await
-        .map(|b| (reader, b))
+        .await?;
+
+    metrics.record_reads(requests, bytes, start.elapsed());
+    Ok((reader, buffers))
 }
 
 #[cfg(test)]
@@ -1,10 +1,18 @@
+use std::sync::Arc;
+
 use futures::StreamExt;
 use vortex::array::{ChunkedArray, PrimitiveArray, StructArray, VarBinArray};
+use vortex::compute::unary::scalar_at;
+use vortex::stats::{ArrayStatistics, Stat};
 use vortex::{ArrayDType, IntoArray, IntoArrayVariant};
-use vortex_dtype::PType;
+use vortex_dtype::{Nullability, PType};
+use vortex_expr::{BinaryExpr, Column, Literal, Operator};
+use vortex_fastlanes::for_compress;
+use vortex_sampling_compressor::SamplingCompressor;
+use vortex_scalar::Scalar;
 
 use crate::layouts::write::LayoutWriter;
-use crate::layouts::{LayoutDeserializer, LayoutReaderBuilder, Projection};
+use crate::layouts::{LayoutDeserializer, LayoutReaderBuilder, Projection, ReadMetrics, RowFilter};
 
 #[tokio::test]
 #[cfg_attr(miri, ignore)]
@@ -135,3 +143,182 @@ async fn unequal_batches() {
     assert_eq!(item_count, 10);
     assert_eq!(batch_count, 2);
 }
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_read_metadata() {
+    let strings = ChunkedArray::from_iter([
+        VarBinArray::from(vec!["ab", "foo", "bar", "baz"]).into_array(),
+        VarBinArray::from(vec!["ab", "foo", "bar", "baz"]).into_array(),
+    ])
+    .into_array();
+
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        PrimitiveArray::from(vec![5u32, 6, 7, 8]).into_array(),
+    ])
+    .into_array();
+
+    let st = StructArray::from_fields(&[("strings", strings), ("numbers", numbers)]);
+    let buf = Vec::new();
+    let mut writer = LayoutWriter::new(buf);
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let metadata = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .read_metadata()
+        .await
+        .unwrap();
+
+    let mins = metadata
+        .column_statistic(1, "min")
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(mins.maybe_null_slice::<u32>(), &[1, 5]);
+    let maxes = metadata
+        .column_statistic(1, "max")
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(maxes.maybe_null_slice::<u32>(), &[4, 8]);
+    let null_counts = metadata
+        .column_statistic(0, "null_count")
+        .unwrap()
+        .into_primitive()
+        .unwrap();
+    assert_eq!(null_counts.maybe_null_slice::<u64>(), &[0, 0]);
+    assert_eq!(
+        scalar_at(&metadata.column_statistic(0, "max").unwrap(), 1).unwrap(),
+        Scalar::utf8("foo".to_string(), Nullability::Nullable)
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_read_metrics() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        PrimitiveArray::from(vec![5u32, 6, 7, 8]).into_array(),
+    ])
+    .into_array();
+
+    let st = StructArray::from_fields(&[("numbers", numbers)]);
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let metrics = ReadMetrics::default();
+    let mut stream = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+            Arc::new(Column::new("numbers".to_string())),
+            Operator::Gt,
+            Arc::new(Literal::new(6u32.into())),
+        ))))
+        .with_metrics(metrics.clone())
+        .with_batch_size(4)
+        .build()
+        .await
+        .unwrap();
+
+    let mut row_count = 0;
+    while let Some(array) = stream.next().await {
+        row_count += array.unwrap().len();
+    }
+
+    assert_eq!(row_count, 2);
+    assert_eq!(metrics.batches_decoded(), 2);
+    assert_eq!(metrics.chunks_decoded(), 2);
+    assert_eq!(metrics.chunks_skipped(), 0);
+    assert_eq!(metrics.rows_filtered(), 6);
+    assert!(metrics.read_requests() >= 2);
+    assert!(metrics.bytes_read() > 0);
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_read_chunk_selection() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        PrimitiveArray::from(vec![5u32, 6, 7, 8]).into_array(),
+        PrimitiveArray::from(vec![9u32, 10, 11, 12]).into_array(),
+    ])
+    .into_array();
+
+    let st = StructArray::from_fields(&[("numbers", numbers)]);
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let metrics = ReadMetrics::default();
+    let mut stream = LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+        .with_chunks(vec![false, true, false])
+        .with_metrics(metrics.clone())
+        .with_batch_size(4)
+        .build()
+        .await
+        .unwrap();
+
+    let mut values = Vec::new();
+    while let Some(array) = stream.next().await {
+        let array = StructArray::try_from(array.unwrap()).unwrap();
+        let numbers = array.children().next().unwrap().into_primitive().unwrap();
+        values.extend_from_slice(numbers.maybe_null_slice::<u32>());
+    }
+
+    assert_eq!(values, vec![5, 6, 7, 8]);
+    assert_eq!(metrics.batches_decoded(), 1);
+    assert_eq!(metrics.chunks_decoded(), 1);
+    assert_eq!(metrics.chunks_skipped(), 2);
+    // The footer and the selected chunk.
+    assert_eq!(metrics.read_requests(), 2);
+
+    assert!(
+        LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+            .with_chunks(vec![true, false])
+            .build()
+            .await
+            .unwrap()
+            .next()
+            .await
+            .unwrap()
+            .is_err()
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_write_metadata_without_decoding() {
+    let plain = PrimitiveArray::from(vec![101u32, 102, 103, 104]);
+    // Compressed chunks only carry the statistics computed before compressing them.
+    let with_stats = PrimitiveArray::from(vec![105u32, 106, 107, 108]);
+    with_stats.statistics().compute(Stat::Min);
+    with_stats.statistics().compute(Stat::Max);
+    let numbers = ChunkedArray::from_iter([
+        for_compress(&plain).unwrap(),
+        SamplingCompressor::default()
+            .compress(with_stats.array(), None)
+            .unwrap()
+            .into_array(),
+    ])
+    .into_array();
+    let structs = StructArray::from_fields(&[("numbers", numbers.clone())]).into_array();
+
+    let st = StructArray::from_fields(&[("numbers", numbers), ("structs", structs)]);
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let metadata = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+        .read_metadata()
+        .await
+        .unwrap();
+
+    let mins = metadata.column_statistic(0, "min").unwrap();
+    assert!(scalar_at(&mins, 0).unwrap().is_null());
+    assert_eq!(
+        scalar_at(&mins, 1).unwrap(),
+        Scalar::primitive(105u32, Nullability::Nullable)
+    );
+    assert!(metadata.column_statistic(1, "min").is_none());
+}
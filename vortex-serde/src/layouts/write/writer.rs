@@ -2,12 +2,14 @@ use std::collections::VecDeque;
 use std::mem;
 
 use futures::{Stream, TryStreamExt};
-use vortex::array::{ChunkedArray, StructArray};
+use vortex::array::{ChunkedArray, ConstantArray, PrimitiveArray, StructArray};
+use vortex::stats::{ArrayStatistics, Stat};
 use vortex::stream::ArrayStream;
 use vortex::validity::Validity;
-use vortex::{Array, ArrayDType, IntoArray};
-use vortex_dtype::DType;
+use vortex::{Array, ArrayDType, IntoArray, IntoCanonical};
+use vortex_dtype::{DType, FieldName, Nullability};
 use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
 
 use crate::io::VortexWrite;
 use crate::layouts::read::{ChunkedLayoutSpec, ColumnLayoutSpec};
@@ -22,6 +24,7 @@ pub struct LayoutWriter<W> {
 
     dtype: Option<DType>,
     column_chunks: Vec<ChunkOffsets>,
+    column_stats: Vec<ChunkStatistics>,
 }
 
 impl<W: VortexWrite> LayoutWriter<W> {
@@ -30,6 +33,7 @@ impl<W: VortexWrite> LayoutWriter<W> {
             msgs: MessageWriter::new(write),
             dtype: None,
             column_chunks: Vec::new(),
+            column_stats: Vec::new(),
         }
     }
 
@@ -88,8 +92,10 @@ impl<W: VortexWrite> LayoutWriter<W> {
             .unwrap_or(0u64);
         let mut byte_offsets = vec![self.msgs.tell()];
         let mut row_offsets = vec![column_row_offset];
+        let mut stats = ChunkStatistics::default();
 
         while let Some(chunk) = stream.try_next().await? {
+            stats.push(&chunk);
             row_offsets.push(
                 row_offsets
                     .last()
@@ -112,13 +118,27 @@ impl<W: VortexWrite> LayoutWriter<W> {
                 .push(ChunkOffsets::new(byte_offsets, row_offsets));
         }
 
+        if let Some(column_stats) = self.column_stats.get_mut(column_idx) {
+            column_stats.extend(stats);
+        } else {
+            self.column_stats.push(stats);
+        }
+
         Ok(())
     }
 
     async fn write_metadata_arrays(&mut self) -> VortexResult<NestedLayout> {
         let mut column_layouts = VecDeque::with_capacity(self.column_chunks.len());
+        let column_dtypes = match &self.dtype {
+            Some(DType::Struct(s, _)) => s.dtypes().to_vec(),
+            _ => vec![],
+        };
 
-        for mut chunk in mem::take(&mut self.column_chunks) {
+        for (column_idx, (mut chunk, stats)) in mem::take(&mut self.column_chunks)
+            .into_iter()
+            .zip(mem::take(&mut self.column_stats))
+            .enumerate()
+        {
             let len = chunk.byte_offsets.len() - 1;
             let mut chunks: VecDeque<Layout> = chunk
                 .byte_offsets
@@ -129,15 +149,27 @@ impl<W: VortexWrite> LayoutWriter<W> {
             chunk.byte_offsets.truncate(len);
             chunk.row_offsets.truncate(len);
 
-            let metadata_array = StructArray::try_new(
-                ["byte_offset".into(), "row_offset".into()].into(),
-                vec![
-                    chunk.byte_offsets.into_array(),
-                    chunk.row_offsets.into_array(),
-                ],
-                len,
-                Validity::NonNullable,
-            )?;
+            let mut names: Vec<FieldName> = vec!["byte_offset".into(), "row_offset".into()];
+            let mut fields = vec![
+                chunk.byte_offsets.into_array(),
+                chunk.row_offsets.into_array(),
+            ];
+            names.push("row_count".into());
+            fields.push(stats.row_count.into_array());
+            names.push("null_count".into());
+            fields.push(PrimitiveArray::from_nullable_vec(stats.null_count).into_array());
+            if let Some(dtype) = column_dtypes
+                .get(column_idx)
+                .filter(|dt| supports_min_max(dt))
+            {
+                names.push("min".into());
+                fields.push(scalars_to_array(stats.min, dtype)?);
+                names.push("max".into());
+                fields.push(scalars_to_array(stats.max, dtype)?);
+            }
+
+            let metadata_array =
+                StructArray::try_new(names.into(), fields, len, Validity::NonNullable)?;
 
             let metadata_table_begin = self.msgs.tell();
             self.msgs.write_dtype(metadata_array.dtype()).await?;
@@ -179,6 +211,68 @@ impl<W: VortexWrite> LayoutWriter<W> {
     }
 }
 
+/// Per-chunk statistics of a single column, stored alongside the chunk offsets in the chunked
+/// layout metadata table so readers can prune chunks without reading them.
+#[derive(Default)]
+struct ChunkStatistics {
+    min: Vec<Option<Scalar>>,
+    max: Vec<Option<Scalar>>,
+    null_count: Vec<Option<u64>>,
+    row_count: Vec<u64>,
+}
+
+impl ChunkStatistics {
+    /// Record the statistics of `chunk` that are cached or can be computed by its encoding.
+    ///
+    /// Chunks are never decoded to compute a statistic, unknown statistics are stored as nulls.
+    fn push(&mut self, chunk: &Array) {
+        let stats = chunk.statistics();
+        let (min, max) = if supports_min_max(chunk.dtype()) {
+            (stats.compute(Stat::Min), stats.compute(Stat::Max))
+        } else {
+            (None, None)
+        };
+
+        self.min.push(min);
+        self.max.push(max);
+        self.null_count
+            .push(stats.compute_as_cast::<u64>(Stat::NullCount));
+        self.row_count.push(chunk.len() as u64);
+    }
+
+    fn extend(&mut self, other: Self) {
+        self.min.extend(other.min);
+        self.max.extend(other.max);
+        self.null_count.extend(other.null_count);
+        self.row_count.extend(other.row_count);
+    }
+}
+
+fn supports_min_max(dtype: &DType) -> bool {
+    matches!(
+        dtype,
+        DType::Bool(_) | DType::Primitive(..) | DType::Utf8(_) | DType::Binary(_)
+    )
+}
+
+fn scalars_to_array(scalars: Vec<Option<Scalar>>, dtype: &DType) -> VortexResult<Array> {
+    let dtype = dtype.with_nullability(Nullability::Nullable);
+    let chunks = scalars
+        .into_iter()
+        .map(|s| {
+            let scalar = match s {
+                Some(s) if s.is_valid() => Scalar::new(dtype.clone(), s.into_value()),
+                _ => Scalar::null(dtype.clone()),
+            };
+            ConstantArray::new(scalar, 1).into_array()
+        })
+        .collect();
+
+    Ok(Array::from(
+        ChunkedArray::try_new(chunks, dtype)?.into_canonical()?,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use futures_executor::block_on;
@@ -17,17 +17,39 @@ pub const ALIGNMENT: usize = 64;
 #[cfg(test)]
 #[allow(clippy::panic_in_result_fn)]
 mod test {
+    use std::collections::HashSet;
     use std::sync::Arc;
 
     use futures_executor::block_on;
     use futures_util::io::Cursor;
     use futures_util::{pin_mut, StreamExt, TryStreamExt};
     use itertools::Itertools;
-    use vortex::array::{ChunkedArray, PrimitiveArray, PrimitiveEncoding};
-    use vortex::encoding::ArrayEncoding;
+    use vortex::accessor::ArrayAccessor;
+    use vortex::array::{
+        BoolArray, BoolEncoding, ChunkedArray, ChunkedEncoding, ConstantEncoding,
+        ExtensionEncoding, NullEncoding, PrimitiveArray, PrimitiveEncoding, SparseEncoding,
+        StructEncoding, VarBinArray, VarBinEncoding, VarBinViewEncoding,
+    };
+    use vortex::compute::unary::scalar_at;
+    use vortex::encoding::{ArrayEncoding, EncodingRef};
+    use vortex::stats::{ArrayStatistics, Stat};
     use vortex::stream::ArrayStreamExt;
-    use vortex::{ArrayDType, Context, IntoArray};
+    use vortex::{ArrayDType, ArrayDef, Context, IntoArray, IntoCanonical};
+    use vortex_alp::{ALPEncoding, ALPRDEncoding};
+    use vortex_bytebool::ByteBoolEncoding;
+    use vortex_datetime_parts::DateTimePartsEncoding;
+    use vortex_dict::DictEncoding;
     use vortex_error::VortexResult;
+    use vortex_fastlanes::{BitPackedEncoding, DeltaEncoding, FoREncoding};
+    use vortex_fsst::{fsst_compress, fsst_train_compressor, FSSTEncoding, FSST};
+    use vortex_roaring::{
+        RoaringBool, RoaringBoolArray, RoaringBoolEncoding, RoaringInt, RoaringIntArray,
+        RoaringIntEncoding,
+    };
+    use vortex_runend::RunEndEncoding;
+    use vortex_runend_bool::RunEndBoolEncoding;
+    use vortex_scalar::{ListScalar, Scalar};
+    use vortex_zigzag::ZigZagEncoding;
 
     use crate::io::FuturesAdapter;
     use crate::stream_reader::StreamArrayReader;
@@ -114,4 +136,184 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_write_read_fsst() -> VortexResult<()> {
+        let strings = VarBinArray::from_iter(
+            (0..1024).map(|i| Some(format!("https://example.com/items/{}?page={}", i, i % 7))),
+            vortex_dtype::DType::Utf8(vortex_dtype::Nullability::Nullable),
+        )
+        .into_array();
+        let compressor = fsst_train_compressor(&strings)?;
+        let fsst = fsst_compress(&strings, &compressor)?;
+        let buffer = write_ipc(fsst);
+
+        let ctx = Arc::new(Context::default().with_encoding(&FSSTEncoding));
+        let read = block_on(async {
+            StreamArrayReader::try_new(FuturesAdapter(Cursor::new(buffer)), ctx)
+                .await?
+                .load_dtype()
+                .await?
+                .into_array_stream()
+                .collect_chunked()
+                .await
+        })?;
+
+        let chunk = read
+            .chunks()
+            .exactly_one()
+            .unwrap_or_else(|_| panic!("Expected one chunk"));
+        assert_eq!(chunk.encoding().id(), FSST::ID);
+        assert_eq!(chunk.dtype(), strings.dtype());
+
+        let values = |array: VarBinArray| {
+            array.with_iterator(|iter| iter.map(|v| v.map(<[u8]>::to_vec)).collect::<Vec<_>>())
+        };
+        assert_eq!(
+            values(chunk.into_canonical()?.into_varbin()?)?,
+            values(VarBinArray::try_from(strings)?)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_write_read_roaring() -> VortexResult<()> {
+        let bools = RoaringBoolArray::encode(
+            BoolArray::from_iter([Some(true), None, Some(false), Some(true)]).into_array(),
+        )?;
+        let ints = RoaringIntArray::encode(
+            PrimitiveArray::from_nullable_vec(vec![Some(1u32), None, Some(5), Some(9)])
+                .into_array(),
+        )?;
+        let ctx = Arc::new(
+            Context::default()
+                .with_encodings([&RoaringBoolEncoding as EncodingRef, &RoaringIntEncoding]),
+        );
+
+        for (array, id) in [(bools, RoaringBool::ID), (ints, RoaringInt::ID)] {
+            let buffer = block_on(async {
+                StreamArrayWriter::new(vec![])
+                    .write_array(array.clone())
+                    .await
+                    .map(|writer| writer.into_inner())
+            })?;
+            let read = block_on(async {
+                StreamArrayReader::try_new(FuturesAdapter(Cursor::new(buffer)), ctx.clone())
+                    .await?
+                    .load_dtype()
+                    .await?
+                    .into_array_stream()
+                    .collect_chunked()
+                    .await
+            })?;
+
+            let chunk = read
+                .chunks()
+                .exactly_one()
+                .unwrap_or_else(|_| panic!("Expected one chunk"));
+            assert_eq!(chunk.encoding().id(), id);
+            assert_eq!(chunk.dtype(), array.dtype());
+            for i in 0..array.len() {
+                assert_eq!(scalar_at(&chunk, i)?, scalar_at(&array, i)?);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_write_read_distribution_stats() -> VortexResult<()> {
+        let array = PrimitiveArray::from((0..10_000u32).map(|i| i % 700).collect_vec());
+        let distinct = array.statistics().compute(Stat::DistinctCount);
+        let histogram = array.statistics().compute(Stat::Histogram);
+        assert!(distinct.is_some() && histogram.is_some());
+
+        let buffer = write_ipc(array.clone());
+        let read = block_on(async {
+            StreamArrayReader::try_new(
+                FuturesAdapter(Cursor::new(buffer)),
+                Arc::new(Context::default()),
+            )
+            .await?
+            .load_dtype()
+            .await?
+            .into_array_stream()
+            .collect_chunked()
+            .await
+        })?;
+        let chunk = read
+            .chunks()
+            .exactly_one()
+            .unwrap_or_else(|_| panic!("Expected one chunk"));
+
+        assert_eq!(chunk.statistics().get(Stat::DistinctCount), distinct);
+        // Integers are written in their narrowest width, so compare the bounds by value.
+        let bounds = |histogram: Option<Scalar>| -> Vec<u32> {
+            let histogram = histogram.unwrap_or_else(|| panic!("Expected a histogram"));
+            ListScalar::try_from(&histogram)
+                .unwrap()
+                .elements()
+                .map(|bound| u32::try_from(&bound).unwrap())
+                .collect()
+        };
+        assert_eq!(
+            bounds(chunk.statistics().get(Stat::Histogram)),
+            bounds(histogram)
+        );
+        assert_eq!(
+            chunk.statistics().compute_distinct_count(),
+            array.statistics().compute_distinct_count()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encoding_codes_unique() {
+        let encodings = [
+            &NullEncoding as EncodingRef,
+            &BoolEncoding,
+            &PrimitiveEncoding,
+            &VarBinEncoding,
+            &VarBinViewEncoding,
+            &StructEncoding,
+            &SparseEncoding,
+            &ConstantEncoding,
+            &ChunkedEncoding,
+            &ExtensionEncoding,
+            &ALPEncoding,
+            &ALPRDEncoding,
+            &BitPackedEncoding,
+            &ByteBoolEncoding,
+            &DateTimePartsEncoding,
+            &DeltaEncoding,
+            &DictEncoding,
+            &FoREncoding,
+            &FSSTEncoding,
+            &RoaringBoolEncoding,
+            &RoaringIntEncoding,
+            &RunEndEncoding,
+            &RunEndBoolEncoding,
+            &ZigZagEncoding,
+        ];
+
+        let codes = encodings
+            .iter()
+            .map(|e| e.id().code())
+            .collect::<HashSet<_>>();
+        assert_eq!(codes.len(), encodings.len());
+        assert!(encodings.iter().all(|e| !e.id().is_custom()));
+
+        let ctx = Context::default().with_encodings(encodings);
+        for encoding in encodings {
+            assert_eq!(
+                ctx.lookup_encoding(encoding.id().code()).map(|e| e.id()),
+                Some(encoding.id())
+            );
+        }
+    }
 }
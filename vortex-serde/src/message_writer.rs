@@ -57,15 +57,15 @@ impl<W: VortexWrite> MessageWriter<W> {
 
         // Keep track of the offset to add padding after each buffer.
         let mut current_offset = 0;
-        for (buffer, &buffer_end) in chunk
+        for (buffer, buffer_end) in chunk
             .depth_first_traversal()
             .flat_map(|data| data.into_buffer().into_iter())
-            .zip_eq(buffer_offsets.iter().skip(1))
+            .zip_eq(buffer_offsets.into_iter().skip(1))
         {
             let buffer_len = buffer.len();
             self.write_all(buffer).await?;
             let padding = (buffer_end as usize) - current_offset - buffer_len;
-            self.write_all(&ZEROS[0..padding]).await?;
+            self.write_all(ZEROS.slice_owned(0..padding)).await?;
             current_offset = buffer_end as usize;
         }
 
@@ -80,7 +80,7 @@ impl<W: VortexWrite> MessageWriter<W> {
 
         let aligned_size = (buffer_len + (self.alignment - 1)) & !(self.alignment - 1);
         let padding = aligned_size - buffer_len;
-        self.write_all(&ZEROS[0..padding]).await?;
+        self.write_all(ZEROS.slice_owned(0..padding)).await?;
 
         Ok(())
     }
@@ -115,7 +115,7 @@ impl<W: VortexWrite> MessageWriter<W> {
             .write_all(buffer.slice_owned(buffer_begin..buffer_end))
             .await?
             .into_inner();
-        self.write_all(&ZEROS[0..padding_bytes]).await?;
+        self.write_all(ZEROS.slice_owned(0..padding_bytes)).await?;
 
         assert_eq!(self.pos % self.alignment as u64, 0);
 